@@ -39,11 +39,12 @@ impl Wallet {
     ) -> Result<Transaction, JsValue> {
         let boxes_to_spend: Vec<chain::ergo_box::ErgoBox> = boxes_to_spend.clone().into();
         let data_boxes: Vec<chain::ergo_box::ErgoBox> = data_boxes.clone().into();
-        let tx_context = ergo_lib::wallet::signing::TransactionContext {
-            spending_tx: tx.clone().into(),
+        let tx_context = ergo_lib::wallet::signing::TransactionContext::new(
+            tx.clone().into(),
             boxes_to_spend,
             data_boxes,
-        };
+        )
+        .map_err(|e| JsValue::from_str(&format!("{}", e)))?;
         self.0
             .sign_transaction(tx_context, &_state_context.clone().into())
             .map_err(|e| JsValue::from_str(&format!("{}", e)))
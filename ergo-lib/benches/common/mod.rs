@@ -0,0 +1,103 @@
+//! Workloads shared by the benchmarks and the tests checking their results
+#![allow(dead_code)]
+
+use ergo_lib::ast::constant::Constant;
+use ergo_lib::chain::ergo_box::box_builder::ErgoBoxCandidateBuilder;
+use ergo_lib::chain::ergo_box::{BoxValue, ErgoBox, NonMandatoryRegisters};
+use ergo_lib::chain::ergo_state_context::ErgoStateContext;
+use ergo_lib::chain::transaction::unsigned::UnsignedTransaction;
+use ergo_lib::chain::transaction::{Transaction, TxId, UnsignedInput};
+use ergo_lib::ergo_tree::ErgoTree;
+use ergo_lib::serialization::SigmaSerializable;
+use ergo_lib::sigma_protocol::private_input::DlogProverInput;
+use ergo_lib::sigma_protocol::sigma_boolean::SigmaProp;
+use ergo_lib::wallet::secret_key::SecretKey;
+use ergo_lib::wallet::signing::{ReductionResult, TransactionContext};
+use ergo_lib::wallet::Wallet;
+use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+
+/// Number of public keys in the large tree
+pub const LARGE_TREE_KEYS_COUNT: usize = 64;
+
+const CONSTANT_SEGREGATION_HEADER: u8 = 0x10;
+const CONSTANT_PLACEHOLDER_OP_CODE: u8 = 0x73;
+const SIGMA_AND_OP_CODE: u8 = 0xea;
+
+/// Secret key derived from the given seed byte (deterministic to keep the runs comparable)
+pub fn secret(seed: u8) -> DlogProverInput {
+    let mut bytes = [0u8; DlogProverInput::SIZE_BYTES];
+    bytes[DlogProverInput::SIZE_BYTES - 1] = seed;
+    DlogProverInput::from_bytes(&bytes).unwrap()
+}
+
+/// Serialized tree with segregated constants requiring all of the given public keys
+/// (`proveDlog(pk1) && ... && proveDlog(pkN)`)
+pub fn large_tree_bytes(keys_count: usize) -> Vec<u8> {
+    let mut w = Vec::new();
+    w.put_u8(CONSTANT_SEGREGATION_HEADER).unwrap();
+    w.put_u32(keys_count as u32).unwrap();
+    for seed in 1..=keys_count {
+        let pk: SigmaProp = secret(seed as u8).public_image().into();
+        w.extend(Constant::from(pk).sigma_serialize_bytes());
+    }
+    w.put_u8(SIGMA_AND_OP_CODE).unwrap();
+    w.put_u32(keys_count as u32).unwrap();
+    for idx in 0..keys_count {
+        w.put_u8(CONSTANT_PLACEHOLDER_OP_CODE).unwrap();
+        w.put_u32(idx as u32).unwrap();
+    }
+    w
+}
+
+/// Parse the tree (including the root expression)
+pub fn parse_tree(bytes: &[u8]) -> ErgoTree {
+    ErgoTree::sigma_parse_bytes(bytes.to_vec()).unwrap()
+}
+
+/// Transaction spending a single box guarded by the public key of the given secret
+pub fn p2pk_tx_context(secret: &DlogProverInput) -> TransactionContext {
+    let pk: SigmaProp = secret.public_image().into();
+    let tree = ErgoTree::with_segregation(std::rc::Rc::new(Constant::from(pk).into()));
+    let input_box = ErgoBox::new(
+        BoxValue::SAFE_USER_MIN,
+        tree.clone(),
+        vec![],
+        NonMandatoryRegisters::empty(),
+        0,
+        TxId::zero(),
+        0,
+    );
+    let candidate = ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, tree, 0)
+        .build()
+        .unwrap();
+    let tx = UnsignedTransaction::new(
+        vec![UnsignedInput::from(input_box.clone())],
+        vec![],
+        vec![candidate],
+    );
+    TransactionContext {
+        spending_tx: tx,
+        boxes_to_spend: vec![input_box],
+        data_boxes: vec![],
+    }
+}
+
+/// Wallet holding the given secret
+pub fn wallet(secret: &DlogProverInput) -> Wallet {
+    let secret_key = SecretKey::dlog_from_bytes(&secret.to_bytes()).unwrap();
+    Wallet::from_secrets(vec![secret_key])
+}
+
+/// Reduce the inputs of the transaction to sigma propositions
+pub fn reduce(wallet: &Wallet, tx_context: &TransactionContext) -> Vec<ReductionResult> {
+    wallet
+        .reduce_transaction_inputs(tx_context, &ErgoStateContext::dummy())
+        .unwrap()
+}
+
+/// Sign the transaction (reduce and prove each input)
+pub fn sign(wallet: &Wallet, tx_context: TransactionContext) -> Transaction {
+    wallet
+        .sign_transaction(tx_context, &ErgoStateContext::dummy())
+        .unwrap()
+}
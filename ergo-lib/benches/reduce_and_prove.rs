@@ -0,0 +1,35 @@
+//! Costs of the transaction signing steps: parsing, reduction and proving
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+mod common;
+
+use common::*;
+
+fn bench_parse(c: &mut Criterion) {
+    let bytes = large_tree_bytes(LARGE_TREE_KEYS_COUNT);
+    c.bench_function("parse large tree", |b| {
+        b.iter(|| parse_tree(black_box(&bytes)))
+    });
+}
+
+fn bench_reduce(c: &mut Criterion) {
+    let secret = secret(1);
+    let wallet = wallet(&secret);
+    let tx_context = p2pk_tx_context(&secret);
+    c.bench_function("reduce p2pk", |b| {
+        b.iter(|| reduce(&wallet, black_box(&tx_context)))
+    });
+}
+
+fn bench_prove(c: &mut Criterion) {
+    let secret = secret(1);
+    let wallet = wallet(&secret);
+    let tx_context = p2pk_tx_context(&secret);
+    c.bench_function("sign p2pk", |b| {
+        b.iter(|| sign(&wallet, black_box(tx_context.clone())))
+    });
+}
+
+criterion_group!(benches, bench_parse, bench_reduce, bench_prove);
+criterion_main!(benches);
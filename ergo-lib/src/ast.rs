@@ -1,13 +1,60 @@
 //! AST for ErgoTree
 
+pub(crate) mod and;
+pub(crate) mod block;
+pub(crate) mod bool_to_sigma;
 pub(crate) mod box_methods;
+pub(crate) mod byte_array_to_bigint;
+pub(crate) mod calc_blake2b256;
+pub(crate) mod calc_sha256;
+pub(crate) mod coll_append;
+pub(crate) mod coll_by_index;
+pub(crate) mod coll_exists;
+pub(crate) mod coll_forall;
+pub(crate) mod coll_map;
 pub(crate) mod coll_methods;
+pub(crate) mod coll_size;
+pub(crate) mod coll_slice;
+pub(crate) mod collection;
+pub(crate) mod create_provedlog;
+pub(crate) mod decode_point;
+pub(crate) mod downcast;
+pub(crate) mod exponentiate;
 pub(crate) mod expr;
+pub(crate) mod extract_amount;
+pub(crate) mod extract_bytes;
+pub(crate) mod extract_bytes_with_no_ref;
+pub(crate) mod extract_creation_info;
+pub(crate) mod extract_id;
+pub(crate) mod extract_script_bytes;
+pub(crate) mod func_value;
+pub(crate) mod get_var;
 pub(crate) mod global_vars;
+pub(crate) mod if_op;
+pub(crate) mod logical_not;
 pub(crate) mod method_call;
+pub(crate) mod multiply_group;
+pub(crate) mod negation;
 pub(crate) mod ops;
-pub(crate) mod predef_func;
+pub(crate) mod option_filter;
+pub(crate) mod option_get;
+pub(crate) mod option_get_or_else;
+pub(crate) mod option_is_defined;
+pub(crate) mod option_map;
+pub(crate) mod or;
 pub(crate) mod property_call;
+pub(crate) mod select_field;
+pub(crate) mod sigma_and;
+pub(crate) mod sigma_atleast;
+pub(crate) mod sigma_or;
+pub(crate) mod subst_constants;
+pub(crate) mod tuple;
+pub(crate) mod upcast;
+pub(crate) mod val_def;
+pub(crate) mod val_use;
+pub(crate) mod xor;
+pub(crate) mod xor_of;
 
+pub mod avl_tree_data;
 pub mod constant;
 pub mod value;
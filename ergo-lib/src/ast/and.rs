@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns true if all the elements of the collection are true (`allOf`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct And {
+    /// Collection of booleans
+    pub input: Box<Expr>,
+}
+
+impl And {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::AND
+    }
+}
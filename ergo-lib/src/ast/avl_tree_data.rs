@@ -0,0 +1,145 @@
+//! AVL tree value
+
+use std::io;
+
+use crate::chain::ADDigest;
+use crate::serialization::{
+    sigma_byte_reader::SigmaByteRead, sigma_byte_writer::SigmaByteWrite, SerializationError,
+    SigmaSerializable,
+};
+
+/// Flags of the operations allowed on the AVL tree
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct AvlTreeFlags(u8);
+
+impl AvlTreeFlags {
+    /// Create flags from the allowed operations
+    pub fn new(insert_allowed: bool, update_allowed: bool, remove_allowed: bool) -> Self {
+        let insert = if insert_allowed { 0x01 } else { 0 };
+        let update = if update_allowed { 0x02 } else { 0 };
+        let remove = if remove_allowed { 0x04 } else { 0 };
+        AvlTreeFlags(insert | update | remove)
+    }
+
+    /// Parse flags from the serialized byte
+    pub fn parse(serialized_flags: u8) -> Self {
+        AvlTreeFlags(serialized_flags)
+    }
+
+    /// Serialized flags byte
+    pub fn serialize(self) -> u8 {
+        self.0
+    }
+
+    /// Insertion of new keys is allowed
+    pub fn insert_allowed(self) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    /// Update of the values is allowed
+    pub fn update_allowed(self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    /// Removal of keys is allowed
+    pub fn remove_allowed(self) -> bool {
+        self.0 & 0x04 != 0
+    }
+}
+
+/// AVL tree (authenticated dynamic dictionary) data stored in the `AvlTree` value
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AvlTreeData {
+    /// Root hash of the tree (with the tree height)
+    pub digest: ADDigest,
+    /// Allowed operations
+    pub tree_flags: AvlTreeFlags,
+    /// All the keys in the tree have this length
+    pub key_length: u32,
+    /// If set, all the values in the tree have this length
+    pub value_length_opt: Option<Box<u32>>,
+}
+
+impl SigmaSerializable for AvlTreeData {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        // for reference see https://github.com/ScorexFoundation/sigmastate-interpreter/blob/25251c1313b0131835f92099f02cef8a5d932b5e/sigmastate/src/main/scala/sigmastate/AvlTreeData.scala#L73-L73
+        self.digest.sigma_serialize(w)?;
+        w.put_u8(self.tree_flags.serialize())?;
+        w.put_u32(self.key_length)?;
+        match &self.value_length_opt {
+            Some(value_length) => {
+                w.put_u8(1)?;
+                w.put_u32(**value_length)
+            }
+            None => w.put_u8(0),
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let digest = ADDigest::sigma_parse(r)?;
+        let tree_flags = AvlTreeFlags::parse(r.get_u8()?);
+        let key_length = r.get_u32()?;
+        let value_length_opt = if r.get_u8()? != 0 {
+            Some(Box::new(r.get_u32()?))
+        } else {
+            None
+        };
+        Ok(AvlTreeData {
+            digest,
+            tree_flags,
+            key_length,
+            value_length_opt,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    impl Arbitrary for AvlTreeData {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (
+                any::<[u8; 32]>(),
+                any::<u8>(),
+                any::<(bool, bool, bool)>(),
+                any::<u32>(),
+                any::<Option<u32>>(),
+            )
+                .prop_map(|(hash, height, flags, key_length, value_length_opt)| {
+                    let mut digest = [0u8; ADDigest::SIZE];
+                    digest[..32].copy_from_slice(&hash);
+                    digest[32] = height;
+                    AvlTreeData {
+                        digest: digest.into(),
+                        tree_flags: AvlTreeFlags::new(flags.0, flags.1, flags.2),
+                        key_length,
+                        value_length_opt: value_length_opt.map(Box::new),
+                    }
+                })
+                .boxed()
+        }
+    }
+
+    #[test]
+    fn flags() {
+        let flags = AvlTreeFlags::new(true, false, true);
+        assert!(flags.insert_allowed());
+        assert!(!flags.update_allowed());
+        assert!(flags.remove_allowed());
+        assert_eq!(AvlTreeFlags::parse(flags.serialize()), flags);
+    }
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in any::<AvlTreeData>()) {
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Block of the value definitions followed by the expression using them
+/// (`{ val a = ...; val b = ...; result }`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BlockValue {
+    /// Value definitions (ValDef)
+    pub items: Vec<Expr>,
+    /// Result of the block
+    pub result: Box<Expr>,
+}
+
+impl BlockValue {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::BLOCK_VALUE
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Embedding of the boolean value into SigmaProp (`sigmaProp(input)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BoolToSigmaProp {
+    /// Boolean value
+    pub input: Box<Expr>,
+}
+
+impl BoolToSigmaProp {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::BOOL_TO_SIGMA_PROP
+    }
+}
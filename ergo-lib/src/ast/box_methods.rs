@@ -1,10 +1,11 @@
 use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
 
 use super::expr::Expr;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// newtype for box register id
-pub struct RegisterId(u8); // should be a sum of NonMandatoryRegisterId and MandatoryRegisterId
+pub struct RegisterId(pub u8); // should be a sum of NonMandatoryRegisterId and MandatoryRegisterId
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// Methods for Box type instance
@@ -13,14 +14,19 @@ pub enum BoxM {
     ExtractRegisterAs {
         /// Box
         input: Box<Expr>,
-        /// Register id to extract value from
+        /// Register id to extract value from. Mandatory registers have fixed types:
+        /// R0 - `Long`, R1 - `Coll[Byte]`, R2 - `Coll[(Coll[Byte], Long)]`, R3 - `(Int, Coll[Byte])`
         register_id: RegisterId,
+        /// Expected type of the register value (result is `Option` of it)
+        tpe: SType,
     },
 }
 
 impl BoxM {
     /// Code (serialization)
     pub fn op_code(&self) -> OpCode {
-        todo!()
+        match self {
+            BoxM::ExtractRegisterAs { .. } => OpCode::EXTRACT_REGISTER_AS,
+        }
     }
 }
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Big integer from the big-endian two's complement bytes (`byteArrayToBigInt`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ByteArrayToBigInt {
+    /// Collection of bytes
+    pub input: Box<Expr>,
+}
+
+impl ByteArrayToBigInt {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::BYTE_ARRAY_TO_BIGINT
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Blake2b256 hash of the byte collection (`blake2b256(input)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CalcBlake2b256 {
+    /// Byte collection
+    pub input: Box<Expr>,
+}
+
+impl CalcBlake2b256 {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::CALC_BLAKE2B256
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// SHA-256 hash of the byte collection (`sha256(input)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CalcSha256 {
+    /// Byte collection
+    pub input: Box<Expr>,
+}
+
+impl CalcSha256 {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::CALC_SHA256
+    }
+}
@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Concatenation of two collections (`input ++ col_2`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Append {
+    /// First collection
+    pub input: Box<Expr>,
+    /// Second collection (appended to the first one)
+    pub col_2: Box<Expr>,
+}
+
+impl Append {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::APPEND
+    }
+}
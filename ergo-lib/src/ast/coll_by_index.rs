@@ -0,0 +1,22 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns an element of the collection at the given index
+/// (or the default value if the index is out of bounds and the default is provided)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ByIndex {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Index of the element
+    pub index: Box<Expr>,
+    /// Value to return if the index is out of bounds
+    pub default: Option<Box<Expr>>,
+}
+
+impl ByIndex {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::BY_INDEX
+    }
+}
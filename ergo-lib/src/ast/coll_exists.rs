@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns true if the condition holds for at least one element of the collection (`exists`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Exists {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Function (lambda) to test each element
+    pub condition: Box<Expr>,
+}
+
+impl Exists {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXISTS
+    }
+}
@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns true if the condition holds for all the elements of the collection (`forall`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ForAll {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Function (lambda) to test each element
+    pub condition: Box<Expr>,
+}
+
+impl ForAll {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::FOR_ALL
+    }
+}
@@ -0,0 +1,20 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Builds a new collection by applying a function to all elements of the collection
+/// (`input.map(mapper)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Map {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Function (lambda) of one argument to apply to each element
+    pub mapper: Box<Expr>,
+}
+
+impl Map {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::MAP_COLLECTION
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Collection size (`input.size`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SizeOf {
+    /// Collection
+    pub input: Box<Expr>,
+}
+
+impl SizeOf {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SIZE_OF
+    }
+}
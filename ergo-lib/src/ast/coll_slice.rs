@@ -0,0 +1,21 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Selects an interval of elements of the collection (`input.slice(from, until)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Slice {
+    /// Collection
+    pub input: Box<Expr>,
+    /// Lower bound of the interval (inclusive)
+    pub from: Box<Expr>,
+    /// Upper bound of the interval (exclusive)
+    pub until: Box<Expr>,
+}
+
+impl Slice {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SLICE
+    }
+}
@@ -0,0 +1,28 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+
+/// Collection built from the explicitly listed elements (i.e. `Coll(a, b, c)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Collection {
+    /// Special representation for a collection of boolean constants
+    BoolConstants(Vec<bool>),
+    /// Collection of elements, where each element is an expression
+    Exprs {
+        /// Element type
+        elem_tpe: SType,
+        /// Elements
+        items: Vec<Expr>,
+    },
+}
+
+impl Collection {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        match self {
+            Collection::BoolConstants(_) => OpCode::COLL_OF_BOOL_CONST,
+            Collection::Exprs { .. } => OpCode::COLL,
+        }
+    }
+}
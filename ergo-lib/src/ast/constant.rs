@@ -1,16 +1,25 @@
 //! Constant(Literal) IR node
 
+use crate::big_integer::BigInteger;
 use crate::chain::ergo_box::ErgoBox;
 use crate::chain::{Base16DecodedBytes, Base16EncodedBytes};
 use crate::types::stype::LiftIntoSType;
 use crate::types::stype::SType;
+use crate::util::coll_bytes_to_utf8;
 use crate::{
-    serialization::{SerializationError, SigmaSerializable},
+    serialization::{
+        constant_store::ConstantStore,
+        sigma_byte_reader::{SigmaByteRead, SigmaByteReader},
+        SerializationError, SigmaSerializable,
+    },
     sigma_protocol::{dlog_group::EcPoint, sigma_boolean::SigmaProp},
 };
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
+use sigma_ser::peekable_reader::PeekableReader;
 use std::convert::TryFrom;
+use std::fmt;
+use std::io::Cursor;
 
 mod constant_placeholder;
 
@@ -44,9 +53,45 @@ impl From<Constant> for Base16EncodedBytes {
 }
 
 impl TryFrom<Base16DecodedBytes> for Constant {
-    type Error = SerializationError;
-    fn try_from(bytes: Base16DecodedBytes) -> Result<Self, Self::Error> {
-        Constant::sigma_parse_bytes(bytes.0)
+    type Error = ConstantParsingError;
+    fn try_from(mut bytes: Base16DecodedBytes) -> Result<Self, Self::Error> {
+        let cursor = Cursor::new(&mut bytes.0[..]);
+        let mut r = SigmaByteReader::new(PeekableReader::new(cursor), ConstantStore::empty());
+        Constant::sigma_parse(&mut r).map_err(|error| ConstantParsingError::SerializationError {
+            position: r.position(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for Constant {
+    /// Renders as `<tpe>: <value>`, e.g. `SInt: 42` or `Coll[Byte]: 0xdeadbeef`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", tpe_str(&self.tpe, true), self.v)
+    }
+}
+
+fn tpe_str(tpe: &SType, top_level: bool) -> String {
+    match tpe {
+        SType::SColl(elem) => format!("Coll[{}]", tpe_str(elem, false)),
+        SType::SOption(elem) => format!("Option[{}]", tpe_str(elem, false)),
+        SType::STup(items) => format!(
+            "({})",
+            items
+                .iter()
+                .map(|i| tpe_str(i, false))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        _ => {
+            let name = format!("{:?}", tpe);
+            if top_level {
+                name
+            } else {
+                // element types are rendered with ErgoScript names (i.e. `Byte` for `SByte`)
+                name.strip_prefix('S').map(str::to_string).unwrap_or(name)
+            }
+        }
     }
 }
 
@@ -56,6 +101,78 @@ impl Constant {
         let base16_bytes: Base16EncodedBytes = self.clone().into();
         base16_bytes.into()
     }
+
+    /// Parse constant from its serialized bytes encoded as Base16 (see [`Constant::base16_str`])
+    pub fn from_base16(s: &str) -> Result<Constant, ConstantParsingError> {
+        let bytes = Base16DecodedBytes::try_from(s)?;
+        Constant::try_from(bytes)
+    }
+
+    /// Decode `Coll[Byte]` constant value as UTF-8 text
+    pub fn as_utf8(&self) -> Result<String, TryExtractFromError> {
+        let bytes = self.clone().try_extract_into::<Vec<i8>>()?;
+        coll_bytes_to_utf8(&bytes)
+            .map_err(|e| TryExtractFromError(format!("invalid UTF-8 bytes: {}", e)))
+    }
+
+    /// Create `Coll[Byte]` constant from the Base16-encoded bytes
+    pub fn coll_byte_from_hex(s: &str) -> Result<Constant, ConstantParsingError> {
+        let bytes = Base16DecodedBytes::try_from(s)?;
+        Ok(bytes.0.into())
+    }
+
+    /// Create constant from the value (i.e. evaluation result) inferring its type
+    pub fn from_value(v: Value) -> Result<Constant, ValueTypeInferenceError> {
+        Ok(Constant {
+            tpe: infer_tpe(&v)?,
+            v,
+        })
+    }
+}
+
+fn infer_tpe(v: &Value) -> Result<SType, ValueTypeInferenceError> {
+    Ok(match v {
+        Value::Boolean(_) => SType::SBoolean,
+        Value::Byte(_) => SType::SByte,
+        Value::Short(_) => SType::SShort,
+        Value::Int(_) => SType::SInt,
+        Value::Long(_) => SType::SLong,
+        Value::BigInt(_) => SType::SBigInt,
+        Value::GroupElement(_) => SType::SGroupElement,
+        Value::SigmaProp(_) => SType::SSigmaProp,
+        Value::CBox(_) => SType::SBox,
+        Value::AvlTree(_) => SType::SAvlTree,
+        Value::Coll(coll) => SType::SColl(Box::new(coll.elem_tpe().clone())),
+        Value::Tup(items) => SType::STup(items.iter().map(infer_tpe).collect::<Result<_, _>>()?),
+        Value::Opt(opt) => match opt.as_ref() {
+            Some(v) => SType::SOption(Box::new(infer_tpe(v)?)),
+            // element type of `None` is unknown
+            None => return Err(ValueTypeInferenceError(format!("{:?}", v))),
+        },
+        Value::Context(_) => return Err(ValueTypeInferenceError("Context".to_string())),
+        Value::Lambda(_) => return Err(ValueTypeInferenceError("Lambda".to_string())),
+    })
+}
+
+/// Type of the value cannot be inferred (e.g. `None`) or it cannot be a constant
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+#[error("Cannot infer constant type for the value: {0}")]
+pub struct ValueTypeInferenceError(pub String);
+
+/// Errors on parsing Constant from Base16-encoded string
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ConstantParsingError {
+    /// Invalid Base16 encoding
+    #[error("Base16 decoding error: {0}")]
+    Base16DecodingError(#[from] base16::DecodeError),
+    /// Failed to parse Constant from bytes
+    #[error("Serialization error at byte {position}: {error}")]
+    SerializationError {
+        /// Number of bytes read before the failure
+        position: u64,
+        /// Underlying error
+        error: SerializationError,
+    },
 }
 
 impl From<bool> for Constant {
@@ -112,6 +229,15 @@ impl From<SigmaProp> for Constant {
     }
 }
 
+impl From<BigInteger> for Constant {
+    fn from(v: BigInteger) -> Constant {
+        Constant {
+            tpe: SType::SBigInt,
+            v: v.into(),
+        }
+    }
+}
+
 impl From<EcPoint> for Constant {
     fn from(v: EcPoint) -> Constant {
         Constant {
@@ -159,6 +285,19 @@ impl<T: LiftIntoSType + StoredNonPrimitive + Into<Value>> From<Vec<T>> for Const
     }
 }
 
+impl<A, B> From<(A, B)> for Constant
+where
+    A: LiftIntoSType + Into<Value>,
+    B: LiftIntoSType + Into<Value>,
+{
+    fn from(v: (A, B)) -> Self {
+        Constant {
+            tpe: <(A, B)>::stype(),
+            v: v.into(),
+        }
+    }
+}
+
 /// Extract value wrapped in a type
 pub trait TryExtractInto<F> {
     /// Extract value of the given type from any type (e.g. ['Constant'], [`super::value::Value`])
@@ -189,29 +328,10 @@ impl<T: TryExtractFrom<Value>> TryExtractFrom<Constant> for T {
     }
 }
 
-impl TryExtractFrom<Constant> for Vec<i8> {
-    fn try_extract_from(c: Constant) -> Result<Self, TryExtractFromError> {
-        match c.v {
-            Value::Coll(Coll::Primitive(CollPrim::CollByte(bs))) => Ok(bs),
-            _ => Err(TryExtractFromError(format!(
-                "expected {:?}, found {:?}",
-                std::any::type_name::<Self>(),
-                c.v
-            ))),
-        }
-    }
-}
-
-impl TryExtractFrom<Constant> for Vec<u8> {
-    fn try_extract_from(cv: Constant) -> Result<Self, TryExtractFromError> {
-        use crate::util::FromVecI8;
-        Vec::<i8>::try_extract_from(cv).map(Vec::<u8>::from_vec_i8)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chain::ergo_box::{NonMandatoryRegisterId, NonMandatoryRegisters};
     use proptest::collection::vec;
     use proptest::prelude::*;
 
@@ -232,11 +352,189 @@ mod tests {
                 (vec(any::<i16>(), 0..100)).prop_map_into(),
                 (vec(any::<i32>(), 0..100)).prop_map_into(),
                 (vec(any::<i64>(), 0..100)).prop_map_into(),
+                (vec(any::<EcPoint>(), 0..10)).prop_map_into(),
+                (vec(any::<SigmaProp>(), 0..10)).prop_map_into(),
             ]
             .boxed()
         }
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(Constant::from(42i32).to_string(), "SInt: 42");
+        assert_eq!(Constant::from(true).to_string(), "SBoolean: true");
+        assert_eq!(
+            Constant::from(vec![0xdeu8, 0xad, 0xbe, 0xef]).to_string(),
+            "Coll[Byte]: 0xdeadbeef"
+        );
+        assert_eq!(
+            Constant::from(vec![1i64, 2]).to_string(),
+            "Coll[Long]: [1, 2]"
+        );
+        assert_eq!(
+            Constant::from(vec![vec![1i32, 2], vec![3]]).to_string(),
+            "Coll[Coll[Int]]: [[1, 2], [3]]"
+        );
+        assert_eq!(
+            Constant::from(vec![vec![1u8], vec![]]).to_string(),
+            "Coll[Coll[Byte]]: [0x01, 0x]"
+        );
+        assert_eq!(
+            Constant::from((1i32, vec![2i64])).to_string(),
+            "(Int, Coll[Long]): (1, [2])"
+        );
+        assert_eq!(
+            Constant::from(vec![Some(1i64), None]).to_string(),
+            "Coll[Option[Long]]: [Some(1), None]"
+        );
+    }
+
+    #[test]
+    fn big_int() {
+        let c: Constant = BigInteger::from(256i64).into();
+        assert_eq!(c.base16_str(), "06020100");
+        assert_eq!(Constant::from_base16("06020100").unwrap(), c);
+        assert_eq!(c.to_string(), "SBigInt: 256");
+        // more than 256 bits
+        assert!(Constant::from_base16(&format!("0621{}", "01".repeat(33))).is_err());
+    }
+
+    #[test]
+    fn from_value_primitives() {
+        for c in [
+            Constant::from(true),
+            Constant::from(1i8),
+            Constant::from(2i16),
+            Constant::from(3i32),
+            Constant::from(4i64),
+            Constant::from(BigInteger::from(5i64)),
+        ]
+        .iter()
+        {
+            assert_eq!(&Constant::from_value(c.v.clone()).unwrap(), c);
+        }
+    }
+
+    #[test]
+    fn from_value_coll_long() {
+        let c: Constant = vec![1i64, 2, 3].into();
+        assert_eq!(c.tpe, SType::SColl(Box::new(SType::SLong)));
+        assert_eq!(Constant::from_value(c.v.clone()).unwrap(), c);
+    }
+
+    #[test]
+    fn from_value_tuple() {
+        let v = Value::Tup(vec![
+            Value::Int(1),
+            vec![1u8, 2].into(),
+            Value::Opt(Box::new(Some(Value::Long(2)))),
+        ]);
+        assert_eq!(
+            Constant::from_value(v).unwrap().tpe,
+            SType::STup(vec![
+                SType::SInt,
+                SType::SColl(Box::new(SType::SByte)),
+                SType::SOption(Box::new(SType::SLong)),
+            ])
+        );
+    }
+
+    #[test]
+    fn from_value_none() {
+        assert!(Constant::from_value(Value::Opt(Box::new(None))).is_err());
+    }
+
+    #[test]
+    fn coll_byte_from_hex_register() {
+        let c = Constant::coll_byte_from_hex("010203ff").unwrap();
+        assert_eq!(c.tpe, SType::SColl(Box::new(SType::SByte)));
+        let regs = NonMandatoryRegisters::from_ordered_values(vec![c]).unwrap();
+        let r4 = regs.get(NonMandatoryRegisterId::R4).unwrap().clone();
+        assert_eq!(
+            r4.try_extract_into::<Vec<u8>>().unwrap(),
+            vec![1u8, 2, 3, 255]
+        );
+    }
+
+    #[test]
+    fn coll_byte_from_invalid_hex() {
+        assert!(matches!(
+            Constant::coll_byte_from_hex("0g"),
+            Err(ConstantParsingError::Base16DecodingError(_))
+        ));
+        assert!(matches!(
+            Constant::coll_byte_from_hex("010"),
+            Err(ConstantParsingError::Base16DecodingError(_))
+        ));
+    }
+
+    #[test]
+    fn coll_option_long() {
+        let v: Vec<Option<i64>> = vec![Some(1), None, Some(i64::MIN)];
+        let c: Constant = v.clone().into();
+        assert_eq!(
+            c.tpe,
+            SType::SColl(Box::new(SType::SOption(Box::new(SType::SLong))))
+        );
+        // Coll[Option[Long]] type: 0c 29, then len, then (flag, value) for each element
+        assert!(c.base16_str().starts_with("0c290301020001"));
+        let c_parsed = Constant::from_base16(&c.base16_str()).unwrap();
+        assert_eq!(c_parsed, c);
+        assert_eq!(c_parsed.try_extract_into::<Vec<Option<i64>>>().unwrap(), v);
+    }
+
+    #[test]
+    fn coll_bool_bit_packed() {
+        let c: Constant = vec![true, false, true].into();
+        assert_eq!(c.base16_str(), "0d0305");
+        assert_eq!(Constant::from_base16("0d0305").unwrap(), c);
+    }
+
+    #[test]
+    fn as_utf8() {
+        let c: Constant = "héllo".as_bytes().to_vec().into();
+        assert_eq!(c.as_utf8().unwrap(), "héllo");
+        assert_eq!(Constant::from(Vec::<u8>::new()).as_utf8().unwrap(), "");
+    }
+
+    #[test]
+    fn as_utf8_invalid_bytes() {
+        // truncated 2-byte sequence
+        let c: Constant = vec![0x68u8, 0xc3].into();
+        assert!(c.as_utf8().is_err());
+        assert!(coll_bytes_to_utf8(&[0x68, 0xc3u8 as i8]).is_err());
+    }
+
+    #[test]
+    fn as_utf8_not_coll_byte() {
+        assert!(Constant::from(1i32).as_utf8().is_err());
+    }
+
+    #[test]
+    fn from_base16() {
+        let c: Constant = vec![1u8, 2, 3].into();
+        assert_eq!(c.base16_str(), "0e03010203");
+        assert_eq!(Constant::from_base16("0e03010203").unwrap(), c);
+        assert!(matches!(
+            Constant::from_base16("0e0301"),
+            Err(ConstantParsingError::SerializationError { position: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_try_extract_coll_tokens() {
+        let tokens: Vec<(Vec<u8>, i64)> = vec![(vec![1, 2, 3], 100), (vec![4, 5], i64::MAX)];
+        let c: Constant = tokens.clone().into();
+        assert_eq!(
+            c.tpe,
+            SType::SColl(Box::new(SType::STup(vec![
+                SType::SColl(Box::new(SType::SByte)),
+                SType::SLong
+            ])))
+        );
+        assert_eq!(c.try_extract_into::<Vec<(Vec<u8>, i64)>>().unwrap(), tokens);
+    }
+
     proptest! {
 
         #[test]
@@ -270,11 +568,18 @@ mod tests {
                         SType::SShort => { let _ = Vec::<i16>::try_extract_from(c).unwrap(); }
                         SType::SInt => { let _ = Vec::<i32>::try_extract_from(c).unwrap(); }
                         SType::SLong => { let _ = Vec::<i64>::try_extract_from(c).unwrap(); }
+                        SType::SGroupElement => { let _ = Vec::<EcPoint>::try_extract_from(c).unwrap(); }
+                        SType::SSigmaProp => { let _ = Vec::<SigmaProp>::try_extract_from(c).unwrap(); }
                         _ => todo!()
                     }
                 }
                 _ => todo!(),
             };
         }
+
+        #[test]
+        fn test_from_value(c in any::<Constant>()) {
+            prop_assert_eq![Constant::from_value(c.v.clone()).unwrap(), c];
+        }
     }
 }
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// SigmaProp proving the knowledge of the discrete logarithm of the group element (`proveDlog(input)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CreateProveDlog {
+    /// Group element (public key)
+    pub input: Box<Expr>,
+}
+
+impl CreateProveDlog {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::PROVE_DLOG
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Decode the group element from the bytes of its encoding (`decodePoint(input)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DecodePoint {
+    /// Encoded group element bytes
+    pub input: Box<Expr>,
+}
+
+impl DecodePoint {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::DECODE_POINT
+    }
+}
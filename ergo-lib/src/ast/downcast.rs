@@ -0,0 +1,68 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+
+/// Numerical downcast (narrowing conversion, e.g. Long to Int)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Downcast {
+    /// Numerical value to be downcasted
+    pub input: Box<Expr>,
+    /// Target type
+    pub tpe: SType,
+}
+
+impl Downcast {
+    /// Create new object, returns an error if the target type is not numeric
+    /// or is wider than the input type (e.g. Int to Long)
+    pub fn new(input: Expr, tpe: SType) -> Result<Self, InvalidArgumentError> {
+        let input_tpe = input.try_tpe()?;
+        match (input_tpe.numeric_rank(), tpe.numeric_rank()) {
+            (_, None) => Err(InvalidArgumentError(format!(
+                "Downcast: expected numeric target type, got {:?}",
+                tpe
+            ))),
+            (None, _) => Err(InvalidArgumentError(format!(
+                "Downcast: expected numeric input type, got {:?}",
+                input_tpe
+            ))),
+            (Some(from), Some(to)) if from < to => Err(InvalidArgumentError(format!(
+                "Downcast: cannot downcast {:?} to wider {:?}",
+                input_tpe, tpe
+            ))),
+            _ => Ok(Downcast {
+                input: Box::new(input),
+                tpe,
+            }),
+        }
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::DOWNCAST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_narrowing() {
+        assert!(Downcast::new(Expr::Const(1i64.into()), SType::SInt).is_ok());
+        assert!(Downcast::new(Expr::Const(1i32.into()), SType::SByte).is_ok());
+        assert!(Downcast::new(Expr::Const(1i32.into()), SType::SInt).is_ok());
+    }
+
+    #[test]
+    fn new_int_to_long() {
+        assert!(Downcast::new(Expr::Const(1i32.into()), SType::SLong).is_err());
+    }
+
+    #[test]
+    fn new_non_numeric() {
+        assert!(Downcast::new(Expr::Const(1i64.into()), SType::SBoolean).is_err());
+        assert!(Downcast::new(Expr::Const(true.into()), SType::SByte).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Exponentiation of the group element (`left.exp(right)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Exponentiate {
+    /// Base (GroupElement)
+    pub left: Box<Expr>,
+    /// Exponent (BigInt)
+    pub right: Box<Expr>,
+}
+
+impl Exponentiate {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXPONENTIATE
+    }
+}
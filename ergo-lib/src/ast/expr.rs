@@ -1,20 +1,89 @@
 use core::fmt;
 
+use std::convert::TryFrom;
+
+use crate::chain::Base16DecodedBytes;
 use crate::serialization::op_code::OpCode;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::types::scontext::SContext;
 use crate::types::stype::SType;
 
+use super::and::And;
+use super::block::BlockValue;
+use super::bool_to_sigma::BoolToSigmaProp;
 use super::box_methods::BoxM;
+use super::byte_array_to_bigint::ByteArrayToBigInt;
+use super::calc_blake2b256::CalcBlake2b256;
+use super::calc_sha256::CalcSha256;
+use super::coll_append::Append;
+use super::coll_by_index::ByIndex;
+use super::coll_exists::Exists;
+use super::coll_forall::ForAll;
+use super::coll_map::Map;
 use super::coll_methods::CollM;
+use super::coll_size::SizeOf;
+use super::coll_slice::Slice;
+use super::collection::Collection;
 use super::constant::Constant;
 use super::constant::ConstantPlaceholder;
+use super::create_provedlog::CreateProveDlog;
+use super::decode_point::DecodePoint;
+use super::downcast::Downcast;
+use super::exponentiate::Exponentiate;
+use super::extract_amount::ExtractAmount;
+use super::extract_bytes::ExtractBytes;
+use super::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use super::extract_creation_info::ExtractCreationInfo;
+use super::extract_id::ExtractId;
+use super::extract_script_bytes::ExtractScriptBytes;
+use super::func_value::FuncValue;
+use super::get_var::GetVar;
 use super::global_vars::GlobalVars;
+use super::if_op::If;
+use super::logical_not::LogicalNot;
 use super::method_call::MethodCall;
+use super::multiply_group::MultiplyGroup;
+use super::negation::Negation;
 use super::ops;
-use super::predef_func::PredefFunc;
+use super::option_filter::OptionFilter;
+use super::option_get::OptionGet;
+use super::option_get_or_else::OptionGetOrElse;
+use super::option_is_defined::OptionIsDefined;
+use super::option_map::OptionMap;
+use super::or::Or;
 use super::property_call::PropertyCall;
+use super::select_field::SelectField;
+use super::sigma_and::SigmaAnd;
+use super::sigma_atleast::Atleast;
+use super::sigma_or::SigmaOr;
+use super::subst_constants::SubstConstants;
+use super::tuple::Tuple;
+use super::upcast::Upcast;
+use super::val_def::ValDef;
+use super::val_use::ValUse;
+use super::xor::Xor;
+use super::xor_of::XorOf;
 
 extern crate derive_more;
 use derive_more::From;
+use thiserror::Error;
+
+/// Invalid arguments on the node construction
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+#[error("InvalidArgumentError: {0}")]
+pub struct InvalidArgumentError(pub String);
+
+/// Errors on parsing Expr from Base16-encoded string
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ExprParsingError {
+    /// Invalid Base16 encoding
+    #[error("Base16 decoding error: {0}")]
+    Base16DecodingError(#[from] base16::DecodeError),
+    /// Failed to parse Expr from bytes
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] SerializationError),
+}
 
 #[derive(PartialEq, Eq, Debug, Clone, From)]
 /// Expression in ErgoTree
@@ -23,12 +92,34 @@ pub enum Expr {
     Const(Constant),
     /// Placeholder for a constant
     ConstPlaceholder(ConstantPlaceholder),
-    /// Predefined functions (global)
-    PredefFunc(PredefFunc),
+    /// Reference to the bound value (e.g. function argument)
+    ValUse(ValUse),
+    /// User-defined function
+    FuncValue(FuncValue),
+    /// SHA-256 hash
+    CalcSha256(CalcSha256),
+    /// Group element decoded from bytes
+    DecodePoint(DecodePoint),
+    /// Numerical upcast
+    Upcast(Upcast),
     /// Collection type methods
     CollM(CollM),
+    /// Concatenation of two collections
+    Append(Append),
+    /// Interval of the collection elements
+    Slice(Slice),
+    /// Collection of the explicitly listed elements
+    Collection(Collection),
+    /// Tests whether the condition holds for at least one element of the collection
+    Exists(Exists),
+    /// Tests whether the condition holds for all the elements of the collection
+    ForAll(ForAll),
+    /// Collection with the function applied to each element
+    Map(Map),
     /// Box methods
     BoxM(BoxM),
+    /// Big integer from the bytes
+    ByteArrayToBigInt(ByteArrayToBigInt),
     Context,
     // Global(Global),
     /// Predefined global variables
@@ -39,9 +130,90 @@ pub enum Expr {
     ProperyCall(PropertyCall),
     /// Binary operation
     BinOp(ops::BinOp, Box<Expr>, Box<Expr>),
+    /// Exponentiation of the group element
+    Exponentiate(Exponentiate),
+    /// Group operation on the group elements
+    MultiplyGroup(MultiplyGroup),
+    /// Collection element by index
+    ByIndex(ByIndex),
+    /// Tuple field
+    SelectField(SelectField),
+    /// Box monetary value
+    ExtractAmount(ExtractAmount),
+    /// Box guarding script serialized bytes
+    ExtractScriptBytes(ExtractScriptBytes),
+    /// Context variable
+    GetVar(GetVar),
+    /// Get value of the Option
+    OptionGet(OptionGet),
+    /// Get value of the Option or the default value if it is empty
+    OptionGetOrElse(OptionGetOrElse),
+    /// Check whether the Option is defined
+    OptionIsDefined(OptionIsDefined),
+    /// Apply the function to the Option value if it is defined
+    OptionMap(OptionMap),
+    /// Keep the Option value if it is defined and satisfies the predicate
+    OptionFilter(OptionFilter),
+    /// Logical AND of the boolean collection elements
+    And(And),
+    /// Logical OR of the boolean collection elements
+    Or(Or),
+    /// AND conjunction of the sigma propositions
+    SigmaAnd(SigmaAnd),
+    /// THRESHOLD composition of sigma propositions (`atLeast(bound, input)`)
+    Atleast(Atleast),
+    /// OR disjunction of the sigma propositions
+    SigmaOr(SigmaOr),
+    /// Embedding of the boolean value into SigmaProp
+    BoolToSigmaProp(BoolToSigmaProp),
+    /// Box creation height along with the creating transaction id and the box index
+    ExtractCreationInfo(ExtractCreationInfo),
+    /// Serialized ErgoTree with the replaced constants
+    SubstConstants(SubstConstants),
+    /// SigmaProp proving the knowledge of the discrete logarithm of the group element
+    CreateProveDlog(CreateProveDlog),
+    /// Collection size
+    SizeOf(SizeOf),
+    /// If-else conditional expression
+    If(If),
+    /// Binding of the value to the id
+    ValDef(ValDef),
+    /// Block of the value definitions followed by the result expression
+    BlockValue(BlockValue),
+    /// Box id
+    ExtractId(ExtractId),
+    /// Logical negation
+    LogicalNot(LogicalNot),
+    /// Blake2b256 hash
+    CalcBlake2b256(CalcBlake2b256),
+    /// Arithmetic negation
+    Negation(Negation),
+    /// Byte-wise XOR of two byte arrays
+    Xor(Xor),
+    /// Box bytes
+    ExtractBytes(ExtractBytes),
+    /// Box bytes without the reference to the creating transaction
+    ExtractBytesWithNoRef(ExtractBytesWithNoRef),
+    /// Tuple built from the listed items
+    Tuple(Tuple),
+    /// Numerical downcast
+    Downcast(Downcast),
+    /// XOR of the collection of booleans
+    XorOf(XorOf),
 }
 
 impl Expr {
+    /// Lift a value convertible into [`Constant`] into a constant node
+    pub fn constant<T: Into<Constant>>(v: T) -> Expr {
+        Expr::Const(v.into())
+    }
+
+    /// Parse expression from its serialized bytes encoded as Base16
+    pub fn from_base16(s: &str) -> Result<Expr, ExprParsingError> {
+        let bytes = Base16DecodedBytes::try_from(s)?;
+        Ok(Expr::sigma_parse_bytes(bytes.0)?)
+    }
+
     /// Code (used in serialization)
     pub fn op_code(&self) -> OpCode {
         match self {
@@ -51,17 +223,280 @@ impl Expr {
             Expr::MethodCall(v) => v.op_code(),
             Expr::ProperyCall(v) => v.op_code(),
             Expr::Context => OpCode::CONTEXT,
+            Expr::Collection(v) => v.op_code(),
+            Expr::Append(v) => v.op_code(),
+            Expr::Exists(v) => v.op_code(),
+            Expr::ForAll(v) => v.op_code(),
+            Expr::CalcSha256(v) => v.op_code(),
+            Expr::DecodePoint(v) => v.op_code(),
+            Expr::Upcast(v) => v.op_code(),
+            Expr::ValUse(v) => v.op_code(),
+            Expr::FuncValue(v) => v.op_code(),
+            Expr::Slice(v) => v.op_code(),
+            Expr::Map(v) => v.op_code(),
+            Expr::ByIndex(v) => v.op_code(),
+            Expr::SelectField(v) => v.op_code(),
+            Expr::BoxM(v) => v.op_code(),
+            Expr::ByteArrayToBigInt(v) => v.op_code(),
+            Expr::ExtractAmount(v) => v.op_code(),
+            Expr::ExtractScriptBytes(v) => v.op_code(),
+            Expr::GetVar(v) => v.op_code(),
+            Expr::OptionGet(v) => v.op_code(),
+            Expr::OptionGetOrElse(v) => v.op_code(),
+            Expr::OptionIsDefined(v) => v.op_code(),
+            Expr::OptionMap(v) => v.op_code(),
+            Expr::OptionFilter(v) => v.op_code(),
+            Expr::And(v) => v.op_code(),
+            Expr::Or(v) => v.op_code(),
+            Expr::SigmaAnd(v) => v.op_code(),
+            Expr::Atleast(v) => v.op_code(),
+            Expr::SigmaOr(v) => v.op_code(),
+            Expr::BoolToSigmaProp(v) => v.op_code(),
+            Expr::ExtractCreationInfo(v) => v.op_code(),
+            Expr::SubstConstants(v) => v.op_code(),
+            Expr::CreateProveDlog(v) => v.op_code(),
+            Expr::SizeOf(v) => v.op_code(),
+            Expr::If(v) => v.op_code(),
+            Expr::ValDef(v) => v.op_code(),
+            Expr::BlockValue(v) => v.op_code(),
+            Expr::ExtractId(v) => v.op_code(),
+            Expr::LogicalNot(v) => v.op_code(),
+            Expr::CalcBlake2b256(v) => v.op_code(),
+            Expr::Negation(v) => v.op_code(),
+            Expr::Xor(v) => v.op_code(),
+            Expr::ExtractBytes(v) => v.op_code(),
+            Expr::ExtractBytesWithNoRef(v) => v.op_code(),
+            Expr::Tuple(v) => v.op_code(),
+            Expr::Downcast(v) => v.op_code(),
+            Expr::XorOf(v) => v.op_code(),
+            Expr::BinOp(op, _, _) => op.op_code(),
+            Expr::Exponentiate(v) => v.op_code(),
+            Expr::MultiplyGroup(v) => v.op_code(),
             _ => todo!("{0:?}", self),
         }
     }
 
-    /// Type of the expression
-    pub fn tpe(&self) -> &SType {
+    /// Total number of nodes in the expression tree (including this one)
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children()
+            .into_iter()
+            .map(Expr::node_count)
+            .sum::<usize>()
+    }
+
+    /// Length of the longest path from this node to a leaf (single node has depth 1)
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children()
+            .into_iter()
+            .map(Expr::depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Direct child expressions
+    pub(crate) fn children(&self) -> Vec<&Expr> {
         match self {
-            Expr::Const(c) => &c.tpe,
-            _ => todo!(),
+            Expr::Const(_)
+            | Expr::ConstPlaceholder(_)
+            | Expr::Context
+            | Expr::GlobalVars(_)
+            | Expr::GetVar(_)
+            | Expr::ValUse(_) => vec![],
+            Expr::FuncValue(v) => vec![&v.body],
+            Expr::Exists(v) => vec![&v.input, &v.condition],
+            Expr::ForAll(v) => vec![&v.input, &v.condition],
+            Expr::Map(v) => vec![&v.input, &v.mapper],
+            Expr::CalcSha256(v) => vec![&v.input],
+            Expr::DecodePoint(v) => vec![&v.input],
+            Expr::Upcast(v) => vec![&v.input],
+            Expr::CollM(CollM::Fold {
+                input,
+                zero,
+                fold_op,
+            }) => vec![input, zero, fold_op],
+            Expr::Append(v) => vec![&v.input, &v.col_2],
+            Expr::Slice(v) => vec![&v.input, &v.from, &v.until],
+            Expr::Collection(Collection::BoolConstants(_)) => vec![],
+            Expr::Collection(Collection::Exprs { items, .. }) => items.iter().collect(),
+            Expr::BoxM(BoxM::ExtractRegisterAs { input, .. }) => vec![input],
+            Expr::MethodCall(v) => std::iter::once(v.obj.as_ref())
+                .chain(v.args.iter())
+                .collect(),
+            Expr::ProperyCall(v) => vec![&v.obj],
+            Expr::BinOp(_, l, r) => vec![l, r],
+            Expr::Exponentiate(v) => vec![&v.left, &v.right],
+            Expr::MultiplyGroup(v) => vec![&v.left, &v.right],
+            Expr::ByIndex(v) => {
+                let mut children: Vec<&Expr> = vec![&v.input, &v.index];
+                if let Some(default) = &v.default {
+                    children.push(default);
+                }
+                children
+            }
+            Expr::SelectField(v) => vec![&v.input],
+            Expr::ByteArrayToBigInt(v) => vec![&v.input],
+            Expr::ExtractAmount(v) => vec![&v.input],
+            Expr::ExtractScriptBytes(v) => vec![&v.input],
+            Expr::OptionGet(v) => vec![&v.input],
+            Expr::OptionGetOrElse(v) => vec![&v.input, &v.default],
+            Expr::OptionIsDefined(v) => vec![&v.input],
+            Expr::OptionMap(v) => vec![&v.input, &v.mapper],
+            Expr::OptionFilter(v) => vec![&v.input, &v.condition],
+            Expr::And(v) => vec![&v.input],
+            Expr::Or(v) => vec![&v.input],
+            Expr::SigmaAnd(v) => v.items.iter().collect(),
+            Expr::Atleast(v) => vec![&v.bound, &v.input],
+            Expr::SigmaOr(v) => v.items.iter().collect(),
+            Expr::BoolToSigmaProp(v) => vec![&v.input],
+            Expr::ExtractCreationInfo(v) => vec![&v.input],
+            Expr::SubstConstants(v) => vec![&v.script_bytes, &v.positions, &v.new_values],
+            Expr::CreateProveDlog(v) => vec![&v.input],
+            Expr::SizeOf(v) => vec![&v.input],
+            Expr::If(v) => vec![&v.condition, &v.true_branch, &v.false_branch],
+            Expr::ValDef(v) => vec![&v.rhs],
+            Expr::BlockValue(v) => v
+                .items
+                .iter()
+                .chain(std::iter::once(v.result.as_ref()))
+                .collect(),
+            Expr::ExtractId(v) => vec![&v.input],
+            Expr::LogicalNot(v) => vec![&v.input],
+            Expr::CalcBlake2b256(v) => vec![&v.input],
+            Expr::Negation(v) => vec![&v.input],
+            Expr::Xor(v) => vec![&v.left, &v.right],
+            Expr::ExtractBytes(v) => vec![&v.input],
+            Expr::ExtractBytesWithNoRef(v) => vec![&v.input],
+            Expr::Tuple(v) => v.items.iter().collect(),
+            Expr::Downcast(v) => vec![&v.input],
+            Expr::XorOf(v) => vec![&v.input],
+        }
+    }
+
+    /// Type of the expression
+    ///
+    /// Panics if the types of the children are inconsistent (see [`Expr::try_tpe`]), which can
+    /// happen only for hand-built expressions since the parser checks them
+    pub fn tpe(&self) -> SType {
+        match self.try_tpe() {
+            Ok(tpe) => tpe,
+            Err(e) => panic!("{}", e),
         }
     }
+
+    /// Type of the expression, returns an error if the types of the children are inconsistent
+    /// (e.g. `OptionGet` of a non-option value)
+    pub fn try_tpe(&self) -> Result<SType, InvalidArgumentError> {
+        Ok(match self {
+            Expr::Const(c) => c.tpe.clone(),
+            Expr::ValUse(v) => v.tpe.clone(),
+            Expr::FuncValue(v) => v.tpe(),
+            Expr::Map(v) => match v.mapper.try_tpe()? {
+                SType::SFunc(f) => SType::SColl(Box::new(f.t_range)),
+                tpe => return Err(unexpected_tpe("Map", "function mapper", tpe)),
+            },
+            Expr::OptionMap(v) => match v.mapper.try_tpe()? {
+                SType::SFunc(f) => SType::SOption(Box::new(f.t_range)),
+                tpe => return Err(unexpected_tpe("OptionMap", "function mapper", tpe)),
+            },
+            Expr::OptionFilter(v) => v.input.try_tpe()?,
+            Expr::Append(v) => v.input.try_tpe()?,
+            Expr::Slice(v) => v.input.try_tpe()?,
+            Expr::Context => SType::SContext(SContext()),
+            Expr::GlobalVars(GlobalVars::Height) => SType::SInt,
+            Expr::GlobalVars(GlobalVars::SelfBox) => SType::SBox,
+            Expr::GlobalVars(GlobalVars::Inputs) | Expr::GlobalVars(GlobalVars::Outputs) => {
+                SType::SColl(Box::new(SType::SBox))
+            }
+            Expr::GlobalVars(GlobalVars::MinerPubKey) => SType::SColl(Box::new(SType::SByte)),
+            Expr::BinOp(ops::BinOp::Num(_), l, _)
+            | Expr::BinOp(ops::BinOp::Bit(_), l, _)
+            | Expr::BinOp(ops::BinOp::Shift(_), l, _) => l.try_tpe()?,
+            Expr::BinOp(ops::BinOp::Relation(_), _, _)
+            | Expr::BinOp(ops::BinOp::Logical(_), _, _) => SType::SBoolean,
+            Expr::Exponentiate(_) | Expr::MultiplyGroup(_) => SType::SGroupElement,
+            Expr::ExtractAmount(_) => SType::SLong,
+            Expr::ByteArrayToBigInt(_) => SType::SBigInt,
+            Expr::CalcSha256(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::DecodePoint(_) => SType::SGroupElement,
+            Expr::Upcast(v) => v.tpe.clone(),
+            Expr::ConstPlaceholder(v) => v.tpe.clone(),
+            Expr::Collection(Collection::BoolConstants(_)) => {
+                SType::SColl(Box::new(SType::SBoolean))
+            }
+            Expr::Collection(Collection::Exprs { elem_tpe, .. }) => {
+                SType::SColl(Box::new(elem_tpe.clone()))
+            }
+            Expr::BoxM(BoxM::ExtractRegisterAs { tpe, .. }) => {
+                SType::SOption(Box::new(tpe.clone()))
+            }
+            Expr::OptionGet(v) => match v.input.try_tpe()? {
+                SType::SOption(elem) => *elem,
+                tpe => return Err(unexpected_tpe("OptionGet", "option input", tpe)),
+            },
+            Expr::OptionGetOrElse(v) => match v.input.try_tpe()? {
+                SType::SOption(elem) if *elem == v.default.try_tpe()? => *elem,
+                tpe => {
+                    return Err(unexpected_tpe(
+                        "OptionGetOrElse",
+                        "option input of the default value type",
+                        tpe,
+                    ))
+                }
+            },
+            Expr::OptionIsDefined(_) => SType::SBoolean,
+            Expr::ByIndex(v) => match v.input.try_tpe()? {
+                SType::SColl(elem) => *elem,
+                tpe => return Err(unexpected_tpe("ByIndex", "collection input", tpe)),
+            },
+            Expr::SelectField(v) => v.tpe(),
+            Expr::ExtractScriptBytes(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::GetVar(v) => v.tpe(),
+            Expr::And(_) | Expr::Or(_) | Expr::Exists(_) | Expr::ForAll(_) => SType::SBoolean,
+            Expr::SigmaAnd(_) | Expr::SigmaOr(_) | Expr::Atleast(_) | Expr::BoolToSigmaProp(_) => {
+                SType::SSigmaProp
+            }
+            Expr::ExtractCreationInfo(_) => {
+                SType::STup(vec![SType::SInt, SType::SColl(Box::new(SType::SByte))])
+            }
+            Expr::SubstConstants(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::CreateProveDlog(_) => SType::SSigmaProp,
+            Expr::SizeOf(_) => SType::SInt,
+            Expr::If(v) => v.true_branch.try_tpe()?,
+            Expr::ValDef(v) => v.rhs.try_tpe()?,
+            Expr::BlockValue(v) => v.result.try_tpe()?,
+            Expr::ProperyCall(v) => match v.method.tpe() {
+                SType::SFunc(f) => f.t_range.clone(),
+                tpe => {
+                    return Err(unexpected_tpe(
+                        "PropertyCall",
+                        "function method",
+                        tpe.clone(),
+                    ))
+                }
+            },
+            Expr::MethodCall(v) => match v.method.tpe() {
+                SType::SFunc(f) => f.t_range.clone(),
+                tpe => return Err(unexpected_tpe("MethodCall", "function method", tpe.clone())),
+            },
+            Expr::ExtractId(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::LogicalNot(_) => SType::SBoolean,
+            Expr::CalcBlake2b256(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::Negation(v) => v.input.try_tpe()?,
+            Expr::Xor(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::ExtractBytes(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::ExtractBytesWithNoRef(_) => SType::SColl(Box::new(SType::SByte)),
+            Expr::Tuple(v) => v.tpe(),
+            Expr::Downcast(v) => v.tpe.clone(),
+            Expr::XorOf(_) => SType::SBoolean,
+            Expr::CollM(CollM::Fold { zero, .. }) => zero.try_tpe()?,
+        })
+    }
+}
+
+fn unexpected_tpe(node: &str, expected: &str, tpe: SType) -> InvalidArgumentError {
+    InvalidArgumentError(format!("{}: expected {}, got {:?}", node, expected, tpe))
 }
 
 impl fmt::Display for Expr {
@@ -74,7 +509,10 @@ impl fmt::Display for Expr {
 mod tests {
     #![allow(unused_imports)]
     use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
     use crate::sigma_protocol::sigma_boolean::SigmaProp;
+    use crate::types::sbox;
     use proptest::prelude::*;
 
     impl Arbitrary for Expr {
@@ -85,4 +523,107 @@ mod tests {
             prop_oneof![any::<Constant>().prop_map(Expr::Const)].boxed()
         }
     }
+
+    #[test]
+    fn constant_lifting_roundtrip() {
+        let exprs: Vec<Expr> = vec![
+            Expr::constant(true),
+            Expr::constant(1i32),
+            Expr::constant(i64::MAX),
+            Expr::constant(vec![1u8, 2, 3]),
+            Expr::constant(vec![1i64, 2]),
+            Constant::from(2i16).into(),
+        ];
+        for expr in exprs {
+            // serialized as a bare constant (type code first, no op code)
+            assert!(expr.sigma_serialize_bytes()[0] <= OpCode::LAST_CONSTANT_CODE.value());
+            assert_eq!(sigma_serialize_roundtrip(&expr), expr);
+        }
+    }
+
+    #[test]
+    fn node_count_and_depth() {
+        // SELF.tokens(0)._1 == SELF.propositionBytes
+        let self_box: Expr = GlobalVars::SelfBox.into();
+        let token_id: Expr = SelectField::new(
+            ByIndex {
+                input: Box::new(
+                    PropertyCall {
+                        obj: Box::new(self_box.clone()),
+                        method: sbox::TOKENS_PROPERTY.clone(),
+                    }
+                    .into(),
+                ),
+                index: Box::new(Expr::Const(0i32.into())),
+                default: None,
+            }
+            .into(),
+            1,
+        )
+        .unwrap()
+        .into();
+        let script_bytes: Expr = ExtractScriptBytes {
+            input: Box::new(self_box),
+        }
+        .into();
+        let expr = sigma_serialize_roundtrip(&Expr::BinOp(
+            ops::BinOp::Relation(ops::RelationOp::Eq),
+            Box::new(token_id),
+            Box::new(script_bytes),
+        ));
+        assert_eq!(expr.node_count(), 8);
+        assert_eq!(expr.depth(), 5);
+    }
+
+    #[test]
+    fn from_base16_numeric_casts() {
+        // bytes as laid out by sigmastate's op code table (Downcast = 0x7d, Upcast = 0x7e)
+        // HEIGHT.toByte > 0.toByte
+        let downcast = Expr::BinOp(
+            ops::BinOp::Relation(ops::RelationOp::Gt),
+            Box::new(
+                Downcast::new(GlobalVars::Height.into(), SType::SByte)
+                    .unwrap()
+                    .into(),
+            ),
+            Box::new(Expr::Const(0i8.into())),
+        );
+        assert_eq!(Expr::from_base16("917da3020200").unwrap(), downcast);
+        assert_eq!(
+            downcast.sigma_serialize_bytes(),
+            vec![0x91, 0x7d, 0xa3, 0x02, 0x02, 0x00]
+        );
+        // HEIGHT.toLong
+        let upcast: Expr = Upcast::new(GlobalVars::Height.into(), SType::SLong)
+            .unwrap()
+            .into();
+        assert_eq!(Expr::from_base16("7ea305").unwrap(), upcast);
+        assert_eq!(upcast.sigma_serialize_bytes(), vec![0x7e, 0xa3, 0x05]);
+    }
+
+    #[test]
+    fn from_base16() {
+        // HEIGHT == 1
+        let expected = Expr::BinOp(
+            ops::BinOp::Relation(ops::RelationOp::Eq),
+            Box::new(GlobalVars::Height.into()),
+            Box::new(Expr::Const(1i32.into())),
+        );
+        assert_eq!(Expr::from_base16("93a30402").unwrap(), expected);
+        assert!(matches!(
+            Expr::from_base16("93a3"),
+            Err(ExprParsingError::SerializationError(_))
+        ));
+        assert!(matches!(
+            Expr::from_base16("xyz"),
+            Err(ExprParsingError::Base16DecodingError(_))
+        ));
+    }
+
+    #[test]
+    fn node_count_and_depth_leaf() {
+        let expr: Expr = GlobalVars::Height.into();
+        assert_eq!(expr.node_count(), 1);
+        assert_eq!(expr.depth(), 1);
+    }
 }
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Box monetary value (`box.value`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractAmount {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractAmount {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_AMOUNT
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Serialized box bytes (`box.bytes`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractBytes {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractBytes {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_BYTES
+    }
+}
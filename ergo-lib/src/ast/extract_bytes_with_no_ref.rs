@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Serialized box bytes without the transaction id and the output index (`box.bytesWithNoRef`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractBytesWithNoRef {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractBytesWithNoRef {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_BYTES_WITH_NO_REF
+    }
+}
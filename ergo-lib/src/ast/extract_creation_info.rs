@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Box creation height along with the id of the creating transaction and the box index in its outputs (`box.creationInfo`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractCreationInfo {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractCreationInfo {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_CREATION_INFO
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Box id (`box.id`), Blake2b256 hash of the box content
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractId {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractId {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_ID
+    }
+}
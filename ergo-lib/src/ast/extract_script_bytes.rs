@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Serialized box guarding script (`box.propositionBytes`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ExtractScriptBytes {
+    /// Box
+    pub input: Box<Expr>,
+}
+
+impl ExtractScriptBytes {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::EXTRACT_SCRIPT_BYTES
+    }
+}
@@ -0,0 +1,39 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::sfunc::SFunc;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+
+/// Argument of the user-defined function
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FuncArg {
+    /// Id the argument value is bound to in the function body (see [`super::val_use::ValUse`])
+    pub idx: u32,
+    /// Type of the argument
+    pub tpe: SType,
+}
+
+/// User-defined function (lambda)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FuncValue {
+    /// Function arguments
+    pub args: Vec<FuncArg>,
+    /// Function body
+    pub body: Box<Expr>,
+}
+
+impl FuncValue {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::FUNC_VALUE
+    }
+
+    /// Type of the function
+    pub fn tpe(&self) -> SType {
+        SType::SFunc(Box::new(SFunc {
+            t_dom: self.args.iter().map(|a| a.tpe.clone()).collect(),
+            t_range: self.body.tpe(),
+            tpe_params: vec![],
+        }))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Context variable (from the context extension) by its id (`getVar[T](id)`).
+/// Evaluates to `None` if the variable is absent, fails if it has a type other than `var_tpe`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct GetVar {
+    /// Variable id
+    pub var_id: u8,
+    /// Expected type of the variable value
+    pub var_tpe: SType,
+}
+
+impl GetVar {
+    /// Type of the expression (Option of the variable value type)
+    pub fn tpe(&self) -> SType {
+        SType::SOption(Box::new(self.var_tpe.clone()))
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::GET_VAR
+    }
+}
@@ -11,6 +11,8 @@ pub enum GlobalVars {
     Height,
     /// ErgoBox instance, which script is being evaluated
     SelfBox,
+    /// Public key of the miner of the block being validated (serialized group element)
+    MinerPubKey,
 }
 
 impl GlobalVars {
@@ -20,6 +22,7 @@ impl GlobalVars {
             GlobalVars::Inputs => OpCode::INPUTS,
             GlobalVars::Outputs => OpCode::OUTPUTS,
             GlobalVars::Height => OpCode::HEIGHT,
+            GlobalVars::MinerPubKey => OpCode::MINER_PUBKEY,
         }
     }
 }
@@ -35,7 +38,14 @@ mod tests {
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
             use GlobalVars::*;
-            prop_oneof![Just(Inputs), Just(Outputs), Just(Height), Just(SelfBox),].boxed()
+            prop_oneof![
+                Just(Inputs),
+                Just(Outputs),
+                Just(Height),
+                Just(SelfBox),
+                Just(MinerPubKey),
+            ]
+            .boxed()
         }
     }
 }
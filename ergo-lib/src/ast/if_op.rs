@@ -0,0 +1,21 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Conditional expression (`if (condition) true_branch else false_branch`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct If {
+    /// Boolean condition
+    pub condition: Box<Expr>,
+    /// Evaluated if the condition is true
+    pub true_branch: Box<Expr>,
+    /// Evaluated if the condition is false
+    pub false_branch: Box<Expr>,
+}
+
+impl If {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::IF
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Logical negation (`!input`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct LogicalNot {
+    /// Boolean value
+    pub input: Box<Expr>,
+}
+
+impl LogicalNot {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::LOGICAL_NOT
+    }
+}
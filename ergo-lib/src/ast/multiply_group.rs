@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Group operation on the group elements (`left.multiply(right)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MultiplyGroup {
+    /// First GroupElement
+    pub left: Box<Expr>,
+    /// Second GroupElement
+    pub right: Box<Expr>,
+}
+
+impl MultiplyGroup {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::MULTIPLY_GROUP
+    }
+}
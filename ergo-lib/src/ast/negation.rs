@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Arithmetic negation of the numeric value (`-input`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Negation {
+    /// Numeric value
+    pub input: Box<Expr>,
+}
+
+impl Negation {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::NEGATION
+    }
+}
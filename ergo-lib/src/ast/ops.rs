@@ -1,9 +1,73 @@
 //! Operators in ErgoTree
+use crate::serialization::op_code::OpCode;
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// Operations for numerical types
 pub enum NumOp {
     /// Addition
     Add,
+    /// Subtraction
+    Subtract,
+    /// Multiplication
+    Multiply,
+    /// Division (rounds toward zero)
+    Divide,
+    /// Remainder of the division (has the sign of the dividend)
+    Modulo,
+    /// Minimum of two values
+    Min,
+    /// Maximum of two values
+    Max,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+/// Relations (comparison operations)
+pub enum RelationOp {
+    /// Equality
+    Eq,
+    /// Non-equality
+    NEq,
+    /// Less than
+    Lt,
+    /// Less or equal
+    Le,
+    /// Greater than
+    Gt,
+    /// Greater or equal
+    Ge,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+/// Logical operations on booleans
+pub enum LogicalOp {
+    /// Logical AND (`&&`, right operand is evaluated only if the left one is true)
+    And,
+    /// Logical OR (`||`, right operand is evaluated only if the left one is false)
+    Or,
+    /// Logical XOR
+    Xor,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+/// Bitwise operations for numerical types (two's complement)
+pub enum BitOp {
+    /// Bitwise AND (`&`)
+    And,
+    /// Bitwise OR (`|`)
+    Or,
+    /// Bitwise XOR (`^`)
+    Xor,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+/// Bit shifts for numerical types (shift amount is of the same type as the shifted value)
+pub enum ShiftOp {
+    /// Left shift (`<<`)
+    Left,
+    /// Arithmetic right shift (`>>`, sign-extending)
+    Right,
+    /// Logical right shift (`>>>`, zero-filling)
+    RightZeroed,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -11,4 +75,42 @@ pub enum NumOp {
 pub enum BinOp {
     /// Binary operations for numerical types
     Num(NumOp),
+    /// Relations
+    Relation(RelationOp),
+    /// Logical operations
+    Logical(LogicalOp),
+    /// Bitwise operations for numerical types
+    Bit(BitOp),
+    /// Bit shifts for numerical types
+    Shift(ShiftOp),
+}
+
+impl BinOp {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        match self {
+            BinOp::Num(NumOp::Add) => OpCode::PLUS,
+            BinOp::Num(NumOp::Subtract) => OpCode::MINUS,
+            BinOp::Num(NumOp::Multiply) => OpCode::MULTIPLY,
+            BinOp::Num(NumOp::Divide) => OpCode::DIVISION,
+            BinOp::Num(NumOp::Modulo) => OpCode::MODULO,
+            BinOp::Num(NumOp::Min) => OpCode::MIN,
+            BinOp::Num(NumOp::Max) => OpCode::MAX,
+            BinOp::Relation(RelationOp::Eq) => OpCode::EQ,
+            BinOp::Relation(RelationOp::NEq) => OpCode::NEQ,
+            BinOp::Relation(RelationOp::Lt) => OpCode::LT,
+            BinOp::Relation(RelationOp::Le) => OpCode::LE,
+            BinOp::Relation(RelationOp::Gt) => OpCode::GT,
+            BinOp::Relation(RelationOp::Ge) => OpCode::GE,
+            BinOp::Logical(LogicalOp::And) => OpCode::BIN_AND,
+            BinOp::Logical(LogicalOp::Or) => OpCode::BIN_OR,
+            BinOp::Logical(LogicalOp::Xor) => OpCode::BIN_XOR,
+            BinOp::Bit(BitOp::Or) => OpCode::BIT_OR,
+            BinOp::Bit(BitOp::And) => OpCode::BIT_AND,
+            BinOp::Bit(BitOp::Xor) => OpCode::BIT_XOR,
+            BinOp::Shift(ShiftOp::Right) => OpCode::BIT_SHIFT_RIGHT,
+            BinOp::Shift(ShiftOp::Left) => OpCode::BIT_SHIFT_LEFT,
+            BinOp::Shift(ShiftOp::RightZeroed) => OpCode::BIT_SHIFT_RIGHT_ZEROED,
+        }
+    }
 }
@@ -0,0 +1,20 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns the Option if it is defined and its value satisfies the predicate, otherwise `None`
+/// (`Option.filter`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionFilter {
+    /// Option value
+    pub input: Box<Expr>,
+    /// Function (lambda) of one argument returning Boolean
+    pub condition: Box<Expr>,
+}
+
+impl OptionFilter {
+    /// Code (serialization), serialized as `SOption.filter` method call
+    pub fn op_code(&self) -> OpCode {
+        OpCode::METHOD_CALL
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Value of the Option if it is defined, otherwise an error (`Option.get`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionGet {
+    /// Option value
+    pub input: Box<Expr>,
+}
+
+impl OptionGet {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::OPTION_GET
+    }
+}
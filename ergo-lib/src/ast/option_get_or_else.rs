@@ -0,0 +1,74 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+
+/// Value of the Option if it is defined, otherwise the default value (`Option.getOrElse`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionGetOrElse {
+    /// Option value
+    pub input: Box<Expr>,
+    /// Default value (of the Option's element type)
+    pub default: Box<Expr>,
+}
+
+impl OptionGetOrElse {
+    /// Create new object, returns an error if the input is not an Option
+    /// or the default value type differs from the Option's element type
+    pub fn new(input: Expr, default: Expr) -> Result<Self, InvalidArgumentError> {
+        let default_tpe = default.try_tpe()?;
+        match input.try_tpe()? {
+            SType::SOption(elem_tpe) if *elem_tpe == default_tpe => Ok(OptionGetOrElse {
+                input: Box::new(input),
+                default: Box::new(default),
+            }),
+            input_tpe => Err(InvalidArgumentError(format!(
+                "OptionGetOrElse: expected default of the element type of {:?}, got {:?}",
+                input_tpe, default_tpe
+            ))),
+        }
+    }
+
+    /// Type (the Option's element type, which is the default value type for the objects
+    /// created with [`OptionGetOrElse::new`])
+    pub fn tpe(&self) -> SType {
+        self.default.tpe()
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::OPTION_GET_OR_ELSE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::get_var::GetVar;
+
+    use super::*;
+
+    fn get_var_long() -> Expr {
+        GetVar {
+            var_id: 1,
+            var_tpe: SType::SLong,
+        }
+        .into()
+    }
+
+    #[test]
+    fn new_matching_default() {
+        let v = OptionGetOrElse::new(get_var_long(), Expr::Const(1i64.into())).unwrap();
+        assert_eq!(v.tpe(), SType::SLong);
+    }
+
+    #[test]
+    fn new_default_type_mismatch() {
+        assert!(OptionGetOrElse::new(get_var_long(), Expr::Const(1i32.into())).is_err());
+    }
+
+    #[test]
+    fn new_non_option_input() {
+        assert!(OptionGetOrElse::new(Expr::Const(1i64.into()), Expr::Const(1i64.into())).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns `true` if the Option is defined (`Option.isDefined`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionIsDefined {
+    /// Option value
+    pub input: Box<Expr>,
+}
+
+impl OptionIsDefined {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::OPTION_IS_DEFINED
+    }
+}
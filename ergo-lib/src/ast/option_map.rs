@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Applies the function to the Option value if it is defined, otherwise `None` (`Option.map`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct OptionMap {
+    /// Option value
+    pub input: Box<Expr>,
+    /// Function (lambda) of one argument to apply to the value
+    pub mapper: Box<Expr>,
+}
+
+impl OptionMap {
+    /// Code (serialization), serialized as `SOption.map` method call
+    pub fn op_code(&self) -> OpCode {
+        OpCode::METHOD_CALL
+    }
+}
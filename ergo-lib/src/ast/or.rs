@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Returns true if any element of the collection is true (`anyOf`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Or {
+    /// Collection of booleans
+    pub input: Box<Expr>,
+}
+
+impl Or {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::OR
+    }
+}
@@ -0,0 +1,74 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+
+/// Select a field of the tuple value
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SelectField {
+    /// Tuple value
+    pub input: Box<Expr>,
+    /// Index of the field (1-based, i.e. `_1` selects the first field)
+    pub field_index: u8,
+    field_tpe: SType,
+}
+
+impl SelectField {
+    /// Create new object, returns an error if the input is not a tuple or the field index is
+    /// out of bounds
+    pub fn new(input: Expr, field_index: u8) -> Result<Self, InvalidArgumentError> {
+        match input.try_tpe()? {
+            SType::STup(items) => {
+                let field_tpe = (field_index as usize)
+                    .checked_sub(1)
+                    .and_then(|idx| items.get(idx).cloned())
+                    .ok_or_else(|| {
+                        InvalidArgumentError(format!(
+                            "SelectField: field index {} is out of bounds for tuple of {} items",
+                            field_index,
+                            items.len()
+                        ))
+                    })?;
+                Ok(SelectField {
+                    input: Box::new(input),
+                    field_index,
+                    field_tpe,
+                })
+            }
+            tpe => Err(InvalidArgumentError(format!(
+                "SelectField: expected tuple input, got {:?}",
+                tpe
+            ))),
+        }
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SELECT_FIELD
+    }
+
+    /// Type of the selected field
+    pub fn tpe(&self) -> SType {
+        self.field_tpe.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_field_index_out_of_bounds() {
+        let tuple = Expr::Const((1i32, 2i64).into());
+        assert!(SelectField::new(tuple.clone(), 1).is_ok());
+        assert!(SelectField::new(tuple.clone(), 2).is_ok());
+        assert!(SelectField::new(tuple.clone(), 0).is_err());
+        assert!(SelectField::new(tuple, 3).is_err());
+    }
+
+    #[test]
+    fn new_non_tuple_input() {
+        assert!(SelectField::new(Expr::Const(1i32.into()), 1).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// AND conjunction of the sigma propositions (evaluates to CAND)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SigmaAnd {
+    /// Items of SigmaProp type
+    pub items: Vec<Expr>,
+}
+
+impl SigmaAnd {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SIGMA_AND
+    }
+}
@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// THRESHOLD composition of the sigma propositions (evaluates to CTHRESHOLD)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Atleast {
+    /// Number of the sigma propositions that should be proven (SInt)
+    pub bound: Box<Expr>,
+    /// Collection of the sigma propositions (`Coll[SigmaProp]`)
+    pub input: Box<Expr>,
+}
+
+impl Atleast {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::ATLEAST
+    }
+}
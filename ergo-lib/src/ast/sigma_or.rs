@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// OR disjunction of the sigma propositions (evaluates to COR)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SigmaOr {
+    /// Items of SigmaProp type
+    pub items: Vec<Expr>,
+}
+
+impl SigmaOr {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SIGMA_OR
+    }
+}
@@ -0,0 +1,22 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Serialized ErgoTree with the segregated constants replaced by the new values
+/// (`substConstants(script_bytes, positions, new_values)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SubstConstants {
+    /// Serialized ErgoTree (Coll[Byte])
+    pub script_bytes: Box<Expr>,
+    /// Indices of the constants to replace (Coll[Int])
+    pub positions: Box<Expr>,
+    /// New values of the constants
+    pub new_values: Box<Expr>,
+}
+
+impl SubstConstants {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::SUBST_CONSTANTS
+    }
+}
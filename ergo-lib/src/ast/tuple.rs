@@ -0,0 +1,54 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+
+/// Tuple built from the explicitly listed items (i.e. `(a, b, c)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Tuple {
+    /// Items (from two to four)
+    pub items: Vec<Expr>,
+}
+
+impl Tuple {
+    /// Allowed number of items
+    pub const ITEMS_COUNT_RANGE: std::ops::RangeInclusive<usize> = 2..=4;
+
+    /// Create new object, returns an error if the number of items is not in
+    /// [`Tuple::ITEMS_COUNT_RANGE`]
+    pub fn new(items: Vec<Expr>) -> Result<Self, InvalidArgumentError> {
+        if Tuple::ITEMS_COUNT_RANGE.contains(&items.len()) {
+            Ok(Tuple { items })
+        } else {
+            Err(InvalidArgumentError(format!(
+                "Tuple: expected from 2 to 4 items, got {}",
+                items.len()
+            )))
+        }
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::TUPLE
+    }
+
+    /// Type
+    pub fn tpe(&self) -> SType {
+        SType::STup(self.items.iter().map(|i| i.tpe()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_items_count() {
+        let item = || Expr::Const(1i32.into());
+        assert!(Tuple::new(vec![item()]).is_err());
+        assert!(Tuple::new(vec![item(), item()]).is_ok());
+        assert!(Tuple::new(vec![item(), item(), item(), item()]).is_ok());
+        assert!(Tuple::new(vec![item(), item(), item(), item(), item()]).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+use super::expr::InvalidArgumentError;
+
+/// Numerical upcast (widening conversion, e.g. Int to Long)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Upcast {
+    /// Numerical value to be upcasted
+    pub input: Box<Expr>,
+    /// Target type
+    pub tpe: SType,
+}
+
+impl Upcast {
+    /// Create new object, returns an error if the target type is not numeric
+    /// or is narrower than the input type (e.g. Long to Int)
+    pub fn new(input: Expr, tpe: SType) -> Result<Self, InvalidArgumentError> {
+        let input_tpe = input.try_tpe()?;
+        match (input_tpe.numeric_rank(), tpe.numeric_rank()) {
+            (_, None) => Err(InvalidArgumentError(format!(
+                "Upcast: expected numeric target type, got {:?}",
+                tpe
+            ))),
+            (None, _) => Err(InvalidArgumentError(format!(
+                "Upcast: expected numeric input type, got {:?}",
+                input_tpe
+            ))),
+            (Some(from), Some(to)) if from > to => Err(InvalidArgumentError(format!(
+                "Upcast: cannot upcast {:?} to narrower {:?}",
+                input_tpe, tpe
+            ))),
+            _ => Ok(Upcast {
+                input: Box::new(input),
+                tpe,
+            }),
+        }
+    }
+
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::UPCAST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_widening() {
+        assert!(Upcast::new(Expr::Const(1i32.into()), SType::SLong).is_ok());
+        assert!(Upcast::new(Expr::Const(1i8.into()), SType::SInt).is_ok());
+        assert!(Upcast::new(Expr::Const(1i32.into()), SType::SInt).is_ok());
+    }
+
+    #[test]
+    fn new_long_to_int() {
+        assert!(Upcast::new(Expr::Const(1i64.into()), SType::SInt).is_err());
+    }
+
+    #[test]
+    fn new_non_numeric_target() {
+        assert!(Upcast::new(Expr::Const(1i32.into()), SType::SBoolean).is_err());
+        assert!(Upcast::new(
+            Expr::Const(1i32.into()),
+            SType::SColl(Box::new(SType::SLong))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn new_non_numeric_input() {
+        assert!(Upcast::new(Expr::Const(true.into()), SType::SLong).is_err());
+    }
+}
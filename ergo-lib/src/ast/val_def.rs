@@ -0,0 +1,26 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+use super::expr::Expr;
+
+/// Binding of the value to the id, which can be referenced in the subsequent expressions
+/// (see [`super::val_use::ValUse`] and [`super::block::BlockValue`])
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ValDef {
+    /// Id the value is bound to
+    pub id: u32,
+    /// Bound value
+    pub rhs: Box<Expr>,
+}
+
+impl ValDef {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::VAL_DEF
+    }
+
+    /// Type of the bound value
+    pub fn tpe(&self) -> SType {
+        self.rhs.tpe()
+    }
+}
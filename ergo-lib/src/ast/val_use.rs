@@ -0,0 +1,18 @@
+use crate::serialization::op_code::OpCode;
+use crate::types::stype::SType;
+
+/// Reference to the value bound to the id (e.g. a function argument)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ValUse {
+    /// Id of the referenced value
+    pub val_id: u32,
+    /// Type of the referenced value
+    pub tpe: SType,
+}
+
+impl ValUse {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::VAL_USE
+    }
+}
@@ -1,8 +1,11 @@
 //! Ergo data type
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::rc::Rc;
 
+use crate::ast::avl_tree_data::AvlTreeData;
+use crate::big_integer::BigInteger;
 use crate::chain::ergo_box::ErgoBox;
 // use crate::eval::context::Context;
 use crate::eval::context::Context;
@@ -16,6 +19,7 @@ use crate::types::stype::SType;
 
 use super::constant::TryExtractFrom;
 use super::constant::TryExtractFromError;
+use super::func_value::FuncValue;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// Collection for primitive values (i.e byte array)
@@ -51,10 +55,46 @@ impl Coll {
     /// Collection element type
     pub fn elem_tpe(&self) -> &SType {
         match self {
-            cp @ Coll::Primitive(_) => cp.elem_tpe(),
+            Coll::Primitive(cp) => cp.elem_tpe(),
             Coll::NonPrimitive { elem_tpe, .. } => elem_tpe,
         }
     }
+
+    /// Number of elements in the collection
+    pub fn len(&self) -> usize {
+        match self {
+            Coll::Primitive(CollPrim::CollByte(bs)) => bs.len(),
+            Coll::NonPrimitive { v, .. } => v.len(),
+        }
+    }
+
+    /// Returns `true` if the collection has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element at the given index (if any)
+    pub fn get(&self, index: usize) -> Option<Value> {
+        match self {
+            Coll::Primitive(CollPrim::CollByte(bs)) => bs.get(index).map(|b| Value::Byte(*b)),
+            Coll::NonPrimitive { v, .. } => v.get(index).cloned(),
+        }
+    }
+
+    /// Elements in `from..until` range (bounds are clamped to the collection length)
+    pub fn slice(&self, from: usize, until: usize) -> Coll {
+        let until = until.min(self.len());
+        let from = from.min(until);
+        match self {
+            Coll::Primitive(CollPrim::CollByte(bs)) => {
+                Coll::Primitive(CollPrim::CollByte(bs[from..until].to_vec()))
+            }
+            Coll::NonPrimitive { elem_tpe, v } => Coll::NonPrimitive {
+                elem_tpe: elem_tpe.clone(),
+                v: v[from..until].to_vec(),
+            },
+        }
+    }
 }
 
 /// Constant value
@@ -71,7 +111,7 @@ pub enum Value {
     /// Long
     Long(i64),
     /// Big integer
-    BigInt,
+    BigInt(BigInteger),
     /// GroupElement
     GroupElement(Box<EcPoint>),
     /// Sigma property
@@ -79,13 +119,63 @@ pub enum Value {
     /// Box
     CBox(Box<ErgoBox>),
     /// AVL tree
-    AvlTree,
+    AvlTree(Box<AvlTreeData>),
     /// Collection of values of the same type
     Coll(Coll),
     /// Tuple (arbitrary type values)
     Tup(Vec<Value>),
+    /// Optional value
+    Opt(Box<Option<Value>>),
     /// Transaction(and blockchain) context info
     Context(Rc<Context>),
+    /// Function value (lambda), passed as an argument to collection methods
+    Lambda(FuncValue),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_items(f: &mut fmt::Formatter<'_>, items: &[Value]) -> fmt::Result {
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", item)?;
+            }
+            Ok(())
+        }
+        match self {
+            Value::Boolean(v) => write!(f, "{}", v),
+            Value::Byte(v) => write!(f, "{}", v),
+            Value::Short(v) => write!(f, "{}", v),
+            Value::Int(v) => write!(f, "{}", v),
+            Value::Long(v) => write!(f, "{}", v),
+            Value::BigInt(v) => write!(f, "{}", v),
+            Value::GroupElement(v) => write!(f, "{:?}", v),
+            Value::SigmaProp(v) => write!(f, "{:?}", v),
+            Value::CBox(v) => write!(f, "Box({})", base16::encode_lower(v.box_id().0 .0.as_ref())),
+            Value::AvlTree(v) => write!(f, "{:?}", v),
+            Value::Coll(Coll::Primitive(CollPrim::CollByte(bytes))) => {
+                let bytes: Vec<u8> = bytes.iter().map(|b| *b as u8).collect();
+                write!(f, "0x{}", base16::encode_lower(&bytes))
+            }
+            Value::Coll(Coll::NonPrimitive { v, .. }) => {
+                write!(f, "[")?;
+                write_items(f, v)?;
+                write!(f, "]")
+            }
+            Value::Tup(items) => {
+                write!(f, "(")?;
+                write_items(f, items)?;
+                write!(f, ")")
+            }
+            Value::Opt(opt) => match opt.as_ref() {
+                Some(v) => write!(f, "Some({})", v),
+                None => write!(f, "None"),
+            },
+            Value::Context(_) => write!(f, "CONTEXT"),
+            Value::Lambda(_) => write!(f, "<lambda>"),
+        }
+    }
 }
 
 impl Value {
@@ -93,6 +183,14 @@ impl Value {
     pub fn sigma_prop(prop: SigmaProp) -> Value {
         Value::SigmaProp(Box::new(prop))
     }
+
+    /// Element type if the value is a collection, `None` otherwise
+    pub fn coll_elem_tpe(&self) -> Option<SType> {
+        match self {
+            Value::Coll(coll) => Some(coll.elem_tpe().clone()),
+            _ => None,
+        }
+    }
 }
 
 impl Into<Value> for bool {
@@ -125,12 +223,47 @@ impl Into<Value> for i64 {
     }
 }
 
+// `From` would conflict with the blanket `Into<Value>` for `Vec<T>` below
+#[allow(clippy::from_over_into)]
+impl Into<Value> for Vec<i8> {
+    fn into(self) -> Value {
+        Value::Coll(Coll::Primitive(CollPrim::CollByte(self)))
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Value> for Vec<u8> {
+    fn into(self) -> Value {
+        Value::Coll(Coll::Primitive(CollPrim::CollByte(
+            self.into_iter().map(|b| b as i8).collect(),
+        )))
+    }
+}
+
+impl<A: Into<Value>, B: Into<Value>> From<(A, B)> for Value {
+    fn from(v: (A, B)) -> Self {
+        Value::Tup(vec![v.0.into(), v.1.into()])
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(v: Option<T>) -> Self {
+        Value::Opt(Box::new(v.map(|i| i.into())))
+    }
+}
+
 impl<T: Into<SigmaProp>> From<T> for Value {
     fn from(t: T) -> Self {
         Value::SigmaProp(Box::new(t.into()))
     }
 }
 
+impl From<BigInteger> for Value {
+    fn from(v: BigInteger) -> Self {
+        Value::BigInt(v)
+    }
+}
+
 impl Into<Value> for EcPoint {
     fn into(self) -> Value {
         Value::GroupElement(Box::new(self))
@@ -151,6 +284,11 @@ impl StoredNonPrimitive for i16 {}
 impl StoredNonPrimitive for i32 {}
 impl StoredNonPrimitive for i64 {}
 impl StoredNonPrimitive for ErgoBox {}
+impl StoredNonPrimitive for EcPoint {}
+impl StoredNonPrimitive for SigmaProp {}
+impl<T> StoredNonPrimitive for Vec<T> {}
+impl<A, B> StoredNonPrimitive for (A, B) {}
+impl<T> StoredNonPrimitive for Option<T> {}
 
 impl<T: LiftIntoSType + StoredNonPrimitive + Into<Value>> Into<Value> for Vec<T> {
     fn into(self) -> Value {
@@ -209,6 +347,18 @@ impl TryExtractFrom<Value> for i64 {
     }
 }
 
+impl TryExtractFrom<Value> for BigInteger {
+    fn try_extract_from(cv: Value) -> Result<BigInteger, TryExtractFromError> {
+        match cv {
+            Value::BigInt(v) => Ok(v),
+            _ => Err(TryExtractFromError(format!(
+                "expected BigInteger, found {:?}",
+                cv
+            ))),
+        }
+    }
+}
+
 impl TryExtractFrom<Value> for EcPoint {
     fn try_extract_from(cv: Value) -> Result<EcPoint, TryExtractFromError> {
         match cv {
@@ -260,6 +410,67 @@ impl<T: TryExtractFrom<Value> + StoredNonPrimitive> TryExtractFrom<Value> for Ve
     }
 }
 
+impl TryExtractFrom<Value> for Vec<i8> {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Coll(Coll::Primitive(CollPrim::CollByte(bs))) => Ok(bs),
+            _ => Err(TryExtractFromError(format!(
+                "expected {:?}, found {:?}",
+                std::any::type_name::<Self>(),
+                v
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for Vec<u8> {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        use crate::util::FromVecI8;
+        Vec::<i8>::try_extract_from(v).map(Vec::<u8>::from_vec_i8)
+    }
+}
+
+impl<A: TryExtractFrom<Value>, B: TryExtractFrom<Value>> TryExtractFrom<Value> for (A, B) {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Tup(items) if items.len() == 2 => {
+                let mut iter = items.into_iter();
+                // length is checked above
+                let a = iter.next().unwrap();
+                let b = iter.next().unwrap();
+                Ok((A::try_extract_from(a)?, B::try_extract_from(b)?))
+            }
+            _ => Err(TryExtractFromError(format!(
+                "expected {:?}, found {:?}",
+                std::any::type_name::<Self>(),
+                v
+            ))),
+        }
+    }
+}
+
+impl<T: TryExtractFrom<Value>> TryExtractFrom<Value> for Option<T> {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Opt(opt) => opt.map(T::try_extract_from).transpose(),
+            _ => Err(TryExtractFromError(format!(
+                "expected {:?}, found {:?}",
+                std::any::type_name::<Self>(),
+                v
+            ))),
+        }
+    }
+}
+
+impl TryExtractFrom<Value> for Coll {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Coll(coll) => Ok(coll),
+            _ => Err(TryExtractFromError(format!("expected Coll, found {:?}", v))),
+        }
+    }
+}
+
 impl TryFrom<Value> for ProveDlog {
     type Error = TryExtractFromError;
     fn try_from(cv: Value) -> Result<Self, Self::Error> {
@@ -281,6 +492,18 @@ impl TryFrom<Value> for ProveDlog {
     }
 }
 
+impl TryExtractFrom<Value> for FuncValue {
+    fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
+        match v {
+            Value::Lambda(f) => Ok(f),
+            _ => Err(TryExtractFromError(format!(
+                "expected Lambda, found {:?}",
+                v
+            ))),
+        }
+    }
+}
+
 impl TryExtractFrom<Value> for Rc<Context> {
     fn try_extract_from(v: Value) -> Result<Self, TryExtractFromError> {
         match v {
@@ -292,3 +515,25 @@ impl TryExtractFrom<Value> for Rc<Context> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coll_elem_tpe_byte() {
+        let v: Value = vec![1i8, 2].into();
+        assert_eq!(v.coll_elem_tpe(), Some(SType::SByte));
+    }
+
+    #[test]
+    fn coll_elem_tpe_long() {
+        let v: Value = vec![1i64, 2].into();
+        assert_eq!(v.coll_elem_tpe(), Some(SType::SLong));
+    }
+
+    #[test]
+    fn coll_elem_tpe_non_coll() {
+        assert_eq!(Value::Long(1).coll_elem_tpe(), None);
+    }
+}
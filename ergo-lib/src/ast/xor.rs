@@ -0,0 +1,19 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// Byte-wise XOR of two byte arrays of the same length (`xor(left, right)`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Xor {
+    /// First byte array
+    pub left: Box<Expr>,
+    /// Second byte array
+    pub right: Box<Expr>,
+}
+
+impl Xor {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::XOR
+    }
+}
@@ -0,0 +1,17 @@
+use crate::serialization::op_code::OpCode;
+
+use super::expr::Expr;
+
+/// XOR of all the elements of the collection (`xorOf`)
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct XorOf {
+    /// Collection of booleans
+    pub input: Box<Expr>,
+}
+
+impl XorOf {
+    /// Code (serialization)
+    pub fn op_code(&self) -> OpCode {
+        OpCode::XOR_OF
+    }
+}
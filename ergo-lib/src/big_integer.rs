@@ -1,12 +1,252 @@
-// use k256::Scalar;
-use num_bigint::BigInt;
-// use std::convert::TryFrom;
+//! Big integer (`BigInt` of ErgoScript)
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor};
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+use num_bigint::{BigInt, Sign};
+use thiserror::Error;
+
+/// Signed integer limited to 256 bits (two's complement)
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct BigInteger(BigInt);
 
-impl From<BigInt> for BigInteger {
-    fn from(b: BigInt) -> Self {
-        BigInteger(b)
+/// Errors on creating a [`BigInteger`]
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum BigIntegerError {
+    /// No bytes to make a value from
+    #[error("Empty byte array")]
+    EmptyBytes,
+    /// Value does not fit into 256 bits
+    #[error("Value {0} is out of 256-bit range")]
+    OutOfBounds(BigInt),
+}
+
+impl BigInteger {
+    /// Max size of the two's complement representation in bytes
+    pub const MAX_SIZE_BYTES: usize = 32;
+
+    /// Parse from the big-endian two's complement representation
+    /// (same as `new java.math.BigInteger(bytes)`)
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Result<Self, BigIntegerError> {
+        if bytes.is_empty() {
+            return Err(BigIntegerError::EmptyBytes);
+        }
+        BigInteger::try_from(BigInt::from_signed_bytes_be(bytes))
+    }
+
+    /// Big-endian two's complement representation (same as `java.math.BigInteger.toByteArray`)
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        self.0.to_signed_bytes_be()
+    }
+
+    /// Returns `true` if the value is zero
+    pub fn is_zero(&self) -> bool {
+        self.0 == BigInt::from(0)
+    }
+
+    /// Addition, `None` if the result is out of 256-bit range
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        BigInteger::try_from(&self.0 + &other.0).ok()
+    }
+
+    /// Subtraction, `None` if the result is out of 256-bit range
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        BigInteger::try_from(&self.0 - &other.0).ok()
+    }
+
+    /// Multiplication, `None` if the result is out of 256-bit range
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        BigInteger::try_from(&self.0 * &other.0).ok()
+    }
+
+    /// Division (rounds toward zero), `None` on division by zero or if the result is out of 256-bit range
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        BigInteger::try_from(&self.0 / &other.0).ok()
+    }
+
+    /// Modulus (non-negative, same as `java.math.BigInteger.mod`), `None` if the modulus is not positive
+    pub fn checked_mod(&self, other: &Self) -> Option<Self> {
+        if other.0.sign() != Sign::Plus {
+            return None;
+        }
+        BigInteger::try_from(((&self.0 % &other.0) + &other.0) % &other.0).ok()
+    }
+
+    /// Left shift, `None` if the result is out of 256-bit range
+    pub fn checked_shl(&self, bits: usize) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self.clone());
+        }
+        if bits >= BigInteger::MAX_SIZE_BYTES * 8 {
+            return None;
+        }
+        BigInteger::try_from(&self.0 << bits).ok()
+    }
+
+    /// Arithmetic right shift (rounds toward negative infinity, same as `java.math.BigInteger.shiftRight`)
+    pub fn shr(&self, bits: usize) -> Self {
+        BigInteger(&self.0 >> bits.min(BigInteger::MAX_SIZE_BYTES * 8))
+    }
+
+    /// Convert to `i64`, `None` if the value does not fit
+    pub fn to_i64(&self) -> Option<i64> {
+        i64::try_from(&self.0).ok()
+    }
+
+    /// Negation, `None` if the result is out of 256-bit range (for the minimal value)
+    pub fn checked_neg(&self) -> Option<Self> {
+        BigInteger::try_from(-&self.0).ok()
+    }
+}
+
+impl TryFrom<BigInt> for BigInteger {
+    type Error = BigIntegerError;
+
+    fn try_from(b: BigInt) -> Result<Self, Self::Error> {
+        if b.to_signed_bytes_be().len() > BigInteger::MAX_SIZE_BYTES {
+            Err(BigIntegerError::OutOfBounds(b))
+        } else {
+            Ok(BigInteger(b))
+        }
+    }
+}
+
+impl From<i64> for BigInteger {
+    fn from(v: i64) -> Self {
+        BigInteger(BigInt::from(v))
+    }
+}
+
+impl From<BigInteger> for BigInt {
+    fn from(b: BigInteger) -> Self {
+        b.0
+    }
+}
+
+// bitwise operations use two's complement semantics (same as java.math.BigInteger)
+// and the result of two 256-bit values always fits into 256 bits
+impl BitAnd for BigInteger {
+    type Output = BigInteger;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        BigInteger(self.0 & other.0)
+    }
+}
+
+impl BitOr for BigInteger {
+    type Output = BigInteger;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        BigInteger(self.0 | other.0)
+    }
+}
+
+impl BitXor for BigInteger {
+    type Output = BigInteger;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        BigInteger(self.0 ^ other.0)
+    }
+}
+
+impl fmt::Display for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn signed_bytes_roundtrip() {
+        for bytes in [vec![0x01, 0x00], vec![0xff], vec![0x00], vec![0x80; 32]] {
+            assert_eq!(
+                BigInteger::from_signed_bytes_be(&bytes)
+                    .unwrap()
+                    .to_signed_bytes_be(),
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn checked_arith() {
+        let max = BigInteger::try_from((BigInt::from(1) << 255usize) - 1).unwrap();
+        let one = BigInteger::from(1i64);
+        let zero = BigInteger::from(0i64);
+        assert!(max.checked_add(&one).is_none());
+        assert_eq!(
+            max.checked_sub(&one).unwrap().checked_add(&one).unwrap(),
+            max
+        );
+        assert!(max.checked_mul(&BigInteger::from(2i64)).is_none());
+        assert!(one.checked_div(&zero).is_none());
+        assert!(one.checked_mod(&zero).is_none());
+        assert!(one.checked_mod(&BigInteger::from(-2i64)).is_none());
+        let min = BigInteger::try_from(-(BigInt::from(1) << 255usize)).unwrap();
+        assert!(min.checked_neg().is_none());
+        assert_eq!(BigInteger::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert!(max.to_i64().is_none());
+        assert_eq!(max.checked_neg().unwrap().checked_neg().unwrap(), max);
+        assert_eq!(
+            BigInteger::from(-7i64)
+                .checked_mod(&BigInteger::from(2i64))
+                .unwrap(),
+            BigInteger::from(1i64)
+        );
+    }
+
+    #[test]
+    fn bit_ops_twos_complement() {
+        let a = BigInteger::from(-6i64);
+        let b = BigInteger::from(5i64);
+        assert_eq!(a.clone() & b.clone(), BigInteger::from(-6i64 & 5));
+        assert_eq!(a.clone() | b.clone(), BigInteger::from(-6i64 | 5));
+        assert_eq!(a ^ b, BigInteger::from(-6i64 ^ 5));
+        let min = BigInteger::try_from(-(BigInt::from(1) << 255usize)).unwrap();
+        let minus_one = BigInteger::from(-1i64);
+        assert_eq!(
+            min.clone() ^ minus_one,
+            BigInteger::try_from((BigInt::from(1) << 255usize) - 1).unwrap()
+        );
+        assert_eq!(min.clone() | BigInteger::from(0i64), min);
+    }
+
+    #[test]
+    fn shifts() {
+        let one = BigInteger::from(1i64);
+        let max = BigInteger::try_from((BigInt::from(1) << 255usize) - 1).unwrap();
+        assert_eq!(
+            one.checked_shl(254),
+            BigInteger::try_from(BigInt::from(1) << 254usize).ok()
+        );
+        assert!(one.checked_shl(255).is_none());
+        assert!(one.checked_shl(usize::MAX).is_none());
+        assert_eq!(
+            BigInteger::from(0i64).checked_shl(1000),
+            Some(BigInteger::from(0i64))
+        );
+        assert_eq!(BigInteger::from(-5i64).shr(1), BigInteger::from(-3i64));
+        assert_eq!(
+            BigInteger::from(-5i64).shr(usize::MAX),
+            BigInteger::from(-1i64)
+        );
+        assert_eq!(max.shr(255), BigInteger::from(0i64));
+    }
+
+    #[test]
+    fn bounds() {
+        let max: BigInt = (BigInt::from(1) << 255usize) - 1;
+        let min: BigInt = -(BigInt::from(1) << 255usize);
+        assert!(BigInteger::try_from(max.clone()).is_ok());
+        assert!(BigInteger::try_from(min.clone()).is_ok());
+        assert!(BigInteger::try_from(max + 1).is_err());
+        assert!(BigInteger::try_from(min - 1).is_err());
     }
 }
@@ -3,9 +3,11 @@
 #[cfg(feature = "json")]
 mod json;
 
+mod ad_digest;
 mod base16_bytes;
 mod digest32;
 
+pub use ad_digest::*;
 pub use base16_bytes::Base16DecodedBytes;
 pub use base16_bytes::Base16EncodedBytes;
 pub use digest32::*;
@@ -14,5 +16,6 @@ pub mod address;
 pub mod contract;
 pub mod ergo_box;
 pub mod ergo_state_context;
+pub mod header;
 pub mod token;
 pub mod transaction;
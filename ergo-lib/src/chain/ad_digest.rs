@@ -0,0 +1,147 @@
+use crate::{
+    chain::{Base16DecodedBytes, Base16EncodedBytes},
+    serialization::{sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable},
+};
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+use sigma_ser::vlq_encode;
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Digest of the authenticated dynamic dictionary (AVL+ tree root hash followed by the tree height)
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(into = "Base16EncodedBytes", try_from = "Base16DecodedBytes")
+)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct ADDigest(pub Box<[u8; ADDigest::SIZE]>);
+
+impl ADDigest {
+    /// Digest size 33 bytes (32 bytes of the root hash and 1 byte of the tree height)
+    pub const SIZE: usize = 33;
+
+    /// All zeros
+    pub fn zero() -> ADDigest {
+        ADDigest(Box::new([0u8; ADDigest::SIZE]))
+    }
+}
+
+impl From<[u8; ADDigest::SIZE]> for ADDigest {
+    fn from(bytes: [u8; ADDigest::SIZE]) -> Self {
+        ADDigest(Box::new(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for ADDigest {
+    type Error = ADDigestError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let arr: [u8; ADDigest::SIZE] = bytes
+            .try_into()
+            .map_err(|_| ADDigestError::InvalidSize(bytes.len()))?;
+        Ok(ADDigest(Box::new(arr)))
+    }
+}
+
+impl TryFrom<Base16DecodedBytes> for ADDigest {
+    type Error = ADDigestError;
+    fn try_from(bytes: Base16DecodedBytes) -> Result<Self, Self::Error> {
+        ADDigest::try_from(bytes.0.as_slice())
+    }
+}
+
+impl From<ADDigest> for Base16EncodedBytes {
+    fn from(v: ADDigest) -> Self {
+        Base16EncodedBytes::new(v.0.as_ref())
+    }
+}
+
+impl fmt::Display for ADDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base16::encode_lower(self.0.as_ref()))
+    }
+}
+
+impl FromStr for ADDigest {
+    type Err = ADDigestError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Base16DecodedBytes::try_from(s)?;
+        ADDigest::try_from(bytes)
+    }
+}
+
+impl SigmaSerializable for ADDigest {
+    fn sigma_serialize<W: vlq_encode::WriteSigmaVlqExt>(&self, w: &mut W) -> Result<(), io::Error> {
+        w.write_all(self.0.as_ref())?;
+        Ok(())
+    }
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let mut bytes = [0; ADDigest::SIZE];
+        r.read_exact(&mut bytes)?;
+        Ok(Self(bytes.into()))
+    }
+}
+
+/// Errors on ADDigest decoding
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ADDigestError {
+    /// Invalid byte array size
+    #[error("Invalid byte array size ({0}), expected 33 bytes")]
+    InvalidSize(usize),
+    /// Invalid Base16 encoding
+    #[error("Base16 decoding error: {0}")]
+    Base16DecodingError(#[from] base16::DecodeError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    const DIGEST_STR: &str = "c0d0b5eafd07b22487dac66628669c42a242b90bef3e1fcdc76d83140d58b6bc0e";
+
+    #[test]
+    fn valid_length() {
+        assert!(ADDigest::try_from(&[1u8; ADDigest::SIZE][..]).is_ok());
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert_eq!(
+            ADDigest::try_from(&[1u8; 32][..]),
+            Err(ADDigestError::InvalidSize(32))
+        );
+        assert_eq!(
+            ADDigest::try_from(&[1u8; 34][..]),
+            Err(ADDigestError::InvalidSize(34))
+        );
+        assert_eq!(
+            ADDigest::from_str("c0d0b5"),
+            Err(ADDigestError::InvalidSize(3))
+        );
+    }
+
+    #[test]
+    fn invalid_base16() {
+        assert!(matches!(
+            ADDigest::from_str("xyz"),
+            Err(ADDigestError::Base16DecodingError(_))
+        ));
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let digest = ADDigest::from_str(DIGEST_STR).unwrap();
+        assert_eq!(digest.to_string(), DIGEST_STR);
+    }
+
+    #[test]
+    fn ser_roundtrip() {
+        let digest = ADDigest::from_str(DIGEST_STR).unwrap();
+        assert_eq![sigma_serialize_roundtrip(&digest), digest];
+    }
+}
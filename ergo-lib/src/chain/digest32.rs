@@ -20,7 +20,7 @@ use thiserror::Error;
     feature = "json",
     serde(into = "Base16EncodedBytes", try_from = "Base16DecodedBytes")
 )]
-#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 #[cfg_attr(test, derive(Arbitrary))]
 pub struct Digest32(pub Box<[u8; Digest32::SIZE]>);
 
@@ -19,6 +19,7 @@ use super::{
 };
 
 use crate::{
+    ast::constant::Constant,
     ergo_tree::ErgoTree,
     serialization::{
         ergo_box::{parse_box_with_indexed_digests, serialize_box_with_indexed_digests},
@@ -30,9 +31,11 @@ use crate::{
 use indexmap::IndexSet;
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 #[cfg(feature = "json")]
 use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 use std::io;
 #[cfg(feature = "json")]
 use thiserror::Error;
@@ -121,6 +124,40 @@ impl ErgoBox {
         self.box_id.clone()
     }
 
+    /// Get register value by register index (0 is R0 .. 9 is R9)
+    /// R0 - value, R1 - script bytes, R2 - tokens, R3 - (creation height, tx id ++ index)
+    pub fn get_register(&self, id: u8) -> Option<Constant> {
+        match id {
+            0 => Some(i64::from(self.value).into()),
+            1 => Some(self.ergo_tree.sigma_serialize_bytes().into()),
+            2 => Some(
+                self.tokens
+                    .iter()
+                    .map(|t| {
+                        let id: Vec<u8> = t.token_id.0 .0.to_vec();
+                        let amount: i64 = t.amount.into();
+                        (id, amount)
+                    })
+                    .collect::<Vec<(Vec<u8>, i64)>>()
+                    .into(),
+            ),
+            3 => Some(self.creation_info().into()),
+            _ => self
+                .additional_registers
+                .get_ordered_values()
+                .get(id as usize - NonMandatoryRegisterId::START_INDEX)
+                .cloned(),
+        }
+    }
+
+    /// Creation height and id of the creating transaction followed by the box index
+    /// in its outputs (big-endian), as stored in R3
+    pub fn creation_info(&self) -> (i32, Vec<u8>) {
+        let mut ref_bytes: Vec<u8> = self.transaction_id.0 .0.to_vec();
+        ref_bytes.extend_from_slice(&self.index.to_be_bytes());
+        (self.creation_height as i32, ref_bytes)
+    }
+
     /// Create ErgoBox from ErgoBoxCandidate by adding transaction id
     /// and index of the box in the transaction
     pub fn from_box_candidate(
@@ -239,6 +276,25 @@ impl ErgoBoxId for ErgoBox {
     }
 }
 
+// box id is a hash of the box content, so equal boxes have equal ids
+impl Hash for ErgoBox {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.box_id.hash(state);
+    }
+}
+
+impl PartialOrd for ErgoBox {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ErgoBox {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.box_id.cmp(&other.box_id)
+    }
+}
+
 /// Errors on parsing ErgoBox from JSON
 #[cfg(feature = "json")]
 #[derive(Error, PartialEq, Eq, Debug, Clone)]
@@ -302,6 +358,8 @@ impl SigmaSerializable for ErgoBox {
 /// Contains the same fields as `ErgoBox`, except if transaction id and index,
 /// that will be calculated after full transaction formation.
 /// Use [`box_builder::ErgoBoxCandidateBuilder`] to create an instance.
+/// Candidates are equal if all their fields are equal. The order of tokens matters,
+/// since it changes the serialized box (and the id of the box made from the candidate).
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct ErgoBoxCandidate {
     /// amount of money associated with the box
@@ -319,6 +377,12 @@ pub struct ErgoBoxCandidate {
 }
 
 impl ErgoBoxCandidate {
+    /// Place the box candidate into the transaction with the given id at the given output index
+    /// (box id is calculated from the resulting box)
+    pub fn into_box(self, tx_id: TxId, index: u16) -> ErgoBox {
+        ErgoBox::from_box_candidate(&self, tx_id, index)
+    }
+
     /// Box serialization with token ids optionally saved in transaction
     /// (in this case only token index is saved)
     pub fn serialize_body_with_indexed_digests<W: SigmaByteWrite>(
@@ -370,6 +434,7 @@ impl From<ErgoBox> for ErgoBoxCandidate {
 #[cfg(test)]
 mod tests {
 
+    use super::box_builder::ErgoBoxCandidateBuilder;
     use super::box_value::tests::ArbBoxValueRange;
     use super::*;
     use crate::serialization::sigma_serialize_roundtrip;
@@ -430,6 +495,23 @@ mod tests {
         type Strategy = BoxedStrategy<Self>;
     }
 
+    #[test]
+    fn box_set_dedup_by_id() {
+        use std::collections::{BTreeSet, HashSet};
+        let b1 = force_any_val::<ErgoBox>();
+        let b2 = ErgoBoxCandidate::from(b1.clone())
+            .into_box(b1.transaction_id.clone(), b1.index.wrapping_add(1));
+        let boxes = vec![b1.clone(), b2.clone(), b1.clone()];
+        let hash_set: HashSet<ErgoBox> = boxes.iter().cloned().collect();
+        assert_eq!(hash_set.len(), 2);
+        let btree_set: BTreeSet<ErgoBox> = boxes.into_iter().collect();
+        assert_eq!(btree_set.len(), 2);
+        let ids: Vec<BoxId> = btree_set.iter().map(|b| b.box_id()).collect();
+        let mut expected = vec![b1.box_id(), b2.box_id()];
+        expected.sort();
+        assert_eq!(ids, expected);
+    }
+
     #[test]
     fn test_sum_tokens_repeating_token_id() {
         let token = force_any_val::<Token>();
@@ -447,6 +529,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn candidate_into_box() {
+        let candidate = force_any_val::<ErgoBoxCandidate>();
+        let tx_id = force_any_val::<TxId>();
+        let b = candidate.clone().into_box(tx_id.clone(), 3);
+        assert_eq!(b.transaction_id, tx_id);
+        assert_eq!(b.index, 3);
+        assert_eq!(
+            b.box_id(),
+            BoxId(blake2b256_hash(&b.sigma_serialize_bytes()))
+        );
+        assert_ne!(b.box_id(), candidate.into_box(tx_id, 4).box_id());
+    }
+
+    #[test]
+    fn candidate_eq_token_order() {
+        let tree = force_any_val::<ErgoTree>();
+        let token1 = force_any_val::<Token>();
+        let token2 = force_any_val::<Token>();
+        let build = |tokens: &[&Token]| {
+            let mut builder =
+                ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, tree.clone(), 1);
+            tokens.iter().for_each(|t| builder.add_token((*t).clone()));
+            builder.build().unwrap()
+        };
+        assert_eq!(build(&[&token1, &token2]), build(&[&token1, &token2]));
+        let reordered = build(&[&token2, &token1]);
+        assert_ne!(build(&[&token1, &token2]), reordered);
+        assert_ne!(
+            build(&[&token1, &token2]).sigma_serialize_bytes(),
+            reordered.sigma_serialize_bytes()
+        );
+    }
+
     proptest! {
 
         #[test]
@@ -1,7 +1,7 @@
 //! ErgoBoxCandidate builder
 
 use std::collections::HashMap;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use crate::ast::constant::Constant;
 use crate::chain::token::Token;
@@ -102,13 +102,17 @@ impl ErgoBoxCandidateBuilder {
         Ok(b.sigma_serialize_bytes().len())
     }
 
-    /// Calculate minimal box value for the current box serialized size(in bytes)
+    /// Calculate minimal box value for the current box serialized size(in bytes).
+    /// Returns [`BoxValue::SAFE_USER_MIN`] if the size-based minimum is lower.
     pub fn calc_min_box_value(&self) -> Result<BoxValue, ErgoBoxCandidateBuilderError> {
         let box_size_bytes = self.calc_box_size_bytes()?;
-        Ok(
-            BoxValue::try_from(box_size_bytes as i64 * BoxValue::MIN_VALUE_PER_BOX_BYTE as i64)
-                .unwrap(),
-        )
+        Ok(self.min_value_for_size(box_size_bytes))
+    }
+
+    /// Minimal box value for the given box size, but not less than [`BoxValue::SAFE_USER_MIN`]
+    fn min_value_for_size(&self, box_size_bytes: usize) -> BoxValue {
+        let raw = box_size_bytes as u64 * self.min_value_per_byte as u64;
+        BoxValue::try_from(raw.max(*BoxValue::SAFE_USER_MIN.as_u64())).unwrap()
     }
 
     /// Set register with a given id (R4-R9) to the given value
@@ -210,17 +214,20 @@ impl ErgoBoxCandidateBuilder {
             );
         }
         let regs = NonMandatoryRegisters::new(additional_registers)?;
-        let b = ErgoBoxCandidate {
+        Ok(ErgoBoxCandidate {
             value: self.value,
             ergo_tree: self.ergo_tree.clone(),
             tokens,
             additional_registers: regs,
             creation_height: self.creation_height,
-        };
+        })
+    }
+
+    /// Build the box candidate
+    pub fn build(self) -> Result<ErgoBoxCandidate, ErgoBoxCandidateBuilderError> {
+        let b = self.build_box()?;
         let box_size_bytes = b.sigma_serialize_bytes().len();
-        let min_box_value: BoxValue = (box_size_bytes as i64 * self.min_value_per_byte as i64)
-            .try_into()
-            .unwrap();
+        let min_box_value = self.min_value_for_size(box_size_bytes);
         if self.value >= min_box_value {
             Ok(b)
         } else {
@@ -230,20 +237,21 @@ impl ErgoBoxCandidateBuilder {
             })
         }
     }
-
-    /// Build the box candidate
-    pub fn build(self) -> Result<ErgoBoxCandidate, ErgoBoxCandidateBuilderError> {
-        self.build_box()
-    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::convert::TryInto;
+    use std::rc::Rc;
+
     use NonMandatoryRegisterId::*;
 
+    use crate::ast::expr::Expr;
     use crate::chain::token::TokenId;
+    use crate::sigma_protocol::sigma_boolean::ProveDlog;
     use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
 
     use super::*;
 
@@ -296,6 +304,63 @@ mod tests {
         assert!(builder.calc_min_box_value().unwrap() > BoxValue::MIN);
     }
 
+    #[test]
+    fn test_build_tiny_tree_with_safe_user_min() {
+        // sigmaProp(true), box is smaller than the theoretical minimal box size
+        let tree = ErgoTree::true_proposition();
+        let mut builder = ErgoBoxCandidateBuilder::new(BoxValue::MIN, tree, 1);
+        assert!(builder.calc_box_size_bytes().unwrap() < 30);
+        assert_eq!(
+            builder.calc_min_box_value().unwrap(),
+            BoxValue::SAFE_USER_MIN
+        );
+        assert!(builder.clone().build().is_err());
+        builder.set_value(BoxValue::SAFE_USER_MIN);
+        assert_eq!(builder.build().unwrap().value, BoxValue::SAFE_USER_MIN);
+    }
+
+    #[test]
+    fn test_custom_min_box_value_per_byte() {
+        let mut builder =
+            ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, force_any_val::<ErgoTree>(), 1);
+        let box_size_bytes = builder.calc_box_size_bytes().unwrap();
+        let min_value_per_byte = BoxValue::SAFE_USER_MIN.as_u64() / box_size_bytes as u64 + 1;
+        builder.set_min_box_value_per_byte(min_value_per_byte as u32);
+        let min_box_value = builder.calc_min_box_value().unwrap();
+        assert_eq!(
+            *min_box_value.as_u64(),
+            box_size_bytes as u64 * min_value_per_byte
+        );
+        assert!(builder.clone().build().is_err());
+        builder.set_value(min_box_value);
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_calc_min_box_value_safe_user_min() {
+        assert_eq!(u64::from(BoxValue::SAFE_USER_MIN), 1000000);
+        let builder = ErgoBoxCandidateBuilder::new(
+            BoxValue::SAFE_USER_MIN,
+            ErgoTree::from(Rc::new(Expr::Const(Constant {
+                tpe: SType::SSigmaProp,
+                v: force_any_val::<ProveDlog>().into(),
+            }))),
+            1,
+        );
+        assert!(
+            BoxValue::try_from(
+                builder.calc_box_size_bytes().unwrap() as i64
+                    * BoxValue::MIN_VALUE_PER_BOX_BYTE as i64
+            )
+            .unwrap()
+                < BoxValue::SAFE_USER_MIN
+        );
+        assert_eq!(
+            builder.calc_min_box_value().unwrap(),
+            BoxValue::SAFE_USER_MIN
+        );
+    }
+
     #[test]
     fn test_build_fail_box_value_too_low() {
         let builder = ErgoBoxCandidateBuilder::new(BoxValue::MIN, force_any_val::<ErgoTree>(), 1);
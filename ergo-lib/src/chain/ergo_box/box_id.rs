@@ -13,7 +13,7 @@ use crate::serialization::{
 use proptest_derive::Arbitrary;
 
 /// newtype for box ids
-#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cfg_attr(test, derive(Arbitrary))]
 pub struct BoxId(pub Digest32);
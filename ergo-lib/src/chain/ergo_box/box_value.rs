@@ -1,5 +1,7 @@
 //! Box value newtype
 
+#[cfg(feature = "json")]
+use crate::chain::json;
 use crate::chain::token::TokenAmountError;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
@@ -13,6 +15,7 @@ use thiserror::Error;
 /// Box value in nanoERGs with bound checks
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json", serde(try_from = "json::box_value::BoxValueJson"))]
 pub struct BoxValue(pub(crate) u64);
 
 impl BoxValue {
@@ -37,6 +40,9 @@ impl BoxValue {
     /// Number of units inside one ERGO (i.e. one ERG using nano ERG representation)
     pub const UNITS_PER_ERGO: u32 = 1000000000;
 
+    /// Total supply of ERGs (in nanoERGs) that will ever be emitted
+    pub const TOTAL_SUPPLY_RAW: u64 = 97739925 * BoxValue::UNITS_PER_ERGO as u64;
+
     /// create from u64 with bounds check
     pub fn new(v: u64) -> Result<BoxValue, BoxValueError> {
         BoxValue::try_from(v)
@@ -110,7 +116,9 @@ impl PartialOrd for BoxValue {
 impl TryFrom<u64> for BoxValue {
     type Error = BoxValueError;
     fn try_from(v: u64) -> Result<Self, Self::Error> {
-        if BoxValue::within_bounds(v) {
+        if v > BoxValue::MAX_RAW {
+            Err(BoxValueError::AboveMax(v))
+        } else if BoxValue::within_bounds(v) {
             Ok(BoxValue(v))
         } else {
             Err(BoxValueError::OutOfBounds(v))
@@ -161,6 +169,18 @@ pub enum BoxValueError {
     /// Overflow
     #[error("Overflow")]
     Overflow,
+    /// Value is zero
+    #[error("Value is zero")]
+    Zero,
+    /// Value is negative
+    #[error("Value is negative: {0}")]
+    Negative(i64),
+    /// Value exceeds the maximum box value
+    #[error(
+        "Value {0} exceeds the maximum box value ({} nanoERGs)",
+        BoxValue::MAX_RAW
+    )]
+    AboveMax(u64),
 }
 
 impl From<BoxValueError> for SerializationError {
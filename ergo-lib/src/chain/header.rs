@@ -0,0 +1,358 @@
+//! Block header
+
+use num_bigint::{BigInt, Sign};
+use std::convert::TryInto;
+
+use super::digest32::{blake2b256_hash, Digest32};
+use super::ADDigest;
+use crate::serialization::sigma_byte_writer::{SigmaByteWrite, SigmaByteWriter};
+use crate::serialization::SigmaSerializable;
+use crate::sigma_protocol::dlog_group::EcPoint;
+
+/// Block header
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Header {
+    /// Block version, to be increased on every soft and hardfork
+    pub version: u8,
+    /// Id of a parent block
+    pub parent_id: Digest32,
+    /// Hash of ADProofs for transactions in a block
+    pub ad_proofs_root: Digest32,
+    /// Root hash (for a Merkle tree) of transactions in a block
+    pub transactions_root: Digest32,
+    /// AvlTree of a state after block application
+    pub state_root: ADDigest,
+    /// Block timestamp (in milliseconds since beginning of Unix Epoch)
+    pub timestamp: u64,
+    /// Root hash (for a Merkle tree) of the extension section
+    pub extension_root: Digest32,
+    /// Proof-of-work target (difficulty encoded in the "compact" form)
+    pub n_bits: u64,
+    /// Block height
+    pub height: u32,
+    /// Votes for changes in system parameters
+    pub votes: [u8; 3],
+    /// Solution for the Autolykos PoW puzzle
+    pub autolykos_solution: AutolykosSolution,
+}
+
+/// Solution for the Autolykos PoW puzzle
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AutolykosSolution {
+    /// Public key of the miner
+    pub miner_pk: Box<EcPoint>,
+    /// Nonce (8 bytes)
+    pub nonce: Vec<u8>,
+    /// Distance between the pseudo-random number and the secret (Autolykos v1 only)
+    pub pow_distance: Option<BigInt>,
+}
+
+impl Header {
+    /// Serialized header fields which are hashed to get the PoW puzzle message
+    pub fn bytes_without_pow(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut w = SigmaByteWriter::new(&mut data, None);
+        self.serialize_without_pow(&mut w)
+            // writing into Vec<u8> cannot fail
+            .expect("serialization failed");
+        data
+    }
+
+    fn serialize_without_pow<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), std::io::Error> {
+        // reference implementation - https://github.com/ergoplatform/ergo/blob/f7b91c0be00531c6d042c10a8855149ca6924373/src/main/scala/org/ergoplatform/modifiers/history/HeaderSerializer.scala#L14-L14
+        w.put_u8(self.version)?;
+        self.parent_id.sigma_serialize(w)?;
+        self.ad_proofs_root.sigma_serialize(w)?;
+        self.transactions_root.sigma_serialize(w)?;
+        self.state_root.sigma_serialize(w)?;
+        w.put_u64(self.timestamp)?;
+        self.extension_root.sigma_serialize(w)?;
+        w.write_all(&(self.n_bits as u32).to_be_bytes())?;
+        w.put_u32(self.height)?;
+        w.write_all(&self.votes)?;
+        // for block version >= 2 this byte encodes the length of possible new fields
+        if self.version > 1 {
+            w.put_u8(0)?;
+        }
+        Ok(())
+    }
+
+    /// Difficulty decoded from the `n_bits` field
+    pub fn difficulty(&self) -> BigInt {
+        decode_compact_bits(self.n_bits)
+    }
+
+    /// PoW target `b` (group order divided by the difficulty), a valid solution
+    /// should have a hit below this value (`None` if the difficulty is not positive)
+    pub fn pow_target(&self) -> Option<BigInt> {
+        let difficulty = self.difficulty();
+        if difficulty.sign() == Sign::Plus {
+            Some(group_order() / difficulty)
+        } else {
+            None
+        }
+    }
+
+    /// Hit of the Autolykos v2 solution (`None` for Autolykos v1 headers, their solution
+    /// verification is not implemented and `pow_distance` alone cannot be trusted)
+    pub fn pow_hit(&self) -> Option<BigInt> {
+        if self.version == 1 {
+            None
+        } else {
+            Some(self.pow_hit_v2())
+        }
+    }
+
+    /// Check that the Autolykos solution is valid for the target encoded in `n_bits`
+    /// (always `false` for Autolykos v1 headers)
+    pub fn check_pow(&self) -> bool {
+        match (self.pow_hit(), self.pow_target()) {
+            (Some(hit), Some(target)) => hit < target,
+            _ => false,
+        }
+    }
+
+    fn pow_hit_v2(&self) -> BigInt {
+        // reference implementation - https://github.com/ergoplatform/ergo/blob/f7b91c0be00531c6d042c10a8855149ca6924373/src/main/scala/org/ergoplatform/mining/AutolykosPowScheme.scala#L114-L114
+        let msg = blake2b256_hash(&self.bytes_without_pow()).0.to_vec();
+        let nonce = &self.autolykos_solution.nonce;
+        let height_bytes = self.height.to_be_bytes();
+        let big_n = calc_big_n(self.version, self.height);
+        let big_m = calc_big_m();
+
+        let pre_i8 = BigInt::from_bytes_be(
+            Sign::Plus,
+            &blake2b256_hash(&[msg.as_slice(), nonce].concat()).0[24..],
+        );
+        let i = as_unsigned_byte_array(4, &(pre_i8 % big_n));
+        let f = blake2b256_hash(&[i.as_slice(), &height_bytes, &big_m].concat());
+        let seed = blake2b256_hash(&[&f.0[1..], msg.as_slice(), nonce].concat());
+
+        let f2 = gen_indexes(&seed, big_n)
+            .into_iter()
+            .fold(BigInt::from(0u32), |acc, idx| {
+                let elem =
+                    blake2b256_hash(&[&idx.to_be_bytes()[..], &height_bytes, &big_m].concat());
+                acc + BigInt::from_bytes_be(Sign::Plus, &elem.0[1..])
+            });
+        let hit = blake2b256_hash(&as_unsigned_byte_array(32, &f2));
+        BigInt::from_bytes_be(Sign::Plus, hit.0.as_ref())
+    }
+}
+
+/// Number of elements in one Autolykos solution
+const AUTOLYKOS_K: usize = 32;
+
+/// Initial table size `N = 2 ^ AUTOLYKOS_N`
+const AUTOLYKOS_N: u32 = 26;
+
+/// Order of the secp256k1 group
+fn group_order() -> BigInt {
+    BigInt::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        16,
+    )
+    // constant is a valid hex number
+    .unwrap()
+}
+
+/// Table size (`N`) for the given height
+fn calc_big_n(version: u8, height: u32) -> u32 {
+    let n_base = 2u32.pow(AUTOLYKOS_N);
+    if version == 1 {
+        n_base
+    } else {
+        // table stops to grow at this height
+        let height = u32::min(4_198_400, height);
+        // table grows by 5% every 50 * 1024 blocks starting from this height
+        let increase_start = 600 * 1024;
+        if height < increase_start {
+            n_base
+        } else {
+            let iters_number = (height - increase_start) / (50 * 1024) + 1;
+            (0..iters_number).fold(n_base, |acc, _| acc / 100 * 105)
+        }
+    }
+}
+
+/// Constant data hashed along with the indexes to increase the hash calculation time
+fn calc_big_m() -> Vec<u8> {
+    (0u64..1024)
+        .flat_map(|x| x.to_be_bytes().to_vec())
+        .collect()
+}
+
+/// `AUTOLYKOS_K` indexes in [0, `big_n`) derived from the seed
+fn gen_indexes(seed: &Digest32, big_n: u32) -> Vec<u32> {
+    let extended_hash: Vec<u8> = [&seed.0[..], &seed.0[..3]].concat();
+    (0..AUTOLYKOS_K)
+        .map(|i| {
+            // slice of 4 bytes, unwrap is safe
+            let bytes: [u8; 4] = extended_hash[i..(i + 4)].try_into().unwrap();
+            u32::from_be_bytes(bytes) % big_n
+        })
+        .collect()
+}
+
+/// Big-endian bytes of a non-negative number padded (or truncated) to the given length
+/// (port of BouncyCastle's `BigIntegers.asUnsignedByteArray`)
+fn as_unsigned_byte_array(length: usize, v: &BigInt) -> Vec<u8> {
+    let (_, bytes) = v.to_bytes_be();
+    if bytes.len() >= length {
+        bytes[(bytes.len() - length)..].to_vec()
+    } else {
+        let mut res = vec![0u8; length - bytes.len()];
+        res.extend(bytes);
+        res
+    }
+}
+
+/// Decode the difficulty from the "compact" form used in Bitcoin (`nBits`)
+/// see https://github.com/bitcoin/bitcoin/blob/7fcf53f7b4524572d1d0c9a5fdc388e87eb02416/src/arith_uint256.cpp#L206
+pub fn decode_compact_bits(n_bits: u64) -> BigInt {
+    let size = ((n_bits >> 24) & 0xFF) as usize;
+    if size == 0 {
+        return BigInt::from(0);
+    }
+    let mut buf = vec![0u8; size];
+    buf[0] = ((n_bits >> 16) & 0xFF) as u8;
+    if size >= 2 {
+        buf[1] = ((n_bits >> 8) & 0xFF) as u8;
+    }
+    if size >= 3 {
+        buf[2] = (n_bits & 0xFF) as u8;
+    }
+    let is_negative = buf[0] & 0x80 == 0x80;
+    if is_negative {
+        buf[0] &= 0x7f;
+        -BigInt::from_bytes_be(Sign::Plus, &buf)
+    } else {
+        BigInt::from_bytes_be(Sign::Plus, &buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::Base16DecodedBytes;
+    use crate::serialization::SigmaSerializable;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    fn digest(s: &str) -> Digest32 {
+        Digest32::try_from(Base16DecodedBytes::try_from(s).unwrap()).unwrap()
+    }
+
+    fn bytes(s: &str) -> Vec<u8> {
+        base16::decode(s).unwrap()
+    }
+
+    // synthetic header (not from the mainnet chain) with a valid PoW solution at height 614400
+    // (first increase of the table size), taken from https://github.com/ergoplatform/ergo/blob/f7b91c0be00531c6d042c10a8855149ca6924373/src/test/scala/org/ergoplatform/mining/AutolykosPowSchemeSpec.scala#L43-L43
+    fn synthetic_header_614400() -> Header {
+        Header {
+            version: 2,
+            parent_id: digest("ac2101807f0000ca01ff0119db227f202201007f62000177a080005d440896d0"),
+            ad_proofs_root: digest(
+                "5d3f80dcff7f5e7f59007294c180808d0158d1ff6ba10000f901c7f0ef87dcff",
+            ),
+            transactions_root: digest(
+                "f17fffacb6ff7f7f1180d2ff7f1e24ffffe1ff937f807f0797b9ff6ebdae007e",
+            ),
+            state_root: ADDigest::from_str(
+                "5c8c00b8403d3701557181c8df800001b6d5009e2201c6ff807d71808c00019780",
+            )
+            .unwrap(),
+            timestamp: 4928911477310178288,
+            extension_root: digest(
+                "1480887f80007f4b01cf7f013ff1ffff564a0000b9a54f00770e807f41ff88c0",
+            ),
+            n_bits: 37748736,
+            height: 614400,
+            votes: [0, 0, 0],
+            autolykos_solution: AutolykosSolution {
+                miner_pk: Box::new(
+                    EcPoint::sigma_parse_bytes(bytes(
+                        "03bedaee069ff4829500b3c07c4d5fe6b3ea3d3bf76c5c28c1d4dcdb1bed0ade0c",
+                    ))
+                    .unwrap(),
+                ),
+                nonce: bytes("0000000000003105"),
+                pow_distance: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_calc_big_n() {
+        let n_base = 2u32.pow(AUTOLYKOS_N);
+        assert_eq!(calc_big_n(1, 700000), n_base);
+        assert_eq!(calc_big_n(2, 600000), n_base);
+        assert_eq!(calc_big_n(2, 600 * 1024), 70464240);
+        assert_eq!(calc_big_n(2, 650 * 1024), 73987410);
+        assert_eq!(calc_big_n(2, 4198400), 2143944600);
+        assert_eq!(calc_big_n(2, 41984000), 2143944600);
+    }
+
+    #[test]
+    fn test_pow_target() {
+        let header = synthetic_header_614400();
+        assert_eq!(
+            header.pow_target(),
+            BigInt::parse_bytes(
+                b"7067388259113537318333190002971674063283542741642755394446115914399301849",
+                10
+            )
+        );
+    }
+
+    #[test]
+    fn test_pow_target_zero_difficulty() {
+        let mut header = synthetic_header_614400();
+        header.n_bits = 0;
+        assert_eq!(header.difficulty(), BigInt::from(0));
+        assert_eq!(header.pow_target(), None);
+        assert!(!header.check_pow());
+    }
+
+    #[test]
+    fn test_check_pow_valid() {
+        let header = synthetic_header_614400();
+        assert_eq!(
+            base16::encode_lower(blake2b256_hash(&header.bytes_without_pow()).0.as_ref()),
+            "548c3e602a8f36f8f2738f5f643b02425038044d98543a51cabaa9785e7e864f"
+        );
+        assert_eq!(
+            header.pow_hit().unwrap(),
+            BigInt::parse_bytes(
+                b"0002fcb113fe65e5754959872dfdbffea0489bf830beb4961ddc0e9e66a1412a",
+                16
+            )
+            .unwrap()
+        );
+        assert!(header.check_pow());
+    }
+
+    #[test]
+    fn test_check_pow_tampered_nonce() {
+        let mut header = synthetic_header_614400();
+        header.autolykos_solution.nonce = bytes("0000000000003106");
+        assert!(!header.check_pow());
+    }
+
+    #[test]
+    fn test_check_pow_v1_without_distance() {
+        let mut header = synthetic_header_614400();
+        header.version = 1;
+        assert!(!header.check_pow());
+    }
+
+    #[test]
+    fn test_check_pow_v1_forged_distance() {
+        let mut header = synthetic_header_614400();
+        header.version = 1;
+        header.autolykos_solution.pow_distance = Some(BigInt::from(1));
+        assert_eq!(header.pow_hit(), None);
+        assert!(!header.check_pow());
+    }
+}
@@ -38,21 +38,43 @@ pub mod ergo_tree {
     }
 }
 
+pub mod box_value {
+    use std::convert::TryFrom;
+
+    use crate::chain::ergo_box::{BoxValue, BoxValueError};
+    use serde::Deserialize;
+
+    /// Box value as it comes in JSON, before the bounds checks
+    #[derive(Deserialize, PartialEq, Eq, Debug, Clone)]
+    #[serde(untagged)]
+    pub enum BoxValueJson {
+        Unsigned(u64),
+        Signed(i64),
+    }
+
+    impl TryFrom<BoxValueJson> for BoxValue {
+        type Error = BoxValueError;
+
+        fn try_from(v: BoxValueJson) -> Result<Self, Self::Error> {
+            match v {
+                BoxValueJson::Signed(v) if v < 0 => Err(BoxValueError::Negative(v)),
+                BoxValueJson::Signed(v) => BoxValue::try_from(BoxValueJson::Unsigned(v as u64)),
+                BoxValueJson::Unsigned(0) => Err(BoxValueError::Zero),
+                BoxValueJson::Unsigned(v) => BoxValue::new(v),
+            }
+        }
+    }
+}
+
 pub mod ergo_box {
     use core::fmt;
     use serde::de::{self, MapAccess, Visitor};
     use serde::Deserializer;
-    use std::convert::TryFrom;
     use std::marker::PhantomData;
     use std::str::FromStr;
 
-    extern crate derive_more;
-    use derive_more::From;
-
     use crate::ast::constant::Constant;
-    use crate::chain::Base16DecodedBytes;
-    use crate::serialization::SerializationError;
-    use crate::serialization::SigmaSerializable;
+    use crate::ast::constant::ConstantParsingError;
     use crate::{
         chain::{
             ergo_box::{BoxId, BoxValue, NonMandatoryRegisters},
@@ -109,22 +131,12 @@ pub mod ergo_box {
         raw_value: Constant,
     }
 
-    use thiserror::Error;
-
-    #[derive(Error, PartialEq, Eq, Debug, Clone, From)]
-    pub enum ConstantParsingError {
-        #[error("Base16 decoding error: {0}")]
-        DecodeError(base16::DecodeError),
-        #[error("Deserialization error: {0}")]
-        DeserializationError(SerializationError),
-    }
-
     impl FromStr for RichConstant {
         type Err = ConstantParsingError;
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let bytes = Base16DecodedBytes::try_from(s)?;
-            let c = Constant::sigma_parse_bytes(bytes.into())?;
-            Ok(RichConstant { raw_value: c })
+            Ok(RichConstant {
+                raw_value: Constant::from_base16(s)?,
+            })
         }
     }
 
@@ -155,8 +167,9 @@ pub mod ergo_box {
             where
                 E: de::Error,
             {
-                FromStr::from_str(value)
-                    .map_err(|_| de::Error::custom("error parsing constant from string: {value}"))
+                FromStr::from_str(value).map_err(|e| {
+                    de::Error::custom(format!("error parsing constant from string: {}", e))
+                })
             }
 
             fn visit_map<M>(self, map: M) -> Result<T, M::Error>
@@ -217,14 +230,27 @@ pub mod transaction {
 
 #[cfg(test)]
 mod tests {
+    use crate::ast::box_methods::{BoxM, RegisterId};
+    use crate::ast::constant::{Constant, TryExtractFrom};
+    use crate::ast::expr::Expr;
+    use crate::ast::extract_creation_info::ExtractCreationInfo;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::value::Value;
     use crate::chain::transaction::unsigned::UnsignedTransaction;
-    use crate::sigma_protocol::prover::ContextExtension;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::eval::EvalError;
+    use crate::sigma_protocol::prover::{ContextExtension, ProofBytes, ProverResult};
+    use crate::test_util::force_any_val;
     use std::convert::TryInto;
 
     use super::super::ergo_box::*;
+    use super::super::token::Token;
     use super::super::transaction::*;
     use super::*;
+    use crate::types::stype::SType;
     use proptest::prelude::*;
+    use std::rc::Rc;
 
     proptest! {
 
@@ -256,6 +282,142 @@ mod tests {
 
     }
 
+    // `tx` of the node's `/wallet/transaction/sign` request body, hand-written after the node's
+    // OpenAPI schema (UnsignedErgoTransaction), not captured from a node; the data input id is
+    // made up (tx and output box ids are calculated from it)
+    const SYNTHETIC_UNSIGNED_TX_JSON: &str = r#"
+        {
+            "id": "ecaed7cca5e1365e8b4760366d40aac57d7faff13ec67c223acf8184b3ded412",
+            "inputs": [
+                {
+                    "boxId": "e56847ed19b3dc6b72828fcfb992fdf7310828cf291221269b7ffc72fd66706e",
+                    "extension": {
+                        "1": "040a"
+                    }
+                }
+            ],
+            "dataInputs": [
+                {
+                    "boxId": "9d88c3c8b9bb4f3dd0d3e41dbcc2c6fd45b4e5a4b8dcf1d0c7f7d8f1c0b7a6e5"
+                }
+            ],
+            "outputs": [
+                {
+                    "boxId": "0768c2a92c3a199c80e66c900db6d11c469198b6906229a27b0ad076955f1f80",
+                    "value": 1000000,
+                    "ergoTree": "0008cd0327e65711a59378c59359c3e1d0f7abe906479eccb76094e50fe79d743ccc15e6",
+                    "assets": [],
+                    "additionalRegisters": {
+                        "R4": "0504"
+                    },
+                    "creationHeight": 352126,
+                    "transactionId": "ecaed7cca5e1365e8b4760366d40aac57d7faff13ec67c223acf8184b3ded412",
+                    "index": 0
+                }
+            ]
+        }
+    "#;
+
+    #[test]
+    fn unsigned_tx_sign_request_body() {
+        let tx: UnsignedTransaction = serde_json::from_str(SYNTHETIC_UNSIGNED_TX_JSON).unwrap();
+        assert_eq!(tx.inputs[0].extension.values.get(&1), Some(&5i32.into()));
+        assert_eq!(
+            serde_json::to_value(&tx).unwrap(),
+            serde_json::from_str::<serde_json::Value>(SYNTHETIC_UNSIGNED_TX_JSON).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_token_amount() {
+        let json = r#"
+        {"tokenId": "2d554219a80c011cc51509e34fa4950965bb8e01de4d012536e766c9ca08bc2c", "amount": 1000}
+        "#;
+        let t: Token = serde_json::from_str(json).unwrap();
+        assert_eq!(u64::from(t.amount), 1000);
+    }
+
+    #[test]
+    fn parse_token_zero_amount() {
+        let json = r#"
+        {"tokenId": "2d554219a80c011cc51509e34fa4950965bb8e01de4d012536e766c9ca08bc2c", "amount": 0}
+        "#;
+        let t: Result<Token, _> = serde_json::from_str(json);
+        assert!(t.is_err());
+    }
+
+    #[test]
+    fn signed_tx_inputs_spending_proof() {
+        let tx = force_any_val::<Transaction>();
+        let mut extension = ContextExtension::empty();
+        extension.values.insert(1, 5i32.into());
+        let input = Input {
+            box_id: tx.inputs[0].box_id.clone(),
+            spending_proof: ProverResult {
+                proof: ProofBytes::Some(vec![1, 2, 3]),
+                extension,
+            },
+        };
+        let tx = Transaction::new(vec![input.clone()], tx.data_inputs, tx.output_candidates);
+        let j = serde_json::to_value(&tx).unwrap();
+        let inputs = j["inputs"].as_array().unwrap();
+        assert_eq!(inputs.len(), 1);
+        let input_json = inputs[0].as_object().unwrap();
+        assert_eq!(input_json.len(), 2);
+        assert_eq!(
+            input_json["boxId"],
+            serde_json::to_value(&input.box_id).unwrap()
+        );
+        let proof_json = input_json["spendingProof"].as_object().unwrap();
+        assert_eq!(proof_json.len(), 2);
+        assert_eq!(proof_json["proofBytes"], "010203");
+        assert_eq!(proof_json["extension"], serde_json::json!({"1": "040a"}));
+    }
+
+    #[test]
+    fn signed_tx_input_empty_extension_roundtrip() {
+        let tx = force_any_val::<Transaction>();
+        let input = Input {
+            box_id: tx.inputs[0].box_id.clone(),
+            spending_proof: ProverResult {
+                proof: ProofBytes::Some(vec![1, 2, 3]),
+                extension: ContextExtension::empty(),
+            },
+        };
+        let tx = Transaction::new(vec![input], tx.data_inputs, tx.output_candidates);
+        let j = serde_json::to_value(&tx).unwrap();
+        assert_eq!(
+            j["inputs"][0]["spendingProof"]["extension"],
+            serde_json::json!({})
+        );
+        let tx_parsed: Transaction = serde_json::from_value(j).unwrap();
+        assert_eq!(
+            tx_parsed.inputs[0].spending_proof.extension,
+            ContextExtension::empty()
+        );
+        assert_eq!(
+            tx_parsed.inputs[0].spending_proof.proof,
+            ProofBytes::Some(vec![1, 2, 3])
+        );
+        assert_eq!(tx_parsed, tx);
+    }
+
+    #[test]
+    fn parse_unsigned_input_without_extension() {
+        // the node's schema (ErgoTransactionUnsignedInput) only requires boxId
+        let json =
+            r#"{"boxId": "e56847ed19b3dc6b72828fcfb992fdf7310828cf291221269b7ffc72fd66706e"}"#;
+        let input: UnsignedInput = serde_json::from_str(json).unwrap();
+        assert_eq!(input.extension, ContextExtension::empty());
+    }
+
+    #[test]
+    fn parse_spending_proof_without_extension() {
+        let json = r#"{"proofBytes": "010203"}"#;
+        let proof: ProverResult = serde_json::from_str(json).unwrap();
+        assert_eq!(proof.extension, ContextExtension::empty());
+    }
+
     #[test]
     fn parse_registers() {
         let json = r#"
@@ -280,6 +442,45 @@ mod tests {
         assert!(regs.get(NonMandatoryRegisterId::R4).is_some());
     }
 
+    #[test]
+    fn registers_rich_and_compact_forms_roundtrip() {
+        let rich_json = r#"
+            {
+                "R4": {
+                    "decodedValue": "5",
+                    "valueType": "Int",
+                    "rawValue": "040a"
+                },
+                "R5": {
+                    "decodedValue": "Coll(1,2,3)",
+                    "valueType": "Coll[Byte]",
+                    "rawValue": "0e03010203"
+                },
+                "R6": {
+                    "decodedValue": "1000",
+                    "valueType": "Long",
+                    "rawValue": "05d00f"
+                }
+            }
+        "#;
+        let regs: NonMandatoryRegisters = serde_json::from_str(rich_json).unwrap();
+        let compact_json = serde_json::to_string(&regs).unwrap();
+        let compact: serde_json::Value = serde_json::from_str(&compact_json).unwrap();
+        assert_eq!(
+            compact,
+            serde_json::json!({"R4": "040a", "R5": "0e03010203", "R6": "05d00f"})
+        );
+        let regs_from_compact: NonMandatoryRegisters = serde_json::from_str(&compact_json).unwrap();
+        assert_eq!(regs_from_compact, regs);
+        let get = |id| regs_from_compact.get(id).unwrap().clone();
+        assert_eq!(get(NonMandatoryRegisterId::R4), Constant::from(5i32));
+        assert_eq!(
+            get(NonMandatoryRegisterId::R5),
+            Constant::from(vec![1u8, 2, 3])
+        );
+        assert_eq!(get(NonMandatoryRegisterId::R6), Constant::from(1000i64));
+    }
+
     #[test]
     fn parse_registers_error() {
         let json = r#"
@@ -289,6 +490,21 @@ mod tests {
         assert!(regs.is_err());
     }
 
+    #[test]
+    fn parse_registers_truncated_raw_value_error_position() {
+        // Coll[Byte] of 3 bytes with only 1 byte present
+        let rich_json = r#"{"R4": {"rawValue": "0e0301"}}"#;
+        let err = serde_json::from_str::<NonMandatoryRegisters>(rich_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at byte 3"), "{}", err);
+        let compact_json = r#"{"R4": "0e0301"}"#;
+        let err = serde_json::from_str::<NonMandatoryRegisters>(compact_json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at byte 3"), "{}", err);
+    }
+
     #[test]
     fn parse_ergo_tree_with_constants() {
         let json = r#"
@@ -315,6 +531,35 @@ mod tests {
         assert!(c.values.get(&3u8).is_some());
     }
 
+    #[test]
+    fn parse_context_extension_unsorted() {
+        let json = r#"
+        {"3":"048ce5d4e505", "1" :"05b0b5cad8e6dbaef44a"}
+        "#;
+        let c: ContextExtension = serde_json::from_str(json).unwrap();
+        assert_eq!(c.values.len(), 2);
+        assert!(c.values.get(&1u8).is_some());
+        assert!(c.values.get(&3u8).is_some());
+    }
+
+    #[test]
+    fn context_extension_json_keys_sorted() {
+        let mut c = ContextExtension::empty();
+        c.values.insert(10, 1i32.into());
+        c.values.insert(3, 2i32.into());
+        c.values.insert(1, 3i32.into());
+        let j = serde_json::to_string(&c).unwrap();
+        assert_eq!(j, r#"{"1":"0406","3":"0404","10":"0402"}"#);
+        let c_parsed: ContextExtension = serde_json::from_str(&j).unwrap();
+        assert_eq!(
+            c_parsed
+                .values
+                .keys()
+                .collect::<std::collections::BTreeSet<_>>(),
+            c.values.keys().collect()
+        );
+    }
+
     #[test]
     fn parse_ergo_box() {
         let box_json = r#"{
@@ -331,6 +576,55 @@ mod tests {
         assert_eq!(b.value, 67500000000u64.try_into().unwrap());
     }
 
+    fn box_value_json_error(value: &str) -> String {
+        serde_json::from_str::<BoxValue>(value)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn parse_box_value_errors() {
+        assert_eq!(box_value_json_error("0"), "Value is zero");
+        assert_eq!(
+            box_value_json_error("-1000000"),
+            "Value is negative: -1000000"
+        );
+        assert_eq!(
+            box_value_json_error("9223372036854775808"),
+            "Value 9223372036854775808 exceeds the maximum box value (9223372036854775807 nanoERGs)"
+        );
+        assert_eq!(
+            box_value_json_error("18446744073709551615"),
+            "Value 18446744073709551615 exceeds the maximum box value (9223372036854775807 nanoERGs)"
+        );
+        assert_eq!(box_value_json_error("1"), "Value is out of bounds: 1");
+        // same bound as BoxValue::new and the binary parser
+        assert_eq!(
+            serde_json::from_str::<BoxValue>("9223372036854775807").unwrap(),
+            BoxValue::new(BoxValue::MAX_RAW).unwrap()
+        );
+        assert_eq!(
+            BoxValue::new(BoxValue::MAX_RAW + 1),
+            Err(BoxValueError::AboveMax(BoxValue::MAX_RAW + 1))
+        );
+    }
+
+    #[test]
+    fn parse_ergo_box_negative_value() {
+        let box_json = r#"{
+          "boxId": "e56847ed19b3dc6b72828fcfb992fdf7310828cf291221269b7ffc72fd66706e",
+          "value": -67500000000,
+          "ergoTree": "0008cd0327e65711a59378c59359c3e1d0f7abe906479eccb76094e50fe79d743ccc15e6",
+          "assets": [],
+          "creationHeight": 284761,
+          "additionalRegisters": {},
+          "transactionId": "9148408c04c2e38a6402a7950d6157730fa7d49e9ab3b9cadec481d7769918e9",
+          "index": 1
+        }"#;
+        let err = serde_json::from_str::<ErgoBox>(box_json).unwrap_err();
+        assert!(err.to_string().contains("Value is negative: -67500000000"));
+    }
+
     #[test]
     fn parse_ergo_box_alternative_box_id_field_name() {
         // check that using "id" field name instead of "boxId" also works
@@ -367,9 +661,8 @@ mod tests {
         assert_eq!(b.value, 67500000000u64.try_into().unwrap());
     }
 
-    #[test]
-    fn parse_ergo_box_from_explorer() {
-        let box_json = r#"
+    /// Box with tokens and R4, R5 registers as returned by the explorer API
+    const EXPLORER_BOX_JSON: &str = r#"
         {
             "id": "3e762407d99b006d53b6583adcca08ef690b42fb0b2ed7abf63179eb6b9033b2",
             "txId": "93d344aa527e18e5a221db060ea1a868f46b61e4537e6e5f69ecc40334c15e38",
@@ -403,7 +696,60 @@ mod tests {
             "mainChain": true
         }
         "#;
-        let b: ErgoBox = serde_json::from_str(box_json).unwrap();
+
+    #[test]
+    fn parse_ergo_box_from_explorer() {
+        let b: ErgoBox = serde_json::from_str(EXPLORER_BOX_JSON).unwrap();
         assert_eq!(b.value, 2875858910u64.try_into().unwrap());
     }
+
+    fn eval_self_reg<T: TryExtractFrom<Value>>(
+        b: ErgoBox,
+        register_id: u8,
+        tpe: SType,
+    ) -> Result<T, EvalError> {
+        let ctx = Context {
+            self_box: b,
+            ..Context::dummy()
+        };
+        let expr: Expr = BoxM::ExtractRegisterAs {
+            input: Box::new(GlobalVars::SelfBox.into()),
+            register_id: RegisterId(register_id),
+            tpe,
+        }
+        .into();
+        try_eval_out(&expr, Rc::new(ctx))
+    }
+
+    #[test]
+    fn eval_explorer_box_creation_info() {
+        let b: ErgoBox = serde_json::from_str(EXPLORER_BOX_JSON).unwrap();
+        let expr: Expr = ExtractCreationInfo {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let ctx = Context {
+            self_box: b.clone(),
+            ..Context::dummy()
+        };
+        let (height, ref_bytes) = try_eval_out::<(i32, Vec<u8>)>(&expr, Rc::new(ctx)).unwrap();
+        assert_eq!(height, 352126);
+        assert_eq!(&ref_bytes[..32], b.transaction_id.0 .0.as_ref());
+        assert_eq!(&ref_bytes[32..], [0, 0]);
+    }
+
+    #[test]
+    fn eval_explorer_box_registers() {
+        let b: ErgoBox = serde_json::from_str(EXPLORER_BOX_JSON).unwrap();
+        assert_eq!(
+            eval_self_reg::<Option<i64>>(b.clone(), 4, SType::SLong),
+            Ok(Some(2))
+        );
+        assert_eq!(
+            eval_self_reg::<Option<i64>>(b.clone(), 5, SType::SLong),
+            Ok(Some(4380010))
+        );
+        assert!(eval_self_reg::<Option<i32>>(b.clone(), 4, SType::SInt).is_err());
+        assert_eq!(eval_self_reg::<Option<i64>>(b, 6, SType::SLong), Ok(None));
+    }
 }
@@ -16,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// newtype for token id
-#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct TokenId(pub Digest32);
 
@@ -47,9 +47,10 @@ impl SigmaSerializable for TokenId {
     }
 }
 
-/// Token amount with bound checks
+/// Token amount with bound checks (zero is not allowed)
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, PartialOrd, Ord)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json", serde(into = "u64", try_from = "u64"))]
 pub struct TokenAmount(u64);
 
 impl TokenAmount {
@@ -216,6 +217,23 @@ pub mod tests {
         type Strategy = BoxedStrategy<Self>;
     }
 
+    #[test]
+    fn token_amount_valid() {
+        assert_eq!(u64::from(TokenAmount::try_from(1).unwrap()), 1);
+        assert_eq!(
+            u64::from(TokenAmount::try_from(TokenAmount::MAX_RAW).unwrap()),
+            TokenAmount::MAX_RAW
+        );
+    }
+
+    #[test]
+    fn token_amount_zero() {
+        assert_eq!(
+            TokenAmount::try_from(0),
+            Err(TokenAmountError::OutOfBounds(0))
+        );
+    }
+
     proptest! {
 
         #[test]
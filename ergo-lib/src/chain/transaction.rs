@@ -11,8 +11,8 @@ pub use input::*;
 use super::json;
 use super::{
     digest32::{blake2b256_hash, Digest32},
-    ergo_box::ErgoBox,
     ergo_box::ErgoBoxCandidate,
+    ergo_box::{checked_sum, BoxValue, BoxValueError, ErgoBox},
     token::TokenId,
 };
 use crate::serialization::{
@@ -145,6 +145,17 @@ impl Transaction {
     pub fn id(&self) -> TxId {
         self.tx_id.clone()
     }
+
+    /// Sum of the output values (in nanoERGs).
+    /// Returns Err on overflow or if the sum exceeds the total supply of ERGs
+    pub fn total_output_value(&self) -> Result<u64, BoxValueError> {
+        let total = *checked_sum(self.output_candidates.iter().map(|b| b.value))?.as_u64();
+        if total > BoxValue::TOTAL_SUPPLY_RAW {
+            Err(BoxValueError::OutOfBounds(total))
+        } else {
+            Ok(total)
+        }
+    }
 }
 
 impl SigmaSerializable for Transaction {
@@ -272,6 +283,7 @@ pub mod tests {
 
     use super::*;
     use crate::serialization::sigma_serialize_roundtrip;
+    use crate::test_util::force_any_val;
 
     use proptest::prelude::*;
     use proptest::{arbitrary::Arbitrary, collection::vec};
@@ -291,6 +303,44 @@ pub mod tests {
         type Strategy = BoxedStrategy<Self>;
     }
 
+    fn tx_with_output_values(values: &[u64]) -> Transaction {
+        let tx = force_any_val::<Transaction>();
+        let candidate = tx.output_candidates[0].clone();
+        let output_candidates = values
+            .iter()
+            .map(|v| ErgoBoxCandidate {
+                value: BoxValue::new(*v).unwrap(),
+                ..candidate.clone()
+            })
+            .collect();
+        Transaction::new(tx.inputs, tx.data_inputs, output_candidates)
+    }
+
+    #[test]
+    fn total_output_value() {
+        let tx = tx_with_output_values(&[BoxValue::MIN_RAW, BoxValue::SAFE_USER_MIN.as_u64() * 2]);
+        assert_eq!(
+            tx.total_output_value(),
+            Ok(BoxValue::MIN_RAW + BoxValue::SAFE_USER_MIN.as_u64() * 2)
+        );
+    }
+
+    #[test]
+    fn total_output_value_over_supply() {
+        let half_supply_and_one = BoxValue::TOTAL_SUPPLY_RAW / 2 + 1;
+        let tx = tx_with_output_values(&[half_supply_and_one, half_supply_and_one]);
+        assert_eq!(
+            tx.total_output_value(),
+            Err(BoxValueError::OutOfBounds(half_supply_and_one * 2))
+        );
+    }
+
+    #[test]
+    fn total_output_value_overflow() {
+        let tx = tx_with_output_values(&[BoxValue::MAX_RAW, BoxValue::MAX_RAW]);
+        assert!(tx.total_output_value().is_err());
+    }
+
     proptest! {
 
         #[test]
@@ -20,8 +20,8 @@ pub struct UnsignedInput {
     /// id of the box to spent
     #[cfg_attr(feature = "json", serde(rename = "boxId"))]
     pub box_id: BoxId,
-    /// user-defined variables to be put into context
-    #[cfg_attr(feature = "json", serde(rename = "extension"))]
+    /// user-defined variables to be put into context (empty if absent in JSON)
+    #[cfg_attr(feature = "json", serde(rename = "extension", default))]
     pub extension: ContextExtension,
 }
 
@@ -8,6 +8,7 @@ use super::{
     super::{digest32::blake2b256_hash, ergo_box::ErgoBoxCandidate},
     Transaction, TxId,
 };
+use crate::chain::ergo_box::BoxId;
 #[cfg(feature = "json")]
 use crate::chain::transaction::ErgoBox;
 #[cfg(feature = "json")]
@@ -18,6 +19,7 @@ use crate::sigma_protocol::prover::{ProofBytes, ProverResult};
 use core::convert::TryFrom;
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Unsigned (inputs without proofs) transaction
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
@@ -91,6 +93,52 @@ impl UnsignedTransaction {
         );
         tx.sigma_serialize_bytes()
     }
+
+    /// Combine inputs, data inputs and output candidates of both transactions (i.e. parts
+    /// contributed by different parties) into a new transaction. Inputs and outputs of `self` go
+    /// first. Data inputs present in both are included once, while an input spent in both is an
+    /// error.
+    pub fn merge(
+        &self,
+        other: &UnsignedTransaction,
+    ) -> Result<UnsignedTransaction, UnsignedTransactionMergeError> {
+        if let Some(dup) = other
+            .inputs
+            .iter()
+            .find(|i| self.inputs.iter().any(|si| si.box_id == i.box_id))
+        {
+            return Err(UnsignedTransactionMergeError::DuplicateInput(
+                dup.box_id.clone(),
+            ));
+        }
+        let mut data_inputs = self.data_inputs.clone();
+        other.data_inputs.iter().for_each(|di| {
+            if !data_inputs.contains(di) {
+                data_inputs.push(di.clone())
+            }
+        });
+        Ok(UnsignedTransaction::new(
+            self.inputs
+                .iter()
+                .chain(other.inputs.iter())
+                .cloned()
+                .collect(),
+            data_inputs,
+            self.output_candidates
+                .iter()
+                .chain(other.output_candidates.iter())
+                .cloned()
+                .collect(),
+        ))
+    }
+}
+
+/// Errors on merging unsigned transactions
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum UnsignedTransactionMergeError {
+    /// The same box is spent by both transactions
+    #[error("Box {0:?} is spent in both transactions")]
+    DuplicateInput(BoxId),
 }
 
 #[cfg(feature = "json")]
@@ -139,6 +187,7 @@ impl From<UnsignedTransaction> for json::transaction::UnsignedTransactionJson {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::test_util::force_any_val;
 
     use proptest::prelude::*;
     use proptest::{arbitrary::Arbitrary, collection::vec};
@@ -168,4 +217,47 @@ pub mod tests {
         }
 
     }
+
+    #[test]
+    fn test_merge() {
+        let tx_a = force_any_val::<UnsignedTransaction>();
+        let mut tx_b = force_any_val::<UnsignedTransaction>();
+        // shared data input
+        tx_b.data_inputs.push(DataInput::from(BoxId::zero()));
+        let tx_a = UnsignedTransaction::new(
+            tx_a.inputs,
+            vec![DataInput::from(BoxId::zero())],
+            tx_a.output_candidates,
+        );
+        let merged = tx_a.merge(&tx_b).unwrap();
+        assert_eq!(
+            merged.inputs,
+            [tx_a.inputs.clone(), tx_b.inputs.clone()].concat()
+        );
+        // shared data input is included once, in its position in `tx_a`
+        let mut data_inputs = tx_a.data_inputs.clone();
+        data_inputs.extend_from_slice(&tx_b.data_inputs[..tx_b.data_inputs.len() - 1]);
+        assert_eq!(merged.data_inputs, data_inputs);
+        assert_eq!(
+            merged.output_candidates,
+            [
+                tx_a.output_candidates.clone(),
+                tx_b.output_candidates.clone()
+            ]
+            .concat()
+        );
+        assert_eq!(merged.id(), merged.calc_tx_id());
+    }
+
+    #[test]
+    fn test_merge_duplicate_input() {
+        let tx_a = force_any_val::<UnsignedTransaction>();
+        let mut tx_b = force_any_val::<UnsignedTransaction>();
+        let dup = tx_a.inputs[0].clone();
+        tx_b.inputs.push(dup.clone());
+        assert_eq!(
+            tx_a.merge(&tx_b),
+            Err(UnsignedTransactionMergeError::DuplicateInput(dup.box_id))
+        );
+    }
 }
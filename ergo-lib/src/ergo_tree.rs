@@ -8,8 +8,10 @@ use crate::serialization::{
     SerializationError, SigmaSerializable,
 };
 use crate::sigma_protocol::sigma_boolean::ProveDlog;
+use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
+use crate::sigma_protocol::sigma_boolean::SigmaProp;
 use crate::types::stype::SType;
-use io::{Cursor, Read};
+use io::{Cursor, Read, Write};
 
 use crate::serialization::constant_store::ConstantStore;
 use sigma_ser::{peekable_reader::PeekableReader, vlq_encode};
@@ -17,7 +19,7 @@ use std::convert::TryFrom;
 use std::io;
 use std::rc::Rc;
 use thiserror::Error;
-use vlq_encode::ReadSigmaVlqExt;
+use vlq_encode::{ReadSigmaVlqExt, WriteSigmaVlqExt};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 struct ParsedTree {
@@ -38,10 +40,21 @@ struct ErgoTreeHeader(u8);
 
 impl ErgoTreeHeader {
     const CONSTANT_SEGREGATION_FLAG: u8 = 0x10;
+    const SIZE_FLAG: u8 = 0x08;
+    const VERSION_MASK: u8 = 0x07;
 
     pub fn is_constant_segregation(&self) -> bool {
         self.0 & ErgoTreeHeader::CONSTANT_SEGREGATION_FLAG != 0
     }
+
+    /// Whether the tree size (in bytes, excluding the header and the size itself) follows the header
+    pub fn has_size(&self) -> bool {
+        self.0 & ErgoTreeHeader::SIZE_FLAG != 0
+    }
+
+    pub fn version(&self) -> u8 {
+        self.0 & ErgoTreeHeader::VERSION_MASK
+    }
 }
 
 /// Whole ErgoTree parsing (deserialization) error
@@ -73,12 +86,44 @@ pub enum ErgoTreeParsingError {
     RootParsingError(ErgoTreeRootParsingError),
 }
 
+/// Error on replacing ErgoTree constants
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum SetConstantError {
+    /// Failed to parse the tree constants
+    #[error("ErgoTree parsing error: {0:?}")]
+    TreeParsingError(ErgoTreeConstantsParsingError),
+    /// There is no constant at the given position
+    #[error("Constant index {index} is out of bounds ({len} constants)")]
+    IndexOutOfBounds {
+        /// Requested position
+        index: usize,
+        /// Number of constants in the tree
+        len: usize,
+    },
+    /// New constant type differs from the type of the replaced constant
+    #[error("Constant {index} has type {expected:?}, got {actual:?}")]
+    TypeMismatch {
+        /// Position of the constant
+        index: usize,
+        /// Type of the constant in the tree
+        expected: SType,
+        /// Type of the new constant
+        actual: SType,
+    },
+}
+
 impl ErgoTree {
     const DEFAULT_HEADER: ErgoTreeHeader = ErgoTreeHeader(0);
 
     /// Reasonable limit for the number of constants allowed in the ErgoTree
     pub const MAX_CONSTANTS_COUNT: usize = 4096;
 
+    /// Maximum ErgoTree version supported by this implementation
+    pub const MAX_VERSION: u8 = 1;
+
+    /// Maximum size (in bytes) of the serialized ErgoTree accepted by consensus
+    pub const MAX_SIZE: usize = 4096;
+
     /// get Expr out of ErgoTree
     pub fn proposition(&self) -> Result<Rc<Expr>, ErgoTreeParsingError> {
         let root = self
@@ -116,6 +161,21 @@ impl ErgoTree {
         }
     }
 
+    /// ErgoTree with `sigmaProp(true)` proposition (can be spent without a proof)
+    pub fn true_proposition() -> ErgoTree {
+        ErgoTree::trivial_proposition(true)
+    }
+
+    /// ErgoTree with `sigmaProp(false)` proposition (cannot be spent)
+    pub fn false_proposition() -> ErgoTree {
+        ErgoTree::trivial_proposition(false)
+    }
+
+    fn trivial_proposition(value: bool) -> ErgoTree {
+        let prop = SigmaProp::new(SigmaBoolean::TrivialProp(value));
+        ErgoTree::without_segregation(Rc::new(Expr::Const(prop.into())))
+    }
+
     /// Build ErgoTree with constants segregated from expr
     pub fn with_segregation(expr: Rc<Expr>) -> ErgoTree {
         let mut data = Vec::new();
@@ -136,6 +196,66 @@ impl ErgoTree {
             }),
         }
     }
+
+    /// Check that the serialized tree does not exceed [`ErgoTree::MAX_SIZE`]
+    pub fn validate_size(&self) -> Result<(), SerializationError> {
+        check_tree_size(self.sigma_serialize_bytes().len())
+    }
+
+    /// Segregated constants of the tree (empty if the tree is built without constant segregation)
+    pub fn constants(&self) -> Result<&[Constant], ErgoTreeConstantsParsingError> {
+        self.tree
+            .as_ref()
+            .map(|t| t.constants.as_slice())
+            .map_err(|e| e.clone())
+    }
+
+    /// Returns a new tree with the segregated constant at `index` replaced,
+    /// see [`ErgoTree::with_constants`]
+    pub fn set_constant(
+        &self,
+        index: usize,
+        constant: Constant,
+    ) -> Result<ErgoTree, SetConstantError> {
+        self.with_constants(vec![(index, constant)])
+    }
+
+    /// Returns a new tree with the segregated constants at the given positions replaced.
+    /// Each new constant must have the same type as the replaced one. Either all replacements
+    /// are applied or none (on the first error).
+    pub fn with_constants(
+        &self,
+        replacements: Vec<(usize, Constant)>,
+    ) -> Result<ErgoTree, SetConstantError> {
+        let mut tree = self
+            .tree
+            .clone()
+            .map_err(SetConstantError::TreeParsingError)?;
+        for (index, constant) in replacements {
+            let len = tree.constants.len();
+            let old = tree
+                .constants
+                .get_mut(index)
+                .ok_or(SetConstantError::IndexOutOfBounds { index, len })?;
+            if old.tpe != constant.tpe {
+                return Err(SetConstantError::TypeMismatch {
+                    index,
+                    expected: old.tpe.clone(),
+                    actual: constant.tpe,
+                });
+            }
+            *old = constant;
+        }
+        Ok(ErgoTree {
+            header: self.header.clone(),
+            tree: Ok(tree),
+        })
+    }
+
+    /// Public key if the tree is exactly a P2PK proposition, `None` otherwise
+    pub fn is_p2pk(&self) -> Option<ProveDlog> {
+        ProveDlog::try_from(self.clone()).ok()
+    }
 }
 
 impl From<Rc<Expr>> for ErgoTree {
@@ -159,27 +279,52 @@ impl SigmaSerializable for ErgoTreeHeader {
     }
 }
 
+impl ParsedTree {
+    fn sigma_serialize<W: SigmaByteWrite>(
+        &self,
+        header: &ErgoTreeHeader,
+        w: &mut W,
+    ) -> Result<(), io::Error> {
+        if header.is_constant_segregation() {
+            w.put_usize_as_u32(self.constants.len())?;
+            self.constants
+                .iter()
+                .try_for_each(|c| c.sigma_serialize(w))?;
+        }
+        match &self.root {
+            Ok(expr) => expr.sigma_serialize(w),
+            Err(ErgoTreeRootParsingError { bytes, .. }) => w.write_all(&bytes[..]),
+        }
+    }
+}
+
 impl SigmaSerializable for ErgoTree {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         self.header.sigma_serialize(w)?;
         match &self.tree {
-            Ok(ParsedTree { constants, root }) => {
-                if self.header.is_constant_segregation() {
-                    w.put_usize_as_u32(constants.len())?;
-                    constants.iter().try_for_each(|c| c.sigma_serialize(w))?;
-                }
-                match root {
-                    Ok(expr) => expr.sigma_serialize(w)?,
-                    Err(ErgoTreeRootParsingError { bytes, .. }) => w.write_all(&bytes[..])?,
-                }
+            Ok(parsed_tree) if self.header.has_size() => {
+                let mut data = Vec::new();
+                let mut body_w = SigmaByteWriter::new(&mut data, None);
+                parsed_tree.sigma_serialize(&self.header, &mut body_w)?;
+                w.put_usize_as_u32(data.len())?;
+                w.write_all(&data[..])?;
             }
+            Ok(parsed_tree) => parsed_tree.sigma_serialize(&self.header, w)?,
+            // the bytes include the tree size (if any)
             Err(ErgoTreeConstantsParsingError { bytes, .. }) => w.write_all(&bytes[..])?,
         }
         Ok(())
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let start_pos = r.position();
         let header = ErgoTreeHeader::sigma_parse(r)?;
+        let tree_size = if header.has_size() {
+            Some(r.get_u32()?)
+        } else {
+            None
+        };
+        let body_start_pos = r.position();
         let constants = if header.is_constant_segregation() {
             let constants_len = r.get_u32()?;
             if constants_len as usize > ErgoTree::MAX_CONSTANTS_COUNT {
@@ -197,7 +342,12 @@ impl SigmaSerializable for ErgoTree {
             vec![]
         };
         r.set_constant_store(ConstantStore::new(constants.clone()));
+        r.set_tree_version(header.version());
         let root = Expr::sigma_parse(r)?;
+        if let Some(tree_size) = tree_size {
+            check_tree_body_size(tree_size, r.position() - body_start_pos)?;
+        }
+        check_tree_size((r.position() - start_pos) as usize)?;
         Ok(ErgoTree {
             header,
             tree: Ok(ParsedTree {
@@ -208,9 +358,15 @@ impl SigmaSerializable for ErgoTree {
     }
 
     fn sigma_parse_bytes(mut bytes: Vec<u8>) -> Result<Self, SerializationError> {
+        let bytes_len = bytes.len();
+        check_tree_size(bytes_len)?;
         let cursor = Cursor::new(&mut bytes[..]);
         let mut r = SigmaByteReader::new(PeekableReader::new(cursor), ConstantStore::empty());
         let header = ErgoTreeHeader::sigma_parse(&mut r)?;
+        if header.has_size() {
+            let tree_size = r.get_u32()?;
+            check_tree_body_size(tree_size, bytes_len as u64 - r.position())?;
+        }
         let constants = if header.is_constant_segregation() {
             let constants_len = r.get_u32()?;
             if constants_len as usize > ErgoTree::MAX_CONSTANTS_COUNT {
@@ -246,6 +402,7 @@ impl SigmaSerializable for ErgoTree {
             PeekableReader::new(Cursor::new(&mut rest_of_the_bytes[..])),
             ConstantStore::new(constants.clone()),
         );
+        new_r.set_tree_version(header.version());
 
         match Expr::sigma_parse(&mut new_r) {
             Ok(parsed) => Ok(ErgoTree {
@@ -269,6 +426,108 @@ impl SigmaSerializable for ErgoTree {
     }
 }
 
+/// Check that the serialized tree does not exceed [`ErgoTree::MAX_SIZE`]
+fn check_tree_size(size: usize) -> Result<(), SerializationError> {
+    if size > ErgoTree::MAX_SIZE {
+        return Err(SerializationError::ValueOutOfBounds(format!(
+            "ErgoTree size {} exceeds maximum {}",
+            size,
+            ErgoTree::MAX_SIZE
+        )));
+    }
+    Ok(())
+}
+
+/// Check the tree size from the header against the size of the tree body (constants and root)
+fn check_tree_body_size(tree_size: u32, body_size: u64) -> Result<(), SerializationError> {
+    if tree_size as u64 != body_size {
+        return Err(SerializationError::ValueOutOfBounds(format!(
+            "ErgoTree size {} does not match the tree body size {}",
+            tree_size, body_size
+        )));
+    }
+    Ok(())
+}
+
+/// Replaces the segregated constants at `positions` in the serialized tree with the corresponding
+/// `new_values` (`substConstants` of sigmastate). The header and the root expression bytes are
+/// copied verbatim, positions out of the constants range are ignored and a tree without constant
+/// segregation is returned unchanged.
+pub(crate) fn substitute_constants(
+    script_bytes: &[u8],
+    positions: &[i32],
+    new_values: &[Constant],
+) -> Result<Vec<u8>, SetConstantError> {
+    let parsing_error = |error: SerializationError| {
+        SetConstantError::TreeParsingError(ErgoTreeConstantsParsingError {
+            bytes: script_bytes.to_vec(),
+            error,
+        })
+    };
+    let mut bytes = script_bytes.to_vec();
+    let cursor = Cursor::new(&mut bytes[..]);
+    let mut r = SigmaByteReader::new(PeekableReader::new(cursor), ConstantStore::empty());
+    let header = ErgoTreeHeader::sigma_parse(&mut r).map_err(parsing_error)?;
+    if !header.is_constant_segregation() {
+        return Ok(script_bytes.to_vec());
+    }
+    if header.has_size() {
+        r.get_u32().map_err(|e| parsing_error(e.into()))?;
+    }
+    let constants_len = r.get_u32().map_err(|e| parsing_error(e.into()))?;
+    if constants_len as usize > ErgoTree::MAX_CONSTANTS_COUNT {
+        return Err(parsing_error(SerializationError::ValueOutOfBounds(
+            "too many constants".to_string(),
+        )));
+    }
+    let constants = (0..constants_len)
+        .map(|_| Constant::sigma_parse(&mut r))
+        .collect::<Result<Vec<Constant>, SerializationError>>()
+        .map_err(parsing_error)?;
+    let mut root_bytes = Vec::new();
+    r.read_to_end(&mut root_bytes)
+        .map_err(|e| parsing_error(e.into()))?;
+    let constants = constants
+        .into_iter()
+        .enumerate()
+        .map(|(index, old)| {
+            match positions
+                .iter()
+                .position(|p| *p as i64 == index as i64)
+                .and_then(|i| new_values.get(i))
+            {
+                Some(new) if new.tpe != old.tpe => Err(SetConstantError::TypeMismatch {
+                    index,
+                    expected: old.tpe,
+                    actual: new.tpe.clone(),
+                }),
+                Some(new) => Ok(new.clone()),
+                None => Ok(old),
+            }
+        })
+        .collect::<Result<Vec<Constant>, SetConstantError>>()?;
+    let mut body = Vec::new();
+    let mut w = SigmaByteWriter::new(&mut body, None);
+    w.put_usize_as_u32(constants.len())
+        .and_then(|_| constants.iter().try_for_each(|c| c.sigma_serialize(&mut w)))
+        .and_then(|_| w.write_all(&root_bytes))
+        .map_err(|e| parsing_error(e.into()))?;
+    let mut res = Vec::new();
+    let mut w = SigmaByteWriter::new(&mut res, None);
+    header
+        .sigma_serialize(&mut w)
+        .and_then(|_| {
+            if header.has_size() {
+                w.put_usize_as_u32(body.len())
+            } else {
+                Ok(())
+            }
+        })
+        .and_then(|_| w.write_all(&body))
+        .map_err(|e| parsing_error(e.into()))?;
+    Ok(res)
+}
+
 impl TryFrom<ErgoTree> for ProveDlog {
     type Error = TryExtractFromError;
 
@@ -292,13 +551,56 @@ impl TryFrom<ErgoTree> for ProveDlog {
 mod tests {
     #![allow(unused_imports)]
     use super::*;
+    use crate::ast::bool_to_sigma::BoolToSigmaProp;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, BitOp, LogicalOp, RelationOp};
+    use crate::ast::sigma_and::SigmaAnd;
+    use crate::ast::sigma_or::SigmaOr;
     use crate::ast::value::Value;
     use crate::chain;
     use crate::chain::Base16DecodedBytes;
+    use crate::serialization::op_code::OpCode;
     use crate::serialization::sigma_serialize_roundtrip;
     use crate::sigma_protocol::sigma_boolean::SigmaProp;
+    use crate::test_util::force_any_val;
+    use proptest::collection::vec;
     use proptest::prelude::*;
 
+    /// SigmaProp expressions composed of ProveDlog, `sigmaProp(HEIGHT <op> n)` and AND/OR
+    fn arb_sigma_prop_expr() -> BoxedStrategy<Expr> {
+        let prove_dlog =
+            any::<ProveDlog>().prop_map(|p| Expr::from(Constant::from(SigmaProp::from(p))));
+        let height_cmp = (
+            prop::sample::select(vec![
+                RelationOp::Eq,
+                RelationOp::NEq,
+                RelationOp::Lt,
+                RelationOp::Le,
+                RelationOp::Gt,
+                RelationOp::Ge,
+            ]),
+            any::<i32>(),
+        )
+            .prop_map(|(op, height)| {
+                BoolToSigmaProp {
+                    input: Box::new(Expr::BinOp(
+                        BinOp::Relation(op),
+                        Box::new(GlobalVars::Height.into()),
+                        Box::new(Expr::Const(height.into())),
+                    )),
+                }
+                .into()
+            });
+        prop_oneof![prove_dlog, height_cmp]
+            .prop_recursive(3, 16, 3, |inner| {
+                prop_oneof![
+                    vec(inner.clone(), 2..4).prop_map(|items| SigmaAnd { items }.into()),
+                    vec(inner, 2..4).prop_map(|items| SigmaOr { items }.into()),
+                ]
+            })
+            .boxed()
+    }
+
     impl Arbitrary for ErgoTree {
         type Parameters = ();
         type Strategy = BoxedStrategy<Self>;
@@ -309,6 +611,7 @@ mod tests {
                 any::<ProveDlog>().prop_map(|p| ErgoTree::from(Rc::new(Expr::from(
                     Constant::from(SigmaProp::from(p))
                 )))),
+                arb_sigma_prop_expr().prop_map(|e| ErgoTree::from(Rc::new(e))),
             ]
             .boxed()
         }
@@ -320,6 +623,14 @@ mod tests {
         fn ser_roundtrip(v in any::<ErgoTree>()) {
             prop_assert_eq![sigma_serialize_roundtrip(&(v)), v];
         }
+
+        #[test]
+        fn ser_bytes_stable(v in any::<ErgoTree>()) {
+            let bytes = v.sigma_serialize_bytes();
+            let parsed = ErgoTree::sigma_parse_bytes(bytes.clone()).unwrap();
+            prop_assert_eq![parsed.sigma_serialize_bytes(), bytes];
+            prop_assert_eq![parsed.proposition().unwrap().tpe(), SType::SSigmaProp];
+        }
     }
 
     #[test]
@@ -352,6 +663,150 @@ mod tests {
         assert_eq!(&bytes[..2], vec![0u8, 8u8].as_slice());
     }
 
+    #[test]
+    fn test_trivial_propositions_roundtrip() {
+        let true_tree = ErgoTree::true_proposition();
+        assert_eq!(sigma_serialize_roundtrip(&true_tree), true_tree);
+        let false_tree = ErgoTree::false_proposition();
+        assert_eq!(sigma_serialize_roundtrip(&false_tree), false_tree);
+        assert_ne!(true_tree, false_tree);
+    }
+
+    #[test]
+    fn validate_size_ok() {
+        assert!(ErgoTree::true_proposition().validate_size().is_ok());
+    }
+
+    #[test]
+    fn validate_size_too_large() {
+        let c: Constant = vec![1u8; ErgoTree::MAX_SIZE].into();
+        let tree = ErgoTree::without_segregation(Rc::new(Expr::Const(c)));
+        assert!(tree.validate_size().is_err());
+        assert!(matches!(
+            parse_strict(tree.sigma_serialize_bytes()),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn parse_bytes_too_large() {
+        let c: Constant = vec![1u8; ErgoTree::MAX_SIZE].into();
+        let tree = ErgoTree::without_segregation(Rc::new(Expr::Const(c)));
+        assert!(matches!(
+            ErgoTree::sigma_parse_bytes(tree.sigma_serialize_bytes()),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn parse_size_flag() {
+        // sigmaProp(true) with the tree size after the header
+        let body = ErgoTree::true_proposition().sigma_serialize_bytes()[1..].to_vec();
+        let with_size = |size: u8| {
+            let mut bytes = vec![ErgoTreeHeader::SIZE_FLAG, size];
+            bytes.extend(body.iter());
+            bytes
+        };
+        let size = body.len() as u8;
+        assert!(parse_strict(with_size(size)).is_ok());
+        assert!(ErgoTree::sigma_parse_bytes(with_size(size)).is_ok());
+        for wrong_size in [size - 1, size + 1].iter() {
+            assert!(matches!(
+                parse_strict(with_size(*wrong_size)),
+                Err(SerializationError::ValueOutOfBounds(_))
+            ));
+            assert!(matches!(
+                ErgoTree::sigma_parse_bytes(with_size(*wrong_size)),
+                Err(SerializationError::ValueOutOfBounds(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn is_p2pk() {
+        let pk = force_any_val::<ProveDlog>();
+        let tree = ErgoTree::from(Rc::new(Expr::from(Constant::from(SigmaProp::from(
+            pk.clone(),
+        )))));
+        assert_eq!(tree.is_p2pk(), Some(pk));
+    }
+
+    #[test]
+    fn is_p2pk_not_p2pk() {
+        assert_eq!(ErgoTree::true_proposition().is_p2pk(), None);
+        let height_check: Expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(GlobalVars::Height.into()),
+            Box::new(Expr::Const(1i32.into())),
+        );
+        assert_eq!(
+            ErgoTree::without_segregation(Rc::new(height_check)).is_p2pk(),
+            None
+        );
+    }
+
+    fn parse_strict(mut bytes: Vec<u8>) -> Result<ErgoTree, SerializationError> {
+        let cursor = Cursor::new(&mut bytes[..]);
+        let mut r = SigmaByteReader::new(PeekableReader::new(cursor), ConstantStore::empty());
+        ErgoTree::sigma_parse(&mut r)
+    }
+
+    fn bit_or_tree_bytes(version: u8) -> Vec<u8> {
+        let expr = Expr::BinOp(
+            BinOp::Bit(BitOp::Or),
+            Box::new(Constant::from(1i32).into()),
+            Box::new(Constant::from(2i32).into()),
+        );
+        let mut bytes = vec![version];
+        bytes.append(&mut expr.sigma_serialize_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parse_v3_op_code_in_older_tree() {
+        for version in 0..3 {
+            assert_eq!(
+                parse_strict(bit_or_tree_bytes(version)),
+                Err(SerializationError::OpCodeNotSupportedInVersion {
+                    op_code: OpCode::BIT_OR.value(),
+                    min_version: 3,
+                    tree_version: version,
+                })
+            );
+        }
+        assert!(ErgoTree::sigma_parse_bytes(bit_or_tree_bytes(0))
+            .unwrap()
+            .proposition()
+            .is_err());
+    }
+
+    #[test]
+    fn parse_v3_op_code_in_v3_tree() {
+        let tree = parse_strict(bit_or_tree_bytes(3)).unwrap();
+        assert_eq!(
+            *tree.proposition().unwrap(),
+            Expr::BinOp(
+                BinOp::Bit(BitOp::Or),
+                Box::new(Constant::from(1i32).into()),
+                Box::new(Constant::from(2i32).into()),
+            )
+        );
+    }
+
+    #[test]
+    fn parse_context_in_v0_tree() {
+        // v0 header, CONTEXT op code
+        let tree = parse_strict(vec![0, OpCode::CONTEXT.value()]).unwrap();
+        assert_eq!(*tree.proposition().unwrap(), Expr::Context);
+    }
+
+    #[test]
+    fn parse_context_in_v1_tree() {
+        // v1 header, CONTEXT op code
+        let tree = parse_strict(vec![1, OpCode::CONTEXT.value()]).unwrap();
+        assert_eq!(*tree.proposition().unwrap(), Expr::Context);
+    }
+
     #[test]
     fn test_constant_segregation() {
         let expr = Expr::Const(Constant {
@@ -366,4 +821,149 @@ mod tests {
             .unwrap();
         assert_eq!(*parsed_expr, expr)
     }
+
+    #[test]
+    fn with_constants() {
+        // sigmaProp(HEIGHT > 100) && pk
+        let tree_expr = |height: i32, pk: ProveDlog| -> Expr {
+            SigmaAnd {
+                items: vec![
+                    BoolToSigmaProp {
+                        input: Box::new(Expr::BinOp(
+                            BinOp::Relation(RelationOp::Gt),
+                            Box::new(GlobalVars::Height.into()),
+                            Box::new(Expr::Const(height.into())),
+                        )),
+                    }
+                    .into(),
+                    Expr::Const(SigmaProp::from(pk).into()),
+                ],
+            }
+            .into()
+        };
+        let tree =
+            ErgoTree::with_segregation(Rc::new(tree_expr(100, force_any_val::<ProveDlog>())));
+        let new_pk = force_any_val::<ProveDlog>();
+        let new_tree = tree
+            .with_constants(vec![
+                (0, 200i32.into()),
+                (1, SigmaProp::from(new_pk.clone()).into()),
+            ])
+            .unwrap();
+        assert_eq!(
+            *new_tree.proposition().unwrap(),
+            tree_expr(200, new_pk.clone())
+        );
+        // serialized with the new constants
+        let parsed = ErgoTree::sigma_parse_bytes(new_tree.sigma_serialize_bytes()).unwrap();
+        assert_eq!(parsed, new_tree);
+    }
+
+    #[test]
+    fn with_constants_rejected() {
+        let tree = ErgoTree::with_segregation(Rc::new(Expr::BinOp(
+            BinOp::Relation(RelationOp::Gt),
+            Box::new(GlobalVars::Height.into()),
+            Box::new(Expr::Const(100i32.into())),
+        )));
+        // the whole batch is rejected if any of the constants has a wrong type
+        assert_eq!(
+            tree.with_constants(vec![(0, 200i32.into()), (0, 200i64.into())]),
+            Err(SetConstantError::TypeMismatch {
+                index: 0,
+                expected: SType::SInt,
+                actual: SType::SLong,
+            })
+        );
+        assert_eq!(
+            tree.with_constants(vec![(0, 200i32.into()), (1, 200i32.into())]),
+            Err(SetConstantError::IndexOutOfBounds { index: 1, len: 1 })
+        );
+    }
+
+    #[test]
+    fn set_constant() {
+        // sigmaProp(HEIGHT > 100 && HEIGHT < 7000)
+        let tree = ErgoTree::with_segregation(Rc::new(
+            BoolToSigmaProp {
+                input: Box::new(Expr::BinOp(
+                    BinOp::Logical(LogicalOp::And),
+                    Box::new(Expr::BinOp(
+                        BinOp::Relation(RelationOp::Gt),
+                        Box::new(GlobalVars::Height.into()),
+                        Box::new(Expr::Const(100i32.into())),
+                    )),
+                    Box::new(Expr::BinOp(
+                        BinOp::Relation(RelationOp::Lt),
+                        Box::new(GlobalVars::Height.into()),
+                        Box::new(Expr::Const(7000i32.into())),
+                    )),
+                )),
+            }
+            .into(),
+        ));
+        assert_eq!(
+            tree.constants().unwrap(),
+            &[Constant::from(100i32), Constant::from(7000i32)][..]
+        );
+        let new_tree = tree.set_constant(1, 8000i32.into()).unwrap();
+        assert_eq!(
+            new_tree.constants().unwrap(),
+            &[Constant::from(100i32), Constant::from(8000i32)][..]
+        );
+        // header, constants count, first constant
+        let offset = 2 + Constant::from(100i32).sigma_serialize_bytes().len();
+        let old_const = Constant::from(7000i32).sigma_serialize_bytes();
+        let new_const = Constant::from(8000i32).sigma_serialize_bytes();
+        assert_eq!(old_const.len(), new_const.len());
+        let bytes = tree.sigma_serialize_bytes();
+        let new_bytes = new_tree.sigma_serialize_bytes();
+        assert_eq!(bytes.len(), new_bytes.len());
+        assert_eq!(bytes[..offset], new_bytes[..offset]);
+        assert_eq!(bytes[offset..offset + old_const.len()], old_const[..]);
+        assert_eq!(new_bytes[offset..offset + new_const.len()], new_const[..]);
+        assert_eq!(
+            bytes[offset + old_const.len()..],
+            new_bytes[offset + new_const.len()..]
+        );
+        assert_eq!(
+            tree.set_constant(2, 8000i32.into()),
+            Err(SetConstantError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+    }
+
+    // serialized trees of the boxes from mainnet
+    const MAINNET_TREES: [&str; 10] = [
+        // P2PK
+        "0008cd0327e65711a59378c59359c3e1d0f7abe906479eccb76094e50fe79d743ccc15e6",
+        // P2PK
+        "0008cd03f1102eb87a4166bf9fbd6247d087e92e1412b0e819dbb5fbc4e716091ec4e4ec",
+        // mining reward (miner public key and the height lock)
+        "100204a00b08cd021dde34603426402615658f1d970cfa7c7bd92ac81a8b16eeebff264d59ce4604ea02d192a39a8cc7a70173007301",
+        // transaction fee
+        "1005040004000e36100204a00b08cd0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798ea02d192a39a8cc7a701730073011001020402d19683030193a38cc7b2a57300000193c2b2a57301007473027303830108cdeeac93b1a57304",
+        // assets contract reading an oracle data input
+        "100604000400050004000e20b662db51cf2dc39f110a021c2a31c74f0a1a18ffffbf73e8a051a7b8c0f09ebc0e2079974b2314c531e62776e6bc4babff35b37b178cebf0976fc0f416ff34ddbc4fd803d601b2a5730000d602e4c6a70407d603b2db6501fe730100ea02d1ededededed93e4c672010407720293e4c67201050ec5720391e4c672010605730293c27201c2a793db63087201db6308a7ed938cb2db6308720373030001730493cbc272037305cd7202",
+        // emission
+        "101004020e36100204a00b08cd0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798ea02d192a39a8cc7a7017300730110010204020404040004c0fd4f05808c82f5f6030580b8c9e5ae040580f882ad16040204c0944004c0f407040004000580f882ad16d19683030191a38cc7a7019683020193c2b2a57300007473017302830108cdeeac93a38cc7b2a573030001978302019683040193b1a5730493c2a7c2b2a573050093958fa3730673079973089c73097e9a730a9d99a3730b730c0599c1a7c1b2a5730d00938cc7b2a5730e0001a390c1a7730f",
+        // assets contract reading an oracle data input (register arithmetic)
+        "101f0400040004020402040004000402050005000580dac4090580dac409050005c00c05c80104000e20b662db51cf2dc39f110a021c2a31c74f0a1a18ffffbf73e8a051a7b8c0f09ebc0580dac40904040404050005feffffffffffffffff01050005e807050005e807050005a0060101050005c00c05a006d81ed601b2db6501fe730000d602b2a5730100d603c17202d604db6308a7d605b27204730200d6068c720502d607db63087202d608b27207730300d6098c720802d60a9472067209d60bb27204730400d60c8c720b02d60db27207730500d60e8c720d02d60f94720c720ed610e4c6a70505d611e4c672020505d612e4c6a70405d613e4c672020405d614b2a5730600d615e4c672140405d61695720a73077215d61795720a72157308d61899c1a77309d619e4c672140505d61a997203730ad61be4c672010405d61ca172189c7212721bd61d9c7213721bd61e9593721d730b730c9d9c721a730d721dd1ededed938cb2db63087201730e0001730fedededed9272037310edec720a720fefed720a720fed939a720672109a72097211939a720c72129a720e7213eded939a721272167213939a721072177211939a72187219721aeded938c720d018c720b01938c7208018c720501938cb27207731100018cb272047312000193721995720f9ca1721b95937212731373149d721c72127216d801d61f997218721c9c9593721f7315731695937210731773189d721f7210721795720f95917216731992721e731a731b95917217731c90721e731d92721e731e",
+        // assets contract with BigInt arithmetic (v1, with the tree size)
+        "19a3030f0400040204020404040404060406058080a0f6f4acdbe01b058080a0f6f4acdbe01b050004d00f0400040005000500d81ad601b2a5730000d602e4c6a70405d603db63087201d604db6308a7d605b27203730100d606b27204730200d607b27203730300d608b27204730400d609b27203730500d60ab27204730600d60b9973078c720602d60c999973088c720502720bd60d8c720802d60e998c720702720dd60f91720e7309d6108c720a02d6117e721006d6127e720e06d613998c7209027210d6147e720d06d615730ad6167e721306d6177e720c06d6187e720b06d6199c72127218d61a9c72167218d1edededededed93c27201c2a793e4c672010405720292c17201c1a793b27203730b00b27204730c00938c7205018c720601ed938c7207018c720801938c7209018c720a019593720c730d95720f929c9c721172127e7202069c7ef07213069a9c72147e7215067e9c720e720206929c9c721472167e7202069c7ef0720e069a9c72117e7215067e9c721372020695ed720f917213730e907217a19d721972149d721a7211ed9272199c7217721492721a9c72177211",
+        // assets contract reading an oracle data input (height conditions)
+        "1027040004000402040204000400040205000500040005c8010500050005feffffffffffffffff0105000580897a05000580897a040205c80104d48e2e05c00c0580a8d6b907050005c80101010580dac4090404040405000501050005a0060101050005a006010101000400d802d601db6501fed602b17201d1ec959172027300d81fd603b2a5730100d604c17203d605db6308a7d606b27205730200d6078c720602d608db63087203d609b27208730300d60a8c720902d60b947207720ad60cb27205730400d60d8c720c02d60eb27208730500d60f8c720e02d61094720d720fd611e4c6a70505d612e4c672030505d613e4c6a70405d614e4c672030405d615b2a5730600d616e4c672150405d61795720b73077216d61895720b72167308d619c1a7d61ae4c672150505d61b9de4c6b272017309000405730ad61ca2a172199c7213721b730bd61d9572109ca1721b95937213730c730d9d721c72137217d801d61d997219721c9c9593721d730e730f95937211731073119d721d72117218d61e9d9c721d7e7312057313d61f9c7214721bd6209591a3731473157316d6219593721f731772209d9c72047318721fedededed7319edededed927204731aedec720b7210efed720b7210ed939a720772119a720a7212939a720d72139a720f7214eded939a721372177214939a721172187212939a7219721a7204eded938c720e018c720c01938c7209018c720601938cb27208731b00018cb27205731c000193721a9a721d958f721e731d9c721e731e721e95721095917217731f9272217320732195917218732290722172209272217323732473259372027326",
+        // P2PK
+        "0008cd03c91e9783f89b7bf6e5b65cf866180c34b6c2476d8dd442882b4ef0f717d2caba",
+    ];
+
+    #[test]
+    fn mainnet_trees_roundtrip() {
+        for tree_hex in MAINNET_TREES.iter() {
+            let bytes = base16::decode(tree_hex).unwrap();
+            let tree = parse_strict(bytes.clone()).unwrap();
+            assert_eq!(tree.sigma_serialize_bytes(), bytes, "tree: {}", tree_hex);
+            let expr = tree.proposition().unwrap();
+            assert_eq!(expr.tpe(), SType::SSigmaProp, "tree: {}", tree_hex);
+        }
+    }
 }
@@ -1,9 +1,13 @@
 //! Interpreter
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::ast::constant::TryExtractFromError;
 use crate::ast::expr::Expr;
 use crate::ast::value::Value;
+use crate::big_integer::BigIntegerError;
+use crate::ergo_tree::SetConstantError;
+use crate::serialization::SerializationError;
 use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
 
 use cost_accum::CostAccumulator;
@@ -14,20 +18,85 @@ use self::cost_accum::CostError;
 
 mod costs;
 
+pub(crate) mod and;
+pub(crate) mod bin_op;
+pub(crate) mod block;
+pub(crate) mod bool_to_sigma;
+pub(crate) mod box_methods;
+pub(crate) mod byte_array_to_bigint;
+pub(crate) mod calc_blake2b256;
+pub(crate) mod calc_sha256;
+pub(crate) mod coll_append;
+pub(crate) mod coll_by_index;
+pub(crate) mod coll_exists;
+pub(crate) mod coll_forall;
+pub(crate) mod coll_map;
+pub(crate) mod coll_size;
+pub(crate) mod coll_slice;
+pub(crate) mod collection;
 pub(crate) mod context;
 pub(crate) mod cost_accum;
+pub(crate) mod create_provedlog;
+pub(crate) mod decode_point;
+pub(crate) mod downcast;
+pub(crate) mod exponentiate;
 pub(crate) mod expr;
+pub(crate) mod extract_amount;
+pub(crate) mod extract_bytes;
+pub(crate) mod extract_bytes_with_no_ref;
+pub(crate) mod extract_creation_info;
+pub(crate) mod extract_id;
+pub(crate) mod extract_script_bytes;
+pub(crate) mod func_value;
+pub(crate) mod get_var;
 pub(crate) mod global_vars;
+pub(crate) mod if_op;
+pub(crate) mod logical_not;
 pub(crate) mod method_call;
+pub(crate) mod multiply_group;
+pub(crate) mod negation;
+pub(crate) mod option_filter;
+pub(crate) mod option_get;
+pub(crate) mod option_get_or_else;
+pub(crate) mod option_is_defined;
+pub(crate) mod option_map;
+pub(crate) mod or;
 pub(crate) mod property_call;
+pub(crate) mod select_field;
+pub(crate) mod sigma_and;
+pub(crate) mod sigma_atleast;
+pub(crate) mod sigma_or;
+pub(crate) mod subst_constants;
+pub(crate) mod tuple;
+pub(crate) mod upcast;
+pub(crate) mod val_use;
+pub(crate) mod xor;
+pub(crate) mod xor_of;
 
 /// Environment for the interpreter
-pub struct Env();
+#[derive(Debug, Clone)]
+pub struct Env {
+    store: HashMap<u32, Value>,
+}
 
 impl Env {
     /// Empty environment
     pub fn empty() -> Env {
-        Env()
+        Env {
+            store: HashMap::new(),
+        }
+    }
+
+    /// New environment with the given value bound to `idx` (in addition to the existing bindings)
+    pub fn extend(&self, idx: u32, v: Value) -> Env {
+        let mut store = self.store.clone();
+        store.insert(idx, v);
+        Env { store }
+    }
+
+    /// Value bound to `idx`
+    pub fn get(&self, idx: u32) -> Option<&Value> {
+        self.store.get(&idx)
     }
 }
 
@@ -47,9 +116,55 @@ pub enum EvalError {
     /// Unexpected value type
     #[error("Unexpected value type: {0:?}")]
     TryExtractFrom(#[from] TryExtractFromError),
+    /// Collection index is out of bounds
+    #[error("Index {index} is out of bounds for collection of length {len}")]
+    IndexOutOfBounds {
+        /// Requested index
+        index: i32,
+        /// Collection length
+        len: usize,
+    },
+    /// Requested value is absent (e.g. `Option.get` on `None`)
+    #[error("Not found: {0}")]
+    NotFound(String),
+    /// Invalid big integer value
+    #[error("BigInteger error: {0}")]
+    BigIntegerError(#[from] BigIntegerError),
+    /// Failed to parse the value from bytes
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] SerializationError),
+    /// Arithmetic operation overflowed the numeric type or division by zero
+    #[error("Arithmetic exception: {0}")]
+    ArithmeticException(String),
+    /// `atLeast` bound is out of `0..=props.len()` range
+    #[error("atLeast bound {bound} is out of range for {props_count} sigma propositions")]
+    AtleastBoundOutOfRange {
+        /// Requested bound
+        bound: i32,
+        /// Number of sigma propositions
+        props_count: usize,
+    },
+    /// Tuple field index is out of bounds
+    #[error("Field index {index} is out of bounds for tuple of size {len}")]
+    TupleFieldIndexOutOfBounds {
+        /// Requested field index (1-based)
+        index: u8,
+        /// Tuple size
+        len: usize,
+    },
+    /// Value is not suitable for the operation (e.g. byte arrays of different lengths)
+    #[error("Unexpected value: {0}")]
+    UnexpectedValue(String),
+    /// Script reads a context field that was not supplied
+    #[error("Context field is missing: {0}")]
+    ContextFieldMissing(String),
+    /// Failed to replace ErgoTree constants (`substConstants`)
+    #[error("Failed to substitute constants: {0}")]
+    SetConstantError(#[from] SetConstantError),
 }
 
 /// Result of expression reduction procedure (see `reduce_to_crypto`).
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ReductionResult {
     /// value of SigmaProp type which represents a statement verifiable via sigma protocol.
     pub sigma_prop: SigmaBoolean,
@@ -68,31 +183,60 @@ pub trait Evaluator {
     ) -> Result<ReductionResult, EvalError> {
         let cost_accum = CostAccumulator::new(0, None);
         let mut ectx = EvalContext::new(ctx, cost_accum);
-        expr.eval(env, &mut ectx)
-            .and_then(|v| -> Result<ReductionResult, EvalError> {
-                match v {
-                    Value::Boolean(b) => Ok(ReductionResult {
-                        sigma_prop: SigmaBoolean::TrivialProp(b),
-                        cost: 0,
-                    }),
-                    Value::SigmaProp(sp) => Ok(ReductionResult {
-                        sigma_prop: sp.value().clone(),
-                        cost: 0,
-                    }),
-                    _ => Err(EvalError::InvalidResultType),
-                }
-            })
+        reduce(expr, env, &mut ectx)
+    }
+
+    /// Same as `reduce_to_crypto`, but calls `tracer` on every evaluated node
+    fn reduce_to_crypto_traced(
+        &self,
+        expr: &Expr,
+        env: &Env,
+        ctx: Rc<Context>,
+        tracer: EvalTracer,
+    ) -> Result<ReductionResult, EvalError> {
+        let cost_accum = CostAccumulator::new(0, None);
+        let mut ectx = EvalContext::new(ctx, cost_accum).with_tracer(tracer);
+        reduce(expr, env, &mut ectx)
     }
 }
 
+fn reduce(expr: &Expr, env: &Env, ectx: &mut EvalContext) -> Result<ReductionResult, EvalError> {
+    let sigma_prop = match expr.eval(env, ectx)? {
+        Value::Boolean(b) => SigmaBoolean::TrivialProp(b),
+        Value::SigmaProp(sp) => sp.value().clone(),
+        _ => return Err(EvalError::InvalidResultType),
+    };
+    Ok(ReductionResult {
+        sigma_prop,
+        cost: ectx.cost_accum.total(),
+    })
+}
+
+/// Callback invoked with every evaluated node and its evaluation result
+pub type EvalTracer = Box<dyn FnMut(&Expr, &Result<Value, EvalError>)>;
+
 pub struct EvalContext {
     ctx: Rc<Context>,
     cost_accum: CostAccumulator,
+    tracer: Option<EvalTracer>,
 }
 
 impl EvalContext {
     pub fn new(ctx: Rc<Context>, cost_accum: CostAccumulator) -> Self {
-        EvalContext { ctx, cost_accum }
+        EvalContext {
+            ctx,
+            cost_accum,
+            tracer: None,
+        }
+    }
+
+    /// Set a tracer to be called after each node is evaluated (children before parents),
+    /// useful to find out which node failed the evaluation
+    pub fn with_tracer(self, tracer: EvalTracer) -> Self {
+        EvalContext {
+            tracer: Some(tracer),
+            ..self
+        }
     }
 }
 
@@ -137,17 +281,83 @@ pub trait Evaluable {
 #[cfg(test)]
 pub mod tests {
 
+    use std::cell::RefCell;
+
+    use crate::ast::constant::Constant;
     use crate::ast::constant::TryExtractFrom;
+    use crate::ast::get_var::GetVar;
+    use crate::ast::ops::BinOp;
+    use crate::ast::ops::RelationOp;
+    use crate::ast::option_get::OptionGet;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
 
     use super::*;
 
     pub fn eval_out<T: TryExtractFrom<Value>>(expr: &Expr, ctx: Rc<Context>) -> T {
+        try_eval_out(expr, ctx).unwrap()
+    }
+
+    pub fn try_eval_out<T: TryExtractFrom<Value>>(
+        expr: &Expr,
+        ctx: Rc<Context>,
+    ) -> Result<T, EvalError> {
         use crate::ast::constant::TryExtractInto;
         let cost_accum = CostAccumulator::new(0, None);
         let mut ectx = EvalContext::new(ctx, cost_accum);
         expr.eval(&Env::empty(), &mut ectx)
-            .unwrap()
-            .try_extract_into::<T>()
-            .unwrap()
+            .and_then(|v| v.try_extract_into::<T>().map_err(EvalError::from))
+    }
+
+    #[test]
+    fn tracer_visits_children_first() {
+        let l: Constant = 1i32.into();
+        let r: Constant = 2i32.into();
+        let expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(l.clone().into()),
+            Box::new(r.clone().into()),
+        );
+        let trace = Rc::new(RefCell::new(Vec::new()));
+        let trace_c = trace.clone();
+        let mut ectx = EvalContext::new(
+            Rc::new(force_any_val::<Context>()),
+            CostAccumulator::new(0, None),
+        )
+        .with_tracer(Box::new(move |e, res| {
+            trace_c.borrow_mut().push((e.clone(), res.clone()))
+        }));
+        let res = expr.eval(&Env::empty(), &mut ectx);
+        assert_eq!(res, Ok(Value::Boolean(false)));
+        assert_eq!(
+            *trace.borrow(),
+            vec![
+                (l.clone().into(), Ok(l.v)),
+                (r.clone().into(), Ok(r.v)),
+                (expr, Ok(Value::Boolean(false)))
+            ]
+        );
+    }
+
+    #[test]
+    fn tracer_records_failed_node() {
+        let get_var: Expr = GetVar {
+            var_id: 99,
+            var_tpe: SType::SInt,
+        }
+        .into();
+        let expr: Expr = OptionGet {
+            input: Box::new(get_var.clone()),
+        }
+        .into();
+        let trace = Rc::new(RefCell::new(Vec::new()));
+        let trace_c = trace.clone();
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.clear();
+        let mut ectx = EvalContext::new(Rc::new(ctx), CostAccumulator::new(0, None)).with_tracer(
+            Box::new(move |e, res| trace_c.borrow_mut().push((e.clone(), res.is_ok()))),
+        );
+        assert!(expr.eval(&Env::empty(), &mut ectx).is_err());
+        assert_eq!(*trace.borrow(), vec![(get_var, true), (expr, false)]);
     }
 }
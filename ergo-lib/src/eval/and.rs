@@ -0,0 +1,134 @@
+use crate::ast::and::And;
+use crate::ast::collection::Collection;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for And {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        if let Expr::Collection(Collection::Exprs { items, .. }) = self.input.as_ref() {
+            // explicitly listed elements (`allOf(Coll(a, b, c))`), stop on the first false
+            for item in items {
+                if !item.eval(env, ectx)?.try_extract_into::<bool>()? {
+                    return Ok(false.into());
+                }
+            }
+            return Ok(true.into());
+        }
+        let input = self
+            .input
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<bool>>()?;
+        Ok(input.into_iter().all(|b| b).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::serialization::SigmaSerializable;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn all_of(coll_bytes: Vec<u8>) -> bool {
+        // Coll[Boolean] constant with bit-packed elements
+        let input = Expr::sigma_parse_bytes(coll_bytes).unwrap();
+        let expr: Expr = And {
+            input: Box::new(input),
+        }
+        .into();
+        eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_bit_packed() {
+        // Coll(true, false, true)
+        assert!(!all_of(vec![0x0d, 0x03, 0x05]));
+        // 10 x true
+        assert!(all_of(vec![0x0d, 0x0a, 0xff, 0x03]));
+        // 10 x true with the last one false
+        assert!(!all_of(vec![0x0d, 0x0a, 0xff, 0x01]));
+        // empty
+        assert!(all_of(vec![0x0d, 0x00]));
+    }
+
+    #[test]
+    fn eval_exprs_short_circuit() {
+        // the last item is not a boolean and fails if evaluated
+        let expr: Expr = And {
+            input: Box::new(
+                Collection::Exprs {
+                    elem_tpe: SType::SBoolean,
+                    items: vec![
+                        Constant::from(true).into(),
+                        Constant::from(false).into(),
+                        Constant::from(1i32).into(),
+                    ],
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert!(!eval_out::<bool>(
+            &expr,
+            Rc::new(force_any_val::<Context>())
+        ));
+    }
+
+    fn all_of_exprs(items: Vec<Constant>) -> Result<bool, EvalError> {
+        let expr: Expr = And {
+            input: Box::new(
+                Collection::Exprs {
+                    elem_tpe: SType::SBoolean,
+                    items: items.into_iter().map(Expr::Const).collect(),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        try_eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval(bools in vec(any::<bool>(), 0..64)) {
+            let expected = bools.iter().all(|b| *b);
+            let expr: Expr = And {
+                input: Box::new(Constant::from(bools.clone()).into()),
+            }
+            .into();
+            prop_assert_eq!(
+                eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>())),
+                expected
+            );
+            prop_assert_eq!(
+                all_of_exprs(bools.into_iter().map(Constant::from).collect()),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_exprs_empty() {
+        assert_eq!(all_of_exprs(vec![]), Ok(true));
+    }
+
+    #[test]
+    fn eval_exprs_non_boolean() {
+        assert!(all_of_exprs(vec![true.into(), 1i32.into()]).is_err());
+    }
+}
@@ -0,0 +1,582 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::ops::BinOp;
+use crate::ast::ops::BitOp;
+use crate::ast::ops::LogicalOp;
+use crate::ast::ops::NumOp;
+use crate::ast::ops::RelationOp;
+use crate::ast::ops::ShiftOp;
+use crate::ast::value::Value;
+use crate::big_integer::BigInteger;
+
+use std::convert::TryFrom;
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use num_bigint::BigInt;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+/// Numeric value supporting checked arithmetic (overflow and division by zero yield `None`)
+trait CheckedNum: Sized + Ord {
+    fn is_zero(&self) -> bool;
+    fn checked_op(self, op: &NumOp, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_num {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedNum for $t {
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                fn checked_op(self, op: &NumOp, other: Self) -> Option<Self> {
+                    match op {
+                        NumOp::Add => self.checked_add(other),
+                        NumOp::Subtract => self.checked_sub(other),
+                        NumOp::Multiply => self.checked_mul(other),
+                        NumOp::Divide => self.checked_div(other),
+                        // MIN % -1 is 0 (same as on JVM), the only case where wrapping_rem wraps
+                        NumOp::Modulo if other != 0 => Some(self.wrapping_rem(other)),
+                        NumOp::Modulo => None,
+                        NumOp::Min => Some(self.min(other)),
+                        NumOp::Max => Some(self.max(other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_num!(i8, i16, i32, i64);
+
+impl CheckedNum for BigInteger {
+    fn is_zero(&self) -> bool {
+        BigInteger::is_zero(self)
+    }
+
+    fn checked_op(self, op: &NumOp, other: Self) -> Option<Self> {
+        match op {
+            NumOp::Add => self.checked_add(&other),
+            NumOp::Subtract => self.checked_sub(&other),
+            NumOp::Multiply => self.checked_mul(&other),
+            NumOp::Divide => self.checked_div(&other),
+            NumOp::Modulo => self.checked_mod(&other),
+            NumOp::Min => Some(self.min(other)),
+            NumOp::Max => Some(self.max(other)),
+        }
+    }
+}
+
+// no wrapping on overflow, same as in sigmastate
+fn eval_num_op<T: CheckedNum>(op: &NumOp, l: T, r: T) -> Result<T, EvalError> {
+    if matches!(op, NumOp::Divide | NumOp::Modulo) && r.is_zero() {
+        return Err(EvalError::ArithmeticException(
+            "division by zero".to_string(),
+        ));
+    }
+    l.checked_op(op, r)
+        .ok_or_else(|| EvalError::ArithmeticException(format!("{:?} overflow", op)))
+}
+
+fn eval_bit_op<T>(op: &BitOp, l: T, r: T) -> T
+where
+    T: BitAnd<Output = T> + BitOr<Output = T> + BitXor<Output = T>,
+{
+    match op {
+        BitOp::And => l & r,
+        BitOp::Or => l | r,
+        BitOp::Xor => l ^ r,
+    }
+}
+
+// shift amount is masked the same way as on JVM (by 31 for Int and by 63 for Long)
+fn eval_int_shift(op: &ShiftOp, l: i32, r: i32) -> i32 {
+    match op {
+        ShiftOp::Left => l.wrapping_shl(r as u32),
+        ShiftOp::Right => l.wrapping_shr(r as u32),
+        ShiftOp::RightZeroed => (l as u32).wrapping_shr(r as u32) as i32,
+    }
+}
+
+fn eval_long_shift(op: &ShiftOp, l: i64, r: i64) -> i64 {
+    match op {
+        ShiftOp::Left => l.wrapping_shl(r as u32),
+        ShiftOp::Right => l.wrapping_shr(r as u32),
+        ShiftOp::RightZeroed => (l as u64).wrapping_shr(r as u32) as i64,
+    }
+}
+
+fn eval_big_int_shift(op: &ShiftOp, l: BigInteger, r: BigInteger) -> Result<BigInteger, EvalError> {
+    if r < BigInteger::from(0i64) {
+        return Err(EvalError::ArithmeticException(format!(
+            "negative shift amount: {}",
+            r
+        )));
+    }
+    let bits = usize::try_from(&BigInt::from(r)).unwrap_or(usize::MAX);
+    match op {
+        ShiftOp::Left => l.checked_shl(bits).ok_or_else(|| {
+            EvalError::ArithmeticException("BigInt left shift overflow".to_string())
+        }),
+        ShiftOp::Right => Ok(l.shr(bits)),
+        // BigInt has no fixed width to fill with zeroes
+        ShiftOp::RightZeroed => Err(EvalError::UnexpectedExpr),
+    }
+}
+
+fn eval_ordering<T: Ord>(op: &RelationOp, l: T, r: T) -> bool {
+    match op {
+        RelationOp::Eq => l == r,
+        RelationOp::NEq => l != r,
+        RelationOp::Lt => l < r,
+        RelationOp::Le => l <= r,
+        RelationOp::Gt => l > r,
+        RelationOp::Ge => l >= r,
+    }
+}
+
+fn eval_relation(op: &RelationOp, l: Value, r: Value) -> Result<bool, EvalError> {
+    match op {
+        RelationOp::Eq => Ok(l == r),
+        RelationOp::NEq => Ok(l != r),
+        _ => match (l, r) {
+            (Value::Byte(l), Value::Byte(r)) => Ok(eval_ordering(op, l, r)),
+            (Value::Short(l), Value::Short(r)) => Ok(eval_ordering(op, l, r)),
+            (Value::Int(l), Value::Int(r)) => Ok(eval_ordering(op, l, r)),
+            (Value::Long(l), Value::Long(r)) => Ok(eval_ordering(op, l, r)),
+            (Value::BigInt(l), Value::BigInt(r)) => Ok(eval_ordering(op, l, r)),
+            _ => Err(EvalError::UnexpectedExpr),
+        },
+    }
+}
+
+fn eval_logical_op(
+    op: &LogicalOp,
+    l: &Expr,
+    r: &Expr,
+    env: &Env,
+    ectx: &mut EvalContext,
+) -> Result<bool, EvalError> {
+    let v_l = l.eval(env, ectx)?.try_extract_into::<bool>()?;
+    match op {
+        LogicalOp::And if !v_l => Ok(false),
+        LogicalOp::Or if v_l => Ok(true),
+        LogicalOp::And | LogicalOp::Or => Ok(r.eval(env, ectx)?.try_extract_into::<bool>()?),
+        LogicalOp::Xor => Ok(v_l ^ r.eval(env, ectx)?.try_extract_into::<bool>()?),
+    }
+}
+
+/// Evaluate binary operation (operands of the numerical operations must be of the same type)
+pub(crate) fn eval_bin_op(
+    op: &BinOp,
+    l: &Expr,
+    r: &Expr,
+    env: &Env,
+    ectx: &mut EvalContext,
+) -> Result<Value, EvalError> {
+    match op {
+        BinOp::Logical(op) => eval_logical_op(op, l, r, env, ectx).map(Value::Boolean),
+        BinOp::Relation(op) => {
+            let v_l = l.eval(env, ectx)?;
+            let v_r = r.eval(env, ectx)?;
+            eval_relation(op, v_l, v_r).map(Value::Boolean)
+        }
+        BinOp::Num(op) => match (l.eval(env, ectx)?, r.eval(env, ectx)?) {
+            (Value::Byte(l), Value::Byte(r)) => eval_num_op(op, l, r).map(Value::Byte),
+            (Value::Short(l), Value::Short(r)) => eval_num_op(op, l, r).map(Value::Short),
+            (Value::Int(l), Value::Int(r)) => eval_num_op(op, l, r).map(Value::Int),
+            (Value::Long(l), Value::Long(r)) => eval_num_op(op, l, r).map(Value::Long),
+            (Value::BigInt(l), Value::BigInt(r)) => eval_num_op(op, l, r).map(Value::BigInt),
+            _ => Err(EvalError::UnexpectedExpr),
+        },
+        BinOp::Bit(op) => match (l.eval(env, ectx)?, r.eval(env, ectx)?) {
+            (Value::Byte(l), Value::Byte(r)) => Ok(Value::Byte(eval_bit_op(op, l, r))),
+            (Value::Short(l), Value::Short(r)) => Ok(Value::Short(eval_bit_op(op, l, r))),
+            (Value::Int(l), Value::Int(r)) => Ok(Value::Int(eval_bit_op(op, l, r))),
+            (Value::Long(l), Value::Long(r)) => Ok(Value::Long(eval_bit_op(op, l, r))),
+            (Value::BigInt(l), Value::BigInt(r)) => Ok(Value::BigInt(eval_bit_op(op, l, r))),
+            _ => Err(EvalError::UnexpectedExpr),
+        },
+        BinOp::Shift(op) => match (l.eval(env, ectx)?, r.eval(env, ectx)?) {
+            (Value::Int(l), Value::Int(r)) => Ok(Value::Int(eval_int_shift(op, l, r))),
+            (Value::Long(l), Value::Long(r)) => Ok(Value::Long(eval_long_shift(op, l, r))),
+            (Value::BigInt(l), Value::BigInt(r)) => eval_big_int_shift(op, l, r).map(Value::BigInt),
+            _ => Err(EvalError::UnexpectedExpr),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::constant::TryExtractFrom;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn eval_num<T: Into<Constant> + TryExtractFrom<Value>>(
+        op: NumOp,
+        l: T,
+        r: T,
+    ) -> Result<T, EvalError> {
+        let expr = Expr::BinOp(
+            BinOp::Num(op),
+            Box::new(l.into().into()),
+            Box::new(r.into().into()),
+        );
+        try_eval_out::<T>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    macro_rules! check_against_checked {
+        ($l:expr, $r:expr) => {
+            prop_assert_eq!(eval_num(NumOp::Add, $l, $r).ok(), $l.checked_add($r));
+            prop_assert_eq!(eval_num(NumOp::Subtract, $l, $r).ok(), $l.checked_sub($r));
+            prop_assert_eq!(eval_num(NumOp::Multiply, $l, $r).ok(), $l.checked_mul($r));
+            prop_assert_eq!(eval_num(NumOp::Divide, $l, $r).ok(), $l.checked_div($r));
+            prop_assert_eq!(
+                eval_num(NumOp::Modulo, $l, $r).ok(),
+                $l.checked_rem($r).or(if $r == -1 { Some(0) } else { None })
+            );
+            prop_assert_eq!(eval_num(NumOp::Min, $l, $r).ok(), Some($l.min($r)));
+            prop_assert_eq!(eval_num(NumOp::Max, $l, $r).ok(), Some($l.max($r)));
+        };
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn eval_byte(l in any::<i8>(), r in any::<i8>()) {
+            check_against_checked!(l, r);
+            check_against_checked!(i8::MIN, -1);
+        }
+
+        #[test]
+        fn eval_short(l in any::<i16>(), r in any::<i16>()) {
+            check_against_checked!(l, r);
+            check_against_checked!(i16::MIN, -1);
+        }
+
+        #[test]
+        fn eval_int(l in any::<i32>(), r in any::<i32>()) {
+            check_against_checked!(l, r);
+            check_against_checked!(i32::MIN, -1);
+        }
+
+        #[test]
+        fn eval_long(l in any::<i64>(), r in any::<i64>()) {
+            check_against_checked!(l, r);
+            check_against_checked!(i64::MIN, -1);
+        }
+    }
+
+    #[test]
+    fn eval_overflow() {
+        assert!(matches!(
+            eval_num(NumOp::Add, i32::MAX, 1),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert!(matches!(
+            eval_num(NumOp::Divide, i64::MIN, -1),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_division_by_zero() {
+        assert!(matches!(
+            eval_num(NumOp::Divide, 1i8, 0),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert!(matches!(
+            eval_num(
+                NumOp::Modulo,
+                BigInteger::from(1i64),
+                BigInteger::from(0i64)
+            ),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_big_int() {
+        assert_eq!(
+            eval_num(
+                NumOp::Multiply,
+                BigInteger::from(3i64),
+                BigInteger::from(-4i64)
+            ),
+            Ok(BigInteger::from(-12i64))
+        );
+        assert_eq!(
+            eval_num(
+                NumOp::Modulo,
+                BigInteger::from(-7i64),
+                BigInteger::from(2i64)
+            ),
+            Ok(BigInteger::from(1i64))
+        );
+        // same as java.math.BigInteger.mod, fails on non-positive modulus
+        assert!(matches!(
+            eval_num(
+                NumOp::Modulo,
+                BigInteger::from(7i64),
+                BigInteger::from(-2i64)
+            ),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_min_max() {
+        assert_eq!(eval_num(NumOp::Min, 10i64, -3i64), Ok(-3i64));
+        assert_eq!(
+            eval_num(
+                NumOp::Max,
+                BigInteger::from(-5i64),
+                BigInteger::from(i64::MAX)
+            ),
+            Ok(BigInteger::from(i64::MAX))
+        );
+    }
+
+    fn eval_cmp(op: RelationOp, l: Constant, r: Constant) -> Result<bool, EvalError> {
+        let expr = Expr::BinOp(BinOp::Relation(op), Box::new(l.into()), Box::new(r.into()));
+        try_eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn eval_ordering_long(l in any::<i64>(), r in any::<i64>()) {
+            prop_assert_eq!(eval_cmp(RelationOp::Lt, l.into(), r.into()), Ok(l < r));
+            prop_assert_eq!(eval_cmp(RelationOp::Le, l.into(), r.into()), Ok(l <= r));
+            prop_assert_eq!(eval_cmp(RelationOp::Gt, l.into(), r.into()), Ok(l > r));
+            prop_assert_eq!(eval_cmp(RelationOp::Ge, l.into(), r.into()), Ok(l >= r));
+        }
+    }
+
+    #[test]
+    fn eval_ordering_big_int_near_bounds() {
+        // 2^255 - 1 and -2^255
+        let max =
+            BigInteger::from_signed_bytes_be(&[&[0x7f][..], &[0xff; 31][..]].concat()).unwrap();
+        let min =
+            BigInteger::from_signed_bytes_be(&[&[0x80][..], &[0x00; 31][..]].concat()).unwrap();
+        let one = BigInteger::from(1i64);
+        let max_prev = max.checked_sub(&one).unwrap();
+        let min_next = min.checked_add(&one).unwrap();
+        // ascending
+        let values = [
+            min.clone(),
+            min_next,
+            BigInteger::from(i64::MIN),
+            BigInteger::from(0i64),
+            BigInteger::from(i64::MAX),
+            max_prev,
+            max,
+        ];
+        for (i, l) in values.iter().enumerate() {
+            for (j, r) in values.iter().enumerate() {
+                let cmp = |op| eval_cmp(op, l.clone().into(), r.clone().into());
+                assert_eq!(cmp(RelationOp::Lt), Ok(i < j));
+                assert_eq!(cmp(RelationOp::Le), Ok(i <= j));
+                assert_eq!(cmp(RelationOp::Gt), Ok(i > j));
+                assert_eq!(cmp(RelationOp::Ge), Ok(i >= j));
+                assert_eq!(cmp(RelationOp::Eq), Ok(i == j));
+            }
+        }
+    }
+
+    #[test]
+    fn eval_ordering_mixed_types() {
+        assert!(eval_cmp(RelationOp::Lt, 1i32.into(), 2i64.into()).is_err());
+    }
+
+    fn eval_logical(op: LogicalOp, l: Expr, r: Expr) -> Result<bool, EvalError> {
+        let expr = Expr::BinOp(BinOp::Logical(op), Box::new(l), Box::new(r));
+        try_eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_logical_ops() {
+        for (l, r) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+            let (el, er): (Expr, Expr) = (Constant::from(*l).into(), Constant::from(*r).into());
+            assert_eq!(
+                eval_logical(LogicalOp::And, el.clone(), er.clone()),
+                Ok(*l && *r)
+            );
+            assert_eq!(
+                eval_logical(LogicalOp::Or, el.clone(), er.clone()),
+                Ok(*l || *r)
+            );
+            assert_eq!(eval_logical(LogicalOp::Xor, el, er), Ok(*l ^ *r));
+        }
+    }
+
+    #[test]
+    fn eval_logical_short_circuit() {
+        // 1 / 0 == 1 fails if evaluated
+        let failing: Expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(Expr::BinOp(
+                BinOp::Num(NumOp::Divide),
+                Box::new(Constant::from(1i32).into()),
+                Box::new(Constant::from(0i32).into()),
+            )),
+            Box::new(Constant::from(1i32).into()),
+        );
+        assert_eq!(
+            eval_logical(
+                LogicalOp::And,
+                Constant::from(false).into(),
+                failing.clone()
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            eval_logical(LogicalOp::Or, Constant::from(true).into(), failing.clone()),
+            Ok(true)
+        );
+        assert!(eval_logical(LogicalOp::Xor, Constant::from(true).into(), failing).is_err());
+    }
+
+    fn eval_bit<T: Into<Constant> + TryExtractFrom<Value>>(
+        op: BitOp,
+        l: T,
+        r: T,
+    ) -> Result<T, EvalError> {
+        let expr = Expr::BinOp(
+            BinOp::Bit(op),
+            Box::new(l.into().into()),
+            Box::new(r.into().into()),
+        );
+        try_eval_out::<T>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    macro_rules! check_bit_ops {
+        ($l:expr, $r:expr) => {
+            prop_assert_eq!(eval_bit(BitOp::And, $l, $r), Ok($l & $r));
+            prop_assert_eq!(eval_bit(BitOp::Or, $l, $r), Ok($l | $r));
+            prop_assert_eq!(eval_bit(BitOp::Xor, $l, $r), Ok($l ^ $r));
+        };
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn eval_bit_byte(l in any::<i8>(), r in any::<i8>()) {
+            check_bit_ops!(l, r);
+        }
+
+        #[test]
+        fn eval_bit_short(l in any::<i16>(), r in any::<i16>()) {
+            check_bit_ops!(l, r);
+        }
+
+        #[test]
+        fn eval_bit_int(l in any::<i32>(), r in any::<i32>()) {
+            check_bit_ops!(l, r);
+        }
+
+        #[test]
+        fn eval_bit_long(l in any::<i64>(), r in any::<i64>()) {
+            check_bit_ops!(l, r);
+        }
+
+        #[test]
+        fn eval_bit_big_int(l in any::<i64>(), r in any::<i64>()) {
+            let (bl, br) = (BigInteger::from(l), BigInteger::from(r));
+            prop_assert_eq!(eval_bit(BitOp::And, bl.clone(), br.clone()), Ok(BigInteger::from(l & r)));
+            prop_assert_eq!(eval_bit(BitOp::Or, bl.clone(), br.clone()), Ok(BigInteger::from(l | r)));
+            prop_assert_eq!(eval_bit(BitOp::Xor, bl, br), Ok(BigInteger::from(l ^ r)));
+        }
+    }
+
+    fn eval_shift<T: Into<Constant> + TryExtractFrom<Value>>(
+        op: ShiftOp,
+        l: T,
+        r: T,
+    ) -> Result<T, EvalError> {
+        let expr = Expr::BinOp(
+            BinOp::Shift(op),
+            Box::new(l.into().into()),
+            Box::new(r.into().into()),
+        );
+        try_eval_out::<T>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_shift_edge_amounts() {
+        assert_eq!(eval_shift(ShiftOp::Left, 1i32, 0), Ok(1));
+        assert_eq!(eval_shift(ShiftOp::Left, 1i32, 31), Ok(i32::MIN));
+        // amount is masked by 31 (same as on JVM)
+        assert_eq!(eval_shift(ShiftOp::Left, 1i32, 32), Ok(1));
+        assert_eq!(eval_shift(ShiftOp::Right, -8i32, 33), Ok(-4));
+        assert_eq!(eval_shift(ShiftOp::RightZeroed, -1i32, 28), Ok(15));
+        assert_eq!(eval_shift(ShiftOp::Left, 1i64, 0), Ok(1));
+        assert_eq!(eval_shift(ShiftOp::Left, 1i64, 63), Ok(i64::MIN));
+        // amount is masked by 63 (same as on JVM)
+        assert_eq!(eval_shift(ShiftOp::Left, 1i64, 64), Ok(1));
+        assert_eq!(eval_shift(ShiftOp::Left, 3i64, -1), Ok(i64::MIN));
+        assert_eq!(eval_shift(ShiftOp::Right, i64::MIN, 63), Ok(-1));
+        assert_eq!(eval_shift(ShiftOp::RightZeroed, i64::MIN, 63), Ok(1));
+        assert_eq!(eval_shift(ShiftOp::RightZeroed, -1i64, 64), Ok(-1));
+    }
+
+    #[test]
+    fn eval_shift_big_int() {
+        let big = |v: i64| BigInteger::from(v);
+        assert_eq!(eval_shift(ShiftOp::Left, big(1), big(0)), Ok(big(1)));
+        assert_eq!(
+            eval_shift(ShiftOp::Left, big(-1), big(63)),
+            Ok(big(i64::MIN))
+        );
+        assert_eq!(
+            eval_shift(ShiftOp::Left, big(1), big(64)),
+            Ok(BigInteger::try_from(BigInt::from(1) << 64usize).unwrap())
+        );
+        assert!(matches!(
+            eval_shift(ShiftOp::Left, big(1), big(255)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert_eq!(eval_shift(ShiftOp::Right, big(-5), big(1)), Ok(big(-3)));
+        assert_eq!(
+            eval_shift(ShiftOp::Right, big(-5), big(i64::MAX)),
+            Ok(big(-1))
+        );
+        assert!(matches!(
+            eval_shift(ShiftOp::Left, big(1), big(-1)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert!(matches!(
+            eval_shift(ShiftOp::Right, big(1), big(-1)),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert!(eval_shift(ShiftOp::RightZeroed, big(1), big(1)).is_err());
+    }
+
+    #[test]
+    fn eval_mixed_types() {
+        let expr = Expr::BinOp(
+            BinOp::Num(NumOp::Add),
+            Box::new(Constant::from(1i32).into()),
+            Box::new(Constant::from(1i64).into()),
+        );
+        assert!(try_eval_out::<i64>(&expr, Rc::new(force_any_val::<Context>())).is_err());
+    }
+}
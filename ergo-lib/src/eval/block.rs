@@ -0,0 +1,95 @@
+use crate::ast::block::BlockValue;
+use crate::ast::expr::Expr;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for BlockValue {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let mut cur_env = env.clone();
+        for item in &self.items {
+            let val_def = match item {
+                Expr::ValDef(vd) => vd,
+                _ => return Err(EvalError::UnexpectedExpr),
+            };
+            let v = val_def.rhs.eval(&cur_env, ectx)?;
+            cur_env = cur_env.extend(val_def.id, v);
+        }
+        self.result.eval(&cur_env, ectx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, NumOp};
+    use crate::ast::val_def::ValDef;
+    use crate::ast::val_use::ValUse;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    #[test]
+    fn eval_bound_values() {
+        // { val a = 2L; val b = a * 3L; b + a }
+        let a: Expr = ValUse {
+            val_id: 1,
+            tpe: SType::SLong,
+        }
+        .into();
+        let b: Expr = ValUse {
+            val_id: 2,
+            tpe: SType::SLong,
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![
+                ValDef {
+                    id: 1,
+                    rhs: Box::new(Constant::from(2i64).into()),
+                }
+                .into(),
+                ValDef {
+                    id: 2,
+                    rhs: Box::new(Expr::BinOp(
+                        BinOp::Num(NumOp::Multiply),
+                        Box::new(a.clone()),
+                        Box::new(Constant::from(3i64).into()),
+                    )),
+                }
+                .into(),
+            ],
+            result: Box::new(Expr::BinOp(
+                BinOp::Num(NumOp::Add),
+                Box::new(b),
+                Box::new(a),
+            )),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i64>(&expr, ctx), 8);
+    }
+
+    #[test]
+    fn eval_non_val_def_item() {
+        let expr: Expr = BlockValue {
+            items: vec![GlobalVars::Height.into()],
+            result: Box::new(Constant::from(1i64).into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            try_eval_out::<i64>(&expr, ctx),
+            Err(EvalError::UnexpectedExpr)
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ast::bool_to_sigma::BoolToSigmaProp;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
+use crate::sigma_protocol::sigma_boolean::SigmaProp;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for BoolToSigmaProp {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<bool>()?;
+        Ok(SigmaProp::new(SigmaBoolean::TrivialProp(input)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_bool() {
+        for b in [true, false].iter() {
+            let expr: Expr = BoolToSigmaProp {
+                input: Box::new(Constant::from(*b).into()),
+            }
+            .into();
+            let ctx = Rc::new(force_any_val::<Context>());
+            assert_eq!(
+                eval_out::<SigmaProp>(&expr, ctx),
+                SigmaProp::new(SigmaBoolean::TrivialProp(*b))
+            );
+        }
+    }
+
+    #[test]
+    fn eval_non_bool() {
+        let expr: Expr = BoolToSigmaProp {
+            input: Box::new(Constant::from(1i32).into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<SigmaProp>(&expr, ctx).is_err());
+    }
+}
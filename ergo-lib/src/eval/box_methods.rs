@@ -0,0 +1,227 @@
+use crate::ast::box_methods::BoxM;
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for BoxM {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self {
+            BoxM::ExtractRegisterAs {
+                input,
+                register_id,
+                tpe,
+            } => {
+                let b = input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+                match b.get_register(register_id.0) {
+                    Some(c) if c.tpe == *tpe => Ok(Some(c.v).into()),
+                    Some(c) => Err(TryExtractFromError(format!(
+                        "expected R{} to be {:?}, found {:?}",
+                        register_id.0, tpe, c.tpe
+                    ))
+                    .into()),
+                    None => Ok(Value::Opt(Box::new(None))),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::box_methods::RegisterId;
+    use crate::ast::coll_by_index::ByIndex;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::option_get::OptionGet;
+    use crate::ast::property_call::PropertyCall;
+    use crate::chain::ergo_box::NonMandatoryRegisters;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::serialization::SigmaSerializable;
+    use crate::test_util::force_any_val;
+    use crate::types::scontext;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn box_with_r4(r4: Constant) -> ErgoBox {
+        let b = force_any_val::<ErgoBox>();
+        ErgoBox::new(
+            b.value,
+            b.ergo_tree,
+            b.tokens,
+            NonMandatoryRegisters::from_ordered_values(vec![r4]).unwrap(),
+            b.creation_height,
+            b.transaction_id,
+            b.index,
+        )
+    }
+
+    fn get_r4_long(input: Expr) -> Expr {
+        OptionGet {
+            input: Box::new(
+                BoxM::ExtractRegisterAs {
+                    input: Box::new(input),
+                    register_id: RegisterId(4),
+                    tpe: SType::SLong,
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_self_r4() {
+        let ctx = Context {
+            self_box: box_with_r4(1i64.into()),
+            ..Context::dummy()
+        };
+        let expr = get_r4_long(GlobalVars::SelfBox.into());
+        assert_eq!(eval_out::<i64>(&expr, Rc::new(ctx)), 1);
+    }
+
+    #[test]
+    fn eval_register_type_mismatch() {
+        let ctx = Context {
+            self_box: box_with_r4(1i32.into()),
+            ..Context::dummy()
+        };
+        let expr = get_r4_long(GlobalVars::SelfBox.into());
+        assert!(try_eval_out::<i64>(&expr, Rc::new(ctx)).is_err());
+    }
+
+    #[test]
+    fn eval_value_register() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let expr: Expr = BoxM::ExtractRegisterAs {
+            input: Box::new(GlobalVars::SelfBox.into()),
+            register_id: RegisterId(0),
+            tpe: SType::SLong,
+        }
+        .into();
+        assert_eq!(
+            eval_out::<Option<i64>>(&expr, ctx.clone()),
+            Some(i64::from(ctx.self_box.value))
+        );
+    }
+
+    fn self_reg(register_id: u8, tpe: SType) -> Expr {
+        BoxM::ExtractRegisterAs {
+            input: Box::new(GlobalVars::SelfBox.into()),
+            register_id: RegisterId(register_id),
+            tpe,
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_mandatory_registers() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let b = &ctx.self_box;
+        // R1: Coll[Byte]
+        assert_eq!(
+            eval_out::<Option<Vec<u8>>>(&self_reg(1, SType::new_scoll(SType::SByte)), ctx.clone()),
+            Some(b.ergo_tree.sigma_serialize_bytes())
+        );
+        // R2: Coll[(Coll[Byte], Long)]
+        let tokens_tpe = SType::new_scoll(SType::STup(vec![
+            SType::new_scoll(SType::SByte),
+            SType::SLong,
+        ]));
+        assert_eq!(
+            eval_out::<Option<Vec<(Vec<u8>, i64)>>>(&self_reg(2, tokens_tpe), ctx.clone()),
+            Some(
+                b.tokens
+                    .iter()
+                    .map(|t| (t.token_id.0 .0.to_vec(), t.amount.into()))
+                    .collect()
+            )
+        );
+        // R3: (Int, Coll[Byte]) - creation height and tx id ++ output index
+        let creation_info_tpe = SType::STup(vec![SType::SInt, SType::new_scoll(SType::SByte)]);
+        let mut ref_bytes = b.transaction_id.0 .0.to_vec();
+        ref_bytes.extend_from_slice(&b.index.to_be_bytes());
+        assert_eq!(
+            eval_out::<Option<(i32, Vec<u8>)>>(&self_reg(3, creation_info_tpe), ctx.clone()),
+            Some((b.creation_height as i32, ref_bytes))
+        );
+    }
+
+    #[test]
+    fn eval_mandatory_register_type_mismatch() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<Option<i32>>(&self_reg(0, SType::SInt), ctx.clone()).is_err());
+        assert!(try_eval_out::<Option<i64>>(&self_reg(3, SType::SLong), ctx).is_err());
+    }
+
+    #[test]
+    fn eval_empty_register() {
+        let ctx = Context {
+            self_box: box_with_r4(1i64.into()),
+            ..Context::dummy()
+        };
+        assert_eq!(
+            eval_out::<Option<i64>>(&self_reg(5, SType::SLong), Rc::new(ctx)),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_empty_register_get() {
+        // SELF.R5[Long].get
+        let expr: Expr = OptionGet {
+            input: Box::new(self_reg(5, SType::SLong)),
+        }
+        .into();
+        let ctx = Context {
+            self_box: box_with_r4(1i64.into()),
+            ..Context::dummy()
+        };
+        assert_eq!(
+            try_eval_out::<i64>(&expr, Rc::new(ctx)),
+            Err(EvalError::NotFound("Option.get on None".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_data_input_r4_eq_self_r4() {
+        // CONTEXT.dataInputs(0).R4[Long].get == SELF.R4[Long].get
+        let data_input_r4: Expr = get_r4_long(
+            ByIndex {
+                input: Box::new(
+                    PropertyCall {
+                        obj: Box::new(Expr::Context),
+                        method: scontext::DATA_INPUTS_PROPERTY.clone(),
+                    }
+                    .into(),
+                ),
+                index: Box::new(Expr::Const(0i32.into())),
+                default: None,
+            }
+            .into(),
+        );
+        let expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(data_input_r4),
+            Box::new(get_r4_long(GlobalVars::SelfBox.into())),
+        );
+        let ctx = |oracle_value: i64| Context {
+            self_box: box_with_r4(42i64.into()),
+            data_inputs: vec![box_with_r4(oracle_value.into())],
+            ..Context::dummy()
+        };
+        assert!(eval_out::<bool>(&expr, Rc::new(ctx(42))));
+        assert!(!eval_out::<bool>(&expr, Rc::new(ctx(7))));
+    }
+}
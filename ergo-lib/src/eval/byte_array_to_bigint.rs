@@ -0,0 +1,105 @@
+use crate::ast::byte_array_to_bigint::ByteArrayToBigInt;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::big_integer::BigInteger;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ByteArrayToBigInt {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Vec<u8>>()?;
+        Ok(BigInteger::from_signed_bytes_be(&input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::big_integer::BigIntegerError;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn byte_array_to_bigint(bytes: Vec<u8>) -> Result<BigInteger, EvalError> {
+        let expr: Expr = ByteArrayToBigInt {
+            input: Box::new(Constant::from(bytes).into()),
+        }
+        .into();
+        try_eval_out::<BigInteger>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval() {
+        assert_eq!(
+            byte_array_to_bigint(vec![0x01, 0x00]),
+            Ok(BigInteger::from(256i64))
+        );
+        assert_eq!(byte_array_to_bigint(vec![0x00]), Ok(BigInteger::from(0i64)));
+        // two's complement
+        assert_eq!(
+            byte_array_to_bigint(vec![0xff]),
+            Ok(BigInteger::from(-1i64))
+        );
+        assert_eq!(
+            byte_array_to_bigint(vec![0xff, 0x00]),
+            Ok(BigInteger::from(-256i64))
+        );
+        assert_eq!(
+            byte_array_to_bigint(vec![0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+            Ok(BigInteger::from(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn eval_256_bit_bounds() {
+        // 2^255 - 1
+        let mut max = vec![0xffu8; 32];
+        max[0] = 0x7f;
+        assert!(byte_array_to_bigint(max).is_ok());
+        // -2^255
+        let mut min = vec![0u8; 32];
+        min[0] = 0x80;
+        assert!(byte_array_to_bigint(min).is_ok());
+        // 2^255
+        let mut over = vec![0u8; 33];
+        over[1] = 0x80;
+        assert!(matches!(
+            byte_array_to_bigint(over),
+            Err(EvalError::BigIntegerError(BigIntegerError::OutOfBounds(_)))
+        ));
+    }
+
+    #[test]
+    fn eval_empty() {
+        assert_eq!(
+            byte_array_to_bigint(vec![]),
+            Err(EvalError::BigIntegerError(BigIntegerError::EmptyBytes))
+        );
+    }
+
+    #[test]
+    fn eval_leading_sign_bytes() {
+        // redundant sign extension does not count towards the size limit
+        let mut bytes = vec![0u8; 40];
+        bytes[39] = 1;
+        assert_eq!(
+            eval_out::<BigInteger>(
+                &ByteArrayToBigInt {
+                    input: Box::new(Constant::from(bytes).into()),
+                }
+                .into(),
+                Rc::new(force_any_val::<Context>())
+            ),
+            BigInteger::from(1i64)
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::ast::calc_blake2b256::CalcBlake2b256;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::chain::blake2b256_hash;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for CalcBlake2b256 {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Vec<u8>>()?;
+        Ok(blake2b256_hash(&input).0.to_vec().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn blake2b256(input: Vec<u8>) -> Vec<u8> {
+        let expr: Expr = CalcBlake2b256 {
+            input: Box::new(Constant::from(input).into()),
+        }
+        .into();
+        eval_out::<Vec<u8>>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_empty() {
+        assert_eq!(
+            base16::encode_lower(&blake2b256(vec![])),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn eval_abc() {
+        assert_eq!(
+            base16::encode_lower(&blake2b256(b"abc".to_vec())),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+}
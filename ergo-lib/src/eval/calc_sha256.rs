@@ -0,0 +1,55 @@
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::ast::calc_sha256::CalcSha256;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for CalcSha256 {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Vec<u8>>()?;
+        Ok(Sha256::digest(&input).to_vec().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn sha256(input: Vec<u8>) -> Vec<u8> {
+        let expr: Expr = CalcSha256 {
+            input: Box::new(Constant::from(input).into()),
+        }
+        .into();
+        eval_out::<Vec<u8>>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_empty() {
+        assert_eq!(
+            base16::encode_lower(&sha256(vec![])),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn eval_abc() {
+        assert_eq!(
+            base16::encode_lower(&sha256(b"abc".to_vec())),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
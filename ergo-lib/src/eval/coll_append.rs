@@ -0,0 +1,107 @@
+use crate::ast::coll_append::Append;
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Coll;
+use crate::ast::value::CollPrim;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Append {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        let col_2 = self.col_2.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        Ok(Value::Coll(match (input, col_2) {
+            // fast path for Coll[Byte], keeps the bytes unboxed
+            (
+                Coll::Primitive(CollPrim::CollByte(mut bytes)),
+                Coll::Primitive(CollPrim::CollByte(bytes_2)),
+            ) => {
+                bytes.extend(bytes_2);
+                Coll::Primitive(CollPrim::CollByte(bytes))
+            }
+            (input, col_2) => append_general(input, col_2)?,
+        }))
+    }
+}
+
+fn append_general(input: Coll, col_2: Coll) -> Result<Coll, EvalError> {
+    if input.elem_tpe() != col_2.elem_tpe() {
+        return Err(TryExtractFromError(format!(
+            "cannot append Coll[{:?}] to Coll[{:?}]",
+            col_2.elem_tpe(),
+            input.elem_tpe()
+        ))
+        .into());
+    }
+    let elem_tpe = input.elem_tpe().clone();
+    let v = (0..input.len())
+        .filter_map(|i| input.get(i))
+        .chain((0..col_2.len()).filter_map(|i| col_2.get(i)))
+        .collect();
+    Ok(Coll::NonPrimitive { elem_tpe, v })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn append(input: Constant, col_2: Constant) -> Expr {
+        Append {
+            input: Box::new(input.into()),
+            col_2: Box::new(col_2.into()),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_bytes_fast_path() {
+        let expr = append(vec![1i8, 2].into(), vec![3i8].into());
+        let res = eval_out::<Coll>(&expr, Rc::new(force_any_val::<Context>()));
+        let general = append_general(
+            Constant::from(vec![1i8, 2])
+                .v
+                .try_extract_into::<Coll>()
+                .unwrap(),
+            Constant::from(vec![3i8])
+                .v
+                .try_extract_into::<Coll>()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(res, Coll::Primitive(CollPrim::CollByte(_))));
+        assert_eq!(res.len(), general.len());
+        assert_eq!(res.elem_tpe(), general.elem_tpe());
+        (0..res.len()).for_each(|i| assert_eq!(res.get(i), general.get(i)));
+        assert_eq!(
+            eval_out::<Vec<i8>>(&expr, Rc::new(force_any_val::<Context>())),
+            vec![1i8, 2, 3]
+        );
+    }
+
+    #[test]
+    fn eval_longs() {
+        let expr = append(vec![1i64, 2].into(), vec![3i64].into());
+        assert_eq!(
+            eval_out::<Vec<i64>>(&expr, Rc::new(force_any_val::<Context>())),
+            vec![1i64, 2, 3]
+        );
+    }
+
+    #[test]
+    fn eval_elem_type_mismatch() {
+        let expr = append(vec![1i64].into(), vec![1i32].into());
+        assert!(try_eval_out::<Vec<i64>>(&expr, Rc::new(force_any_val::<Context>())).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use std::convert::TryFrom;
+
+use crate::ast::coll_by_index::ByIndex;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+use crate::types::scontext;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ByIndex {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let coll = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        let index = self.index.eval(env, ectx)?.try_extract_into::<i32>()?;
+        match usize::try_from(index).ok().and_then(|idx| coll.get(idx)) {
+            Some(v) => Ok(v),
+            None => match &self.default {
+                Some(default) => default.eval(env, ectx),
+                None if self.is_data_input_access() => Err(EvalError::ContextFieldMissing(
+                    format!("dataInputs({})", index),
+                )),
+                None => Err(EvalError::IndexOutOfBounds {
+                    index,
+                    len: coll.len(),
+                }),
+            },
+        }
+    }
+}
+
+impl ByIndex {
+    /// `CONTEXT.dataInputs(i)`, a data inputs collection bound to a val is not recognized
+    /// and is reported as `IndexOutOfBounds`
+    fn is_data_input_access(&self) -> bool {
+        match self.input.as_ref() {
+            Expr::ProperyCall(pc) => {
+                *pc.obj == Expr::Context && pc.method == *scontext::DATA_INPUTS_PROPERTY
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::block::BlockValue;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::property_call::PropertyCall;
+    use crate::ast::val_def::ValDef;
+    use crate::ast::val_use::ValUse;
+    use crate::chain::ergo_box::ErgoBox;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+    use crate::types::scontext;
+
+    use super::*;
+
+    fn by_index(coll: Vec<i64>, index: i32, default: Option<i64>) -> Expr {
+        ByIndex {
+            input: Box::new(Expr::Const(coll.into())),
+            index: Box::new(Expr::Const(index.into())),
+            default: default.map(|d| Box::new(Expr::Const(Constant::from(d)))),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_in_bounds() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i64>(&by_index(vec![1, 2, 3], 1, None), ctx), 2);
+    }
+
+    #[test]
+    fn eval_coll_byte() {
+        let expr: Expr = ByIndex {
+            input: Box::new(Expr::Const(vec![1i8, 2, 3].into())),
+            index: Box::new(Expr::Const(2i32.into())),
+            default: None,
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i8>(&expr, ctx), 3);
+    }
+
+    #[test]
+    fn eval_negative_index() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            try_eval_out::<i64>(&by_index(vec![1, 2, 3], -1, None), ctx),
+            Err(EvalError::IndexOutOfBounds { index: -1, len: 3 })
+        );
+    }
+
+    #[test]
+    fn eval_index_equal_to_length() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            try_eval_out::<i64>(&by_index(vec![1, 2, 3], 3, None), ctx),
+            Err(EvalError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn eval_out_of_bounds_with_default() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i64>(&by_index(vec![1, 2, 3], 3, Some(42)), ctx.clone()),
+            42
+        );
+        assert_eq!(
+            eval_out::<i64>(&by_index(vec![1, 2, 3], -1, Some(42)), ctx),
+            42
+        );
+    }
+
+    #[test]
+    fn eval_missing_data_input() {
+        // CONTEXT.dataInputs(1)
+        let expr: Expr = ByIndex {
+            input: Box::new(
+                PropertyCall {
+                    obj: Box::new(Expr::Context),
+                    method: scontext::DATA_INPUTS_PROPERTY.clone(),
+                }
+                .into(),
+            ),
+            index: Box::new(Expr::Const(1i32.into())),
+            default: None,
+        }
+        .into();
+        let ctx = Context {
+            data_inputs: vec![force_any_val::<ErgoBox>()],
+            ..Context::dummy()
+        };
+        assert_eq!(
+            try_eval_out::<ErgoBox>(&expr, Rc::new(ctx)),
+            Err(EvalError::ContextFieldMissing("dataInputs(1)".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_data_input_via_val_out_of_bounds() {
+        // { val d = CONTEXT.dataInputs; d(1) }
+        let data_inputs: Expr = PropertyCall {
+            obj: Box::new(Expr::Context),
+            method: scontext::DATA_INPUTS_PROPERTY.clone(),
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: 1,
+                rhs: Box::new(data_inputs.clone()),
+            }
+            .into()],
+            result: Box::new(
+                ByIndex {
+                    input: Box::new(
+                        ValUse {
+                            val_id: 1,
+                            tpe: data_inputs.tpe(),
+                        }
+                        .into(),
+                    ),
+                    index: Box::new(Expr::Const(1i32.into())),
+                    default: None,
+                }
+                .into(),
+            ),
+        }
+        .into();
+        let ctx = Context {
+            data_inputs: vec![force_any_val::<ErgoBox>()],
+            ..Context::dummy()
+        };
+        assert_eq!(
+            try_eval_out::<ErgoBox>(&expr, Rc::new(ctx)),
+            Err(EvalError::IndexOutOfBounds { index: 1, len: 1 })
+        );
+    }
+
+    #[test]
+    fn eval_inputs_out_of_bounds() {
+        // INPUTS(1), with a single input
+        let expr: Expr = ByIndex {
+            input: Box::new(GlobalVars::Inputs.into()),
+            index: Box::new(Expr::Const(1i32.into())),
+            default: None,
+        }
+        .into();
+        let ctx = Context {
+            data_inputs: vec![force_any_val::<ErgoBox>()],
+            ..Context::dummy()
+        };
+        assert_eq!(
+            try_eval_out::<ErgoBox>(&expr, Rc::new(ctx)),
+            Err(EvalError::IndexOutOfBounds { index: 1, len: 1 })
+        );
+    }
+
+    #[test]
+    fn eval_empty_box_coll_without_data_inputs() {
+        // Coll[Box]()(0), with no data inputs
+        let expr: Expr = ByIndex {
+            input: Box::new(Expr::Const(Vec::<ErgoBox>::new().into())),
+            index: Box::new(Expr::Const(0i32.into())),
+            default: None,
+        }
+        .into();
+        let ctx = Context {
+            data_inputs: vec![],
+            ..Context::dummy()
+        };
+        assert_eq!(
+            try_eval_out::<ErgoBox>(&expr, Rc::new(ctx)),
+            Err(EvalError::IndexOutOfBounds { index: 0, len: 0 })
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::ast::coll_forall::ForAll;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ForAll {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        let condition = match self.condition.as_ref() {
+            Expr::FuncValue(f) => f,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        // stop on the first element the condition does not hold for
+        for item in (0..input.len()).filter_map(|i| input.get(i)) {
+            if !condition
+                .apply(env, ectx, vec![item])?
+                .try_extract_into::<bool>()?
+            {
+                return Ok(false.into());
+            }
+        }
+        Ok(true.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::coll_by_index::ByIndex;
+    use crate::ast::constant::Constant;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::val_use::ValUse;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn forall(input: Vec<i32>, body: Expr) -> Expr {
+        ForAll {
+            input: Box::new(Constant::from(input).into()),
+            condition: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SInt,
+                    }],
+                    body: Box::new(body),
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    fn arg() -> Expr {
+        ValUse {
+            val_id: 1,
+            tpe: SType::SInt,
+        }
+        .into()
+    }
+
+    // Coll(true, false, true)(x)
+    fn flag_at_arg() -> Expr {
+        ByIndex {
+            input: Box::new(Constant::from(vec![true, false, true]).into()),
+            index: Box::new(arg()),
+            default: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval() {
+        let neq_2 = Expr::BinOp(
+            BinOp::Relation(RelationOp::NEq),
+            Box::new(arg()),
+            Box::new(Constant::from(2i32).into()),
+        );
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(eval_out::<bool>(
+            &forall(vec![1, 3], neq_2.clone()),
+            ctx.clone()
+        ));
+        assert!(!eval_out::<bool>(
+            &forall(vec![1, 2, 3], neq_2.clone()),
+            ctx.clone()
+        ));
+        assert!(eval_out::<bool>(&forall(vec![], neq_2), ctx));
+    }
+
+    #[test]
+    fn eval_short_circuit() {
+        // the condition fails on the out of bounds index 5, but is not evaluated for it
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(!eval_out::<bool>(&forall(vec![1, 5], flag_at_arg()), ctx));
+    }
+
+    #[test]
+    fn eval_condition_error() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            try_eval_out::<bool>(&forall(vec![0, 5, 1], flag_at_arg()), ctx),
+            Err(EvalError::IndexOutOfBounds { index: 5, len: 3 })
+        );
+    }
+}
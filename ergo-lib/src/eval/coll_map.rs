@@ -0,0 +1,100 @@
+use crate::ast::coll_map::Map;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::value::Coll;
+use crate::ast::value::CollPrim;
+use crate::ast::value::Value;
+use crate::types::stype::SType;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Map {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        let mapper = match self.mapper.as_ref() {
+            Expr::FuncValue(f) => f,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        // element type of the result is the range of the mapper's function type
+        let elem_tpe = match mapper.tpe() {
+            SType::SFunc(f) => f.t_range,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        let v = (0..input.len())
+            .filter_map(|i| input.get(i))
+            .map(|item| mapper.apply(env, ectx, vec![item]))
+            .collect::<Result<Vec<Value>, EvalError>>()?;
+        Ok(Value::Coll(match elem_tpe {
+            // keep Coll[Byte] unboxed
+            SType::SByte => Coll::Primitive(CollPrim::CollByte(
+                v.into_iter()
+                    .map(|b| b.try_extract_into::<i8>())
+                    .collect::<Result<Vec<i8>, _>>()?,
+            )),
+            elem_tpe => Coll::NonPrimitive { elem_tpe, v },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::ops::{BinOp, NumOp};
+    use crate::ast::val_use::ValUse;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    // input.map { (x: tpe) => x + one }
+    fn map_plus_one(input: Constant, tpe: SType, one: Constant) -> Expr {
+        let arg: Expr = ValUse {
+            val_id: 1,
+            tpe: tpe.clone(),
+        }
+        .into();
+        Map {
+            input: Box::new(input.into()),
+            mapper: Box::new(
+                FuncValue {
+                    args: vec![FuncArg { idx: 1, tpe }],
+                    body: Box::new(Expr::BinOp(
+                        BinOp::Num(NumOp::Add),
+                        Box::new(arg),
+                        Box::new(one.into()),
+                    )),
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_byte_to_byte() {
+        let expr = map_plus_one(vec![1i8, 2, 3].into(), SType::SByte, 1i8.into());
+        let res = eval_out::<Coll>(&expr, Rc::new(force_any_val::<Context>()));
+        assert_eq!(res, Coll::Primitive(CollPrim::CollByte(vec![2, 3, 4])));
+    }
+
+    #[test]
+    fn eval_long_to_long() {
+        let expr = map_plus_one(vec![1i64, 2].into(), SType::SLong, 1i64.into());
+        let res = eval_out::<Coll>(&expr, Rc::new(force_any_val::<Context>()));
+        assert_eq!(
+            res,
+            Coll::NonPrimitive {
+                elem_tpe: SType::SLong,
+                v: vec![Value::Long(2), Value::Long(3)]
+            }
+        );
+    }
+}
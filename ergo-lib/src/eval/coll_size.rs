@@ -0,0 +1,53 @@
+use crate::ast::coll_size::SizeOf;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for SizeOf {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        Ok((input.len() as i32).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_outputs_size() {
+        let expr: Expr = SizeOf {
+            input: Box::new(GlobalVars::Outputs.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i32>(&expr, ctx.clone()),
+            ctx.outputs.len() as i32
+        );
+    }
+
+    #[test]
+    fn eval_bytes_size() {
+        let expr: Expr = SizeOf {
+            input: Box::new(Constant::from(vec![1u8, 2, 3]).into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i32>(&expr, ctx), 3);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::ast::coll_slice::Slice;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Slice {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?.try_extract_into::<Coll>()?;
+        let from = self.from.eval(env, ectx)?.try_extract_into::<i32>()?;
+        let until = self.until.eval(env, ectx)?.try_extract_into::<i32>()?;
+        // clamp the bounds the same way as Scala's `slice` does
+        Ok(Value::Coll(
+            input.slice(from.max(0) as usize, until.max(0) as usize),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn slice(coll: Constant, from: i32, until: i32) -> Expr {
+        Slice {
+            input: Box::new(coll.into()),
+            from: Box::new(Constant::from(from).into()),
+            until: Box::new(Constant::from(until).into()),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_clamped_bounds() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let coll = vec![1i32, 2, 3, 4];
+        let eval = |from: i32, until: i32| {
+            eval_out::<Vec<i32>>(&slice(coll.clone().into(), from, until), ctx.clone())
+        };
+        assert_eq!(eval(1, 3), vec![2, 3]);
+        assert_eq!(eval(-5, 2), vec![1, 2]);
+        assert_eq!(eval(2, 100), vec![3, 4]);
+        assert_eq!(eval(3, 1), Vec::<i32>::new());
+        assert_eq!(eval(10, 20), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn eval_bytes() {
+        let expr = slice(vec![1i8, 2, 3].into(), 1, 2);
+        assert_eq!(
+            eval_out::<Vec<i8>>(&expr, Rc::new(force_any_val::<Context>())),
+            vec![2i8]
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_vec_i32(coll in any::<Vec<i32>>(), from in -5..105i32, until in -5..105i32) {
+            // reference semantics: take the elements whose index is in [from, until)
+            let expected: Vec<i32> = coll
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (*i as i32) >= from && (*i as i32) < until)
+                .map(|(_, v)| *v)
+                .collect();
+            let expr = slice(coll.into(), from, until);
+            prop_assert_eq!(
+                eval_out::<Vec<i32>>(&expr, Rc::new(force_any_val::<Context>())),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::ast::collection::Collection;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+use crate::types::stype::SType;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Collection {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        Ok(match self {
+            Collection::BoolConstants(bools) => bools.clone().into(),
+            Collection::Exprs { elem_tpe, items } => {
+                let values = items
+                    .iter()
+                    .map(|i| i.eval(env, ectx))
+                    .collect::<Result<Vec<Value>, EvalError>>()?;
+                match elem_tpe {
+                    SType::SByte => values
+                        .into_iter()
+                        .map(|v| v.try_extract_into::<i8>())
+                        .collect::<Result<Vec<i8>, _>>()?
+                        .into(),
+                    _ => Value::Coll(Coll::NonPrimitive {
+                        elem_tpe: elem_tpe.clone(),
+                        v: values,
+                    }),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_exprs() {
+        let expr: Expr = Collection::Exprs {
+            elem_tpe: SType::SLong,
+            items: vec![Constant::from(1i64).into(), Constant::from(2i64).into()],
+        }
+        .into();
+        assert_eq!(
+            eval_out::<Vec<i64>>(&expr, Rc::new(force_any_val::<Context>())),
+            vec![1i64, 2]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::chain::ergo_box::ErgoBox;
 use crate::chain::ergo_state_context::ErgoStateContext;
+use crate::sigma_protocol::prover::ContextExtension;
 use crate::wallet::signing::TransactionContext;
 use thiserror::Error;
 
@@ -7,8 +8,12 @@ use thiserror::Error;
 pub struct Context {
     pub height: i32,
     pub self_box: ErgoBox,
+    /// Boxes spent by the transaction (shared by all the inputs)
+    pub inputs: Vec<ErgoBox>,
     pub outputs: Vec<ErgoBox>,
     pub data_inputs: Vec<ErgoBox>,
+    /// Context variables of the spending input (read via `getVar`)
+    pub extension: ContextExtension,
 }
 
 impl Context {
@@ -16,11 +21,14 @@ impl Context {
     #[cfg(test)]
     pub fn dummy() -> Self {
         use crate::test_util::force_any_val;
+        let self_box = force_any_val::<ErgoBox>();
         Context {
             height: 0,
-            self_box: force_any_val::<ErgoBox>(),
+            self_box: self_box.clone(),
+            inputs: vec![self_box],
             outputs: vec![force_any_val::<ErgoBox>()],
             data_inputs: vec![],
+            extension: ContextExtension::empty(),
         }
     }
 
@@ -37,6 +45,7 @@ impl Context {
             .get(self_index)
             .cloned()
             .ok_or(ContextError::SelfIndexOutOfBounds)?;
+        let inputs = tx_ctx.boxes_to_spend.clone();
         let outputs: Vec<ErgoBox> = tx_ctx
             .spending_tx
             .output_candidates
@@ -45,11 +54,19 @@ impl Context {
             .map(|(idx, b)| ErgoBox::from_box_candidate(b, tx_ctx.spending_tx.id(), idx as u16))
             .collect();
         let data_inputs: Vec<ErgoBox> = tx_ctx.data_boxes.clone();
+        let extension = tx_ctx
+            .spending_tx
+            .inputs
+            .get(self_index)
+            .map(|input| input.extension.clone())
+            .ok_or(ContextError::SelfIndexOutOfBounds)?;
         Ok(Context {
             height,
             self_box,
+            inputs,
             outputs,
             data_inputs,
+            extension,
         })
     }
 }
@@ -64,6 +81,16 @@ pub enum ContextError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::coll_by_index::ByIndex;
+    use crate::ast::expr::Expr;
+    use crate::ast::extract_amount::ExtractAmount;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::chain::ergo_box::{BoxValue, ErgoBoxCandidate};
+    use crate::chain::transaction::{unsigned::UnsignedTransaction, UnsignedInput};
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use std::rc::Rc;
 
     use proptest::collection::vec;
     use proptest::prelude::*;
@@ -74,19 +101,98 @@ mod tests {
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
             (
                 0..i32::MAX,
-                any::<ErgoBox>(),
+                vec(any::<ErgoBox>(), 1..3),
                 vec(any::<ErgoBox>(), 0..3),
                 vec(any::<ErgoBox>(), 0..3),
+                any::<ContextExtension>(),
             )
-                .prop_map(|(height, self_box, outputs, data_inputs)| Self {
+                .prop_map(|(height, inputs, outputs, data_inputs, extension)| Self {
                     height,
-                    self_box,
+                    self_box: inputs[0].clone(),
+                    inputs,
                     outputs,
                     data_inputs,
+                    extension,
                 })
                 .boxed()
         }
 
         type Strategy = BoxedStrategy<Self>;
     }
+
+    fn self_value_eq_input_value(input_index: i32) -> Expr {
+        // SELF.value == INPUTS(input_index).value
+        Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(
+                ExtractAmount {
+                    input: Box::new(GlobalVars::SelfBox.into()),
+                }
+                .into(),
+            ),
+            Box::new(
+                ExtractAmount {
+                    input: Box::new(
+                        ByIndex {
+                            input: Box::new(GlobalVars::Inputs.into()),
+                            index: Box::new(Expr::Const(input_index.into())),
+                            default: None,
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        )
+    }
+
+    #[test]
+    fn self_box_per_input_index() {
+        let boxes_to_spend: Vec<ErgoBox> = [BoxValue::SAFE_USER_MIN, BoxValue::MIN]
+            .iter()
+            .map(|value| {
+                let b = force_any_val::<ErgoBox>();
+                ErgoBox::new(
+                    *value,
+                    b.ergo_tree,
+                    b.tokens,
+                    b.additional_registers,
+                    b.creation_height,
+                    b.transaction_id,
+                    b.index,
+                )
+            })
+            .collect();
+        let inputs = boxes_to_spend
+            .iter()
+            .cloned()
+            .map(UnsignedInput::from)
+            .collect();
+        let tx_ctx = TransactionContext {
+            spending_tx: UnsignedTransaction::new(
+                inputs,
+                vec![],
+                vec![force_any_val::<ErgoBoxCandidate>()],
+            ),
+            boxes_to_spend: boxes_to_spend.clone(),
+            data_boxes: vec![],
+        };
+        let state_ctx = ErgoStateContext::dummy();
+        let ctx0 = Rc::new(Context::new(&state_ctx, &tx_ctx, 0).unwrap());
+        let ctx1 = Rc::new(Context::new(&state_ctx, &tx_ctx, 1).unwrap());
+        assert_eq!(ctx0.self_box, boxes_to_spend[0]);
+        assert_eq!(ctx1.self_box, boxes_to_spend[1]);
+        assert_eq!(ctx0.inputs, ctx1.inputs);
+        assert_eq!(ctx0.outputs, ctx1.outputs);
+        assert!(eval_out::<bool>(
+            &self_value_eq_input_value(0),
+            ctx0.clone()
+        ));
+        assert!(eval_out::<bool>(
+            &self_value_eq_input_value(1),
+            ctx1.clone()
+        ));
+        assert!(!eval_out::<bool>(&self_value_eq_input_value(1), ctx0));
+        assert!(!eval_out::<bool>(&self_value_eq_input_value(0), ctx1));
+    }
 }
@@ -0,0 +1,45 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::create_provedlog::CreateProveDlog;
+use crate::ast::value::Value;
+use crate::sigma_protocol::dlog_group::EcPoint;
+use crate::sigma_protocol::sigma_boolean::ProveDlog;
+use crate::sigma_protocol::sigma_boolean::SigmaProp;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for CreateProveDlog {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let point = self.input.eval(env, ectx)?.try_extract_into::<EcPoint>()?;
+        Ok(SigmaProp::from(ProveDlog::new(point)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval() {
+        let pk = force_any_val::<EcPoint>();
+        let expr: Expr = CreateProveDlog {
+            input: Box::new(Constant::from(pk.clone()).into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<SigmaProp>(&expr, ctx),
+            SigmaProp::from(ProveDlog::new(pk))
+        );
+    }
+}
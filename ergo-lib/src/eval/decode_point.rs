@@ -0,0 +1,82 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::decode_point::DecodePoint;
+use crate::ast::value::Value;
+use crate::serialization::SerializationError;
+use crate::serialization::SigmaSerializable;
+use crate::sigma_protocol::dlog_group::EcPoint;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for DecodePoint {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bytes = self.input.eval(env, ectx)?.try_extract_into::<Vec<u8>>()?;
+        if bytes.len() != EcPoint::GROUP_SIZE {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "expected {} bytes of encoded group element, got {}",
+                EcPoint::GROUP_SIZE,
+                bytes.len()
+            ))
+            .into());
+        }
+        Ok(EcPoint::sigma_parse_bytes(bytes)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::sigma_protocol::dlog_group;
+    use crate::test_util::force_any_val;
+    use k256::Scalar;
+
+    use super::*;
+
+    fn decode_point(bytes: Vec<u8>) -> Expr {
+        DecodePoint {
+            input: Box::new(Constant::from(bytes).into()),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_generator_multiples() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        for i in 1..5u32 {
+            let p = dlog_group::exponentiate(&dlog_group::generator(), &Scalar::from(i));
+            let expr = decode_point(p.sigma_serialize_bytes());
+            assert_eq!(eval_out::<EcPoint>(&expr, ctx.clone()), p);
+        }
+    }
+
+    #[test]
+    fn eval_identity() {
+        let expr = decode_point(vec![0u8; EcPoint::GROUP_SIZE]);
+        assert_eq!(
+            eval_out::<EcPoint>(&expr, Rc::new(force_any_val::<Context>())),
+            dlog_group::identity()
+        );
+    }
+
+    #[test]
+    fn eval_malformed() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let mut bytes = dlog_group::generator().sigma_serialize_bytes();
+        // truncated
+        assert!(try_eval_out::<EcPoint>(&decode_point(bytes[..10].to_vec()), ctx.clone()).is_err());
+        // invalid prefix
+        bytes[0] = 5;
+        assert!(try_eval_out::<EcPoint>(&decode_point(bytes), ctx.clone()).is_err());
+        // not on the curve
+        let mut not_on_curve = vec![2u8];
+        not_on_curve.extend_from_slice(&[0xffu8; 32]);
+        assert!(try_eval_out::<EcPoint>(&decode_point(not_on_curve), ctx).is_err());
+    }
+}
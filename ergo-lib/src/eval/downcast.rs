@@ -0,0 +1,85 @@
+use crate::ast::downcast::Downcast;
+use crate::ast::value::Value;
+
+use super::upcast::cast_numeric;
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Downcast {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        cast_numeric(self.input.eval(env, ectx)?, &self.tpe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::big_integer::BigInteger;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn downcast(input: Expr, tpe: SType) -> Expr {
+        Downcast::new(input, tpe).unwrap().into()
+    }
+
+    #[test]
+    fn eval_long_to_int() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i32>(
+                &downcast(Expr::Const((i32::MIN as i64).into()), SType::SInt),
+                ctx.clone()
+            ),
+            i32::MIN
+        );
+        assert!(matches!(
+            try_eval_out::<i32>(
+                &downcast(Expr::Const((i32::MAX as i64 + 1).into()), SType::SInt),
+                ctx
+            ),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_big_int_to_long() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        let max = BigInteger::from(i64::MAX);
+        assert_eq!(
+            eval_out::<i64>(
+                &downcast(Expr::Const(max.clone().into()), SType::SLong),
+                ctx.clone()
+            ),
+            i64::MAX
+        );
+        let too_big = max.checked_add(&BigInteger::from(1)).unwrap();
+        assert!(matches!(
+            try_eval_out::<i64>(&downcast(Expr::Const(too_big.into()), SType::SLong), ctx),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_short_to_byte() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i8>(
+                &downcast(Expr::Const(127i16.into()), SType::SByte),
+                ctx.clone()
+            ),
+            127
+        );
+        assert!(matches!(
+            try_eval_out::<i8>(&downcast(Expr::Const((-129i16).into()), SType::SByte), ctx),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::exponentiate::Exponentiate;
+use crate::ast::value::Value;
+use crate::big_integer::BigInteger;
+use crate::sigma_protocol::dlog_group;
+use crate::sigma_protocol::dlog_group::EcPoint;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Exponentiate {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let base = self.left.eval(env, ectx)?.try_extract_into::<EcPoint>()?;
+        let exponent = self
+            .right
+            .eval(env, ectx)?
+            .try_extract_into::<BigInteger>()?;
+        Ok(dlog_group::exponentiate(&base, &dlog_group::bigint_to_scalar(&exponent)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn exp(base: Expr, exponent: i64) -> Expr {
+        Exponentiate {
+            left: Box::new(base),
+            right: Box::new(Constant::from(BigInteger::from(exponent)).into()),
+        }
+        .into()
+    }
+
+    fn eval_exp(base: &EcPoint, exponent: i64) -> EcPoint {
+        let ctx = Rc::new(force_any_val::<Context>());
+        eval_out::<EcPoint>(&exp(Constant::from(base.clone()).into(), exponent), ctx)
+    }
+
+    #[test]
+    fn eval_zero_one_and_negative() {
+        let g = force_any_val::<EcPoint>();
+        assert_eq!(eval_exp(&g, 0), dlog_group::identity());
+        assert_eq!(eval_exp(&g, 1), g);
+        assert_eq!(eval_exp(&g, -1), dlog_group::inverse(&g));
+        assert_eq!(eval_exp(&dlog_group::identity(), 5), dlog_group::identity());
+    }
+
+    proptest! {
+
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn eval_exp_of_exp(g in any::<EcPoint>(), a in any::<i32>(), b in any::<i32>()) {
+            let ctx = Rc::new(force_any_val::<Context>());
+            let expr = exp(exp(Constant::from(g.clone()).into(), a as i64), b as i64);
+            prop_assert_eq!(
+                eval_out::<EcPoint>(&expr, ctx),
+                eval_exp(&g, a as i64 * b as i64)
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::ast::expr::Expr;
 use crate::ast::value::Value;
 
+use super::bin_op::eval_bin_op;
 use super::Env;
 use super::EvalContext;
 use super::EvalError;
@@ -9,27 +10,66 @@ use super::Evaluable;
 impl Evaluable for Expr {
     fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
         ectx.cost_accum.add_cost_of(self)?;
-        match self {
+        let res = match self {
             Expr::Const(c) => Ok(c.v.clone()),
-            Expr::PredefFunc(_) => todo!(),
+            Expr::CalcSha256(v) => v.eval(env, ectx),
+            Expr::DecodePoint(v) => v.eval(env, ectx),
+            Expr::Upcast(v) => v.eval(env, ectx),
             Expr::CollM(_) => todo!(),
-            Expr::BoxM(_) => todo!(),
+            Expr::Collection(v) => v.eval(env, ectx),
+            Expr::Append(v) => v.eval(env, ectx),
+            Expr::Exists(v) => v.eval(env, ectx),
+            Expr::ForAll(v) => v.eval(env, ectx),
+            Expr::ValUse(v) => v.eval(env, ectx),
+            Expr::Slice(v) => v.eval(env, ectx),
+            Expr::Map(v) => v.eval(env, ectx),
+            Expr::BoxM(v) => v.eval(env, ectx),
+            Expr::ByteArrayToBigInt(v) => v.eval(env, ectx),
             Expr::GlobalVars(v) => v.eval(env, ectx),
             Expr::MethodCall(v) => v.eval(env, ectx),
             Expr::ProperyCall(v) => v.eval(env, ectx),
-            Expr::BinOp(_bin_op, _l, _r) => {
-                todo!()
-                // let _v_l = eval(l, env, ca, ctx)?;
-                // let _v_r = eval(r, env, ca, ctx)?;
-                // ca.add_cost_of(expr);
-                // Ok(match bin_op {
-                //     BinOp::Num(op) => match op {
-                //         NumOp::Add => v_l + v_r,
-                //     },
-                // })
-            }
+            Expr::BinOp(op, l, r) => eval_bin_op(op, l, r, env, ectx),
+            Expr::Exponentiate(v) => v.eval(env, ectx),
+            Expr::MultiplyGroup(v) => v.eval(env, ectx),
             Expr::Context => Ok(Value::Context(ectx.ctx.clone())),
+            Expr::FuncValue(v) => Ok(Value::Lambda(v.clone())),
+            Expr::ByIndex(v) => v.eval(env, ectx),
+            Expr::SelectField(v) => v.eval(env, ectx),
+            Expr::ExtractAmount(v) => v.eval(env, ectx),
+            Expr::ExtractCreationInfo(v) => v.eval(env, ectx),
+            Expr::ExtractScriptBytes(v) => v.eval(env, ectx),
+            Expr::GetVar(v) => v.eval(env, ectx),
+            Expr::OptionGet(v) => v.eval(env, ectx),
+            Expr::OptionGetOrElse(v) => v.eval(env, ectx),
+            Expr::OptionIsDefined(v) => v.eval(env, ectx),
+            Expr::OptionMap(v) => v.eval(env, ectx),
+            Expr::OptionFilter(v) => v.eval(env, ectx),
+            Expr::And(v) => v.eval(env, ectx),
+            Expr::Or(v) => v.eval(env, ectx),
+            Expr::SigmaAnd(v) => v.eval(env, ectx),
+            Expr::Atleast(v) => v.eval(env, ectx),
+            Expr::SigmaOr(v) => v.eval(env, ectx),
+            Expr::BoolToSigmaProp(v) => v.eval(env, ectx),
+            Expr::CreateProveDlog(v) => v.eval(env, ectx),
+            Expr::SizeOf(v) => v.eval(env, ectx),
+            Expr::If(v) => v.eval(env, ectx),
+            Expr::BlockValue(v) => v.eval(env, ectx),
+            Expr::ExtractId(v) => v.eval(env, ectx),
+            Expr::CalcBlake2b256(v) => v.eval(env, ectx),
+            Expr::Negation(v) => v.eval(env, ectx),
+            Expr::Xor(v) => v.eval(env, ectx),
+            Expr::ExtractBytes(v) => v.eval(env, ectx),
+            Expr::ExtractBytesWithNoRef(v) => v.eval(env, ectx),
+            Expr::Tuple(v) => v.eval(env, ectx),
+            Expr::LogicalNot(v) => v.eval(env, ectx),
+            Expr::SubstConstants(v) => v.eval(env, ectx),
+            Expr::Downcast(v) => v.eval(env, ectx),
+            Expr::XorOf(v) => v.eval(env, ectx),
             _ => Err(EvalError::UnexpectedExpr),
+        };
+        if let Some(tracer) = ectx.tracer.as_mut() {
+            tracer(self, &res);
         }
+        res
     }
 }
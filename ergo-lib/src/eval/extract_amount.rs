@@ -0,0 +1,55 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::extract_amount::ExtractAmount;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ExtractAmount {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let b = self.input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+        // BoxValue upper bound is i64::MAX, so the conversion is lossless
+        Ok(Value::Long(b.value.as_i64()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::chain::ergo_box::BoxValue;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn self_box_value() -> Expr {
+        ExtractAmount {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_self_box_value() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<i64>(&self_box_value(), ctx.clone()),
+            ctx.self_box.value.as_i64()
+        );
+    }
+
+    #[test]
+    fn eval_self_box_max_value() {
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box.value = BoxValue::try_from(BoxValue::MAX_RAW).unwrap();
+        assert_eq!(eval_out::<i64>(&self_box_value(), Rc::new(ctx)), i64::MAX);
+    }
+}
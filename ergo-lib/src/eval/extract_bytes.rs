@@ -0,0 +1,42 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::extract_bytes::ExtractBytes;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+use crate::serialization::SigmaSerializable;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ExtractBytes {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let b = self.input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+        Ok(b.sigma_serialize_bytes().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_self_box_bytes() {
+        let expr: Expr = ExtractBytes {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let bytes = eval_out::<Vec<u8>>(&expr, ctx.clone());
+        assert_eq!(bytes, ctx.self_box.sigma_serialize_bytes());
+        assert_eq!(ErgoBox::sigma_parse_bytes(bytes).unwrap(), ctx.self_box);
+    }
+}
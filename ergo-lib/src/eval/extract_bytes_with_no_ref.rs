@@ -0,0 +1,47 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+use crate::chain::ergo_box::ErgoBoxCandidate;
+use crate::serialization::SigmaSerializable;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ExtractBytesWithNoRef {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let b = self.input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+        Ok(ErgoBoxCandidate::from(b).sigma_serialize_bytes().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use sigma_ser::vlq_encode::WriteSigmaVlqExt;
+
+    use super::*;
+
+    #[test]
+    fn eval_self_box_bytes_with_no_ref() {
+        let expr: Expr = ExtractBytesWithNoRef {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let bytes = eval_out::<Vec<u8>>(&expr, ctx.clone());
+        let box_bytes = ctx.self_box.sigma_serialize_bytes();
+        // box bytes are followed by the tx id and the output index (VLQ)
+        let mut tail = ctx.self_box.transaction_id.sigma_serialize_bytes();
+        tail.put_u16(ctx.self_box.index).unwrap();
+        assert_eq!([bytes, tail].concat(), box_bytes);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::extract_creation_info::ExtractCreationInfo;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ExtractCreationInfo {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let b = self.input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+        Ok(b.creation_info().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_self_box_creation_info() {
+        let expr: Expr = ExtractCreationInfo {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        let (height, ref_bytes) = eval_out::<(i32, Vec<u8>)>(&expr, ctx.clone());
+        assert_eq!(height, ctx.self_box.creation_height as i32);
+        // 32 bytes of tx id followed by 2 bytes of output index (big-endian), as in sigmastate
+        assert_eq!(ref_bytes.len(), 34);
+        assert_eq!(&ref_bytes[..32], ctx.self_box.transaction_id.0 .0.as_ref());
+        assert_eq!(&ref_bytes[32..], ctx.self_box.index.to_be_bytes().as_ref());
+    }
+}
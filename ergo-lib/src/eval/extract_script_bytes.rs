@@ -0,0 +1,43 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::extract_script_bytes::ExtractScriptBytes;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+use crate::serialization::SigmaSerializable;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ExtractScriptBytes {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let b = self.input.eval(env, ectx)?.try_extract_into::<ErgoBox>()?;
+        Ok(b.ergo_tree.sigma_serialize_bytes().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_self_box_script_bytes() {
+        let expr: Expr = ExtractScriptBytes {
+            input: Box::new(GlobalVars::SelfBox.into()),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<Vec<u8>>(&expr, ctx.clone()),
+            ctx.self_box.ergo_tree.sigma_serialize_bytes()
+        );
+    }
+}
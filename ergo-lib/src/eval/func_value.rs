@@ -0,0 +1,27 @@
+use crate::ast::func_value::FuncValue;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl FuncValue {
+    /// Evaluate the function body with the arguments bound to `arg_values`
+    pub(crate) fn apply(
+        &self,
+        env: &Env,
+        ectx: &mut EvalContext,
+        arg_values: Vec<Value>,
+    ) -> Result<Value, EvalError> {
+        if arg_values.len() != self.args.len() {
+            return Err(EvalError::UnexpectedExpr);
+        }
+        let env = self
+            .args
+            .iter()
+            .zip(arg_values)
+            .fold(env.clone(), |env, (arg, v)| env.extend(arg.idx, v));
+        self.body.eval(&env, ectx)
+    }
+}
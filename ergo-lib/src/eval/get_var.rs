@@ -0,0 +1,75 @@
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::get_var::GetVar;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for GetVar {
+    fn eval(&self, _env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        match ectx.ctx.extension.values.get(&self.var_id) {
+            None => Ok(Value::Opt(Box::new(None))),
+            Some(c) if c.tpe == self.var_tpe => Ok(Value::Opt(Box::new(Some(c.v.clone())))),
+            // not None, same as in sigmastate (InvalidType is thrown for a variable of another type)
+            Some(c) => Err(TryExtractFromError(format!(
+                "expected context variable {} of type {:?}, found {:?}",
+                self.var_id, self.var_tpe, c.tpe
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn ctx_with_var(var_id: u8, c: Constant) -> Rc<Context> {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.clear();
+        ctx.extension.values.insert(var_id, c);
+        Rc::new(ctx)
+    }
+
+    fn get_var(var_id: u8, var_tpe: SType) -> Expr {
+        GetVar { var_id, var_tpe }.into()
+    }
+
+    #[test]
+    fn eval_defined() {
+        let ctx = ctx_with_var(1, 5i64.into());
+        assert_eq!(
+            try_eval_out::<Option<i64>>(&get_var(1, SType::SLong), ctx),
+            Ok(Some(5))
+        );
+    }
+
+    #[test]
+    fn eval_undefined() {
+        let ctx = ctx_with_var(1, 5i64.into());
+        assert_eq!(
+            try_eval_out::<Option<i64>>(&get_var(2, SType::SLong), ctx),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn eval_type_mismatch() {
+        let ctx = ctx_with_var(1, 5i32.into());
+        assert!(matches!(
+            try_eval_out::<Option<i64>>(&get_var(1, SType::SLong), ctx),
+            Err(EvalError::TryExtractFrom(_))
+        ));
+    }
+}
@@ -11,8 +11,12 @@ impl Evaluable for GlobalVars {
         match self {
             GlobalVars::Height => Ok(ectx.ctx.height.clone().into()),
             GlobalVars::SelfBox => Ok(ectx.ctx.self_box.clone().into()),
+            GlobalVars::Inputs => Ok(ectx.ctx.inputs.clone().into()),
             GlobalVars::Outputs => Ok(ectx.ctx.outputs.clone().into()),
-            _ => Err(EvalError::UnexpectedExpr),
+            // block pre-header is not a part of the context yet
+            GlobalVars::MinerPubKey => {
+                Err(EvalError::ContextFieldMissing("minerPubKey".to_string()))
+            }
         }
     }
 }
@@ -21,10 +25,13 @@ impl Evaluable for GlobalVars {
 mod tests {
     use std::rc::Rc;
 
-    use crate::chain::ergo_box::ErgoBox;
+    use crate::chain::ergo_box::{ErgoBox, ErgoBoxCandidate};
+    use crate::chain::ergo_state_context::{ErgoStateContext, PreHeader};
+    use crate::chain::transaction::{unsigned::UnsignedTransaction, UnsignedInput};
     use crate::eval::context::Context;
-    use crate::eval::tests::eval_out;
+    use crate::eval::tests::{eval_out, try_eval_out};
     use crate::test_util::force_any_val;
+    use crate::wallet::signing::TransactionContext;
 
     use super::*;
 
@@ -37,6 +44,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_miner_pub_key_missing() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            try_eval_out::<Vec<u8>>(&GlobalVars::MinerPubKey.into(), ctx),
+            Err(EvalError::ContextFieldMissing("minerPubKey".to_string()))
+        );
+    }
+
     #[test]
     fn eval_self_box() {
         let ctx = Rc::new(force_any_val::<Context>());
@@ -46,6 +62,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_inputs() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<Vec<ErgoBox>>(&GlobalVars::Inputs.into(), ctx.clone()),
+            ctx.inputs
+        );
+    }
+
     #[test]
     fn eval_outputs() {
         let ctx = Rc::new(force_any_val::<Context>());
@@ -54,4 +79,42 @@ mod tests {
             ctx.outputs
         );
     }
+
+    #[test]
+    fn eval_from_tx_context() {
+        let boxes_to_spend = vec![force_any_val::<ErgoBox>(), force_any_val::<ErgoBox>()];
+        let spending_tx = UnsignedTransaction::new(
+            boxes_to_spend
+                .iter()
+                .cloned()
+                .map(UnsignedInput::from)
+                .collect(),
+            vec![],
+            vec![force_any_val::<ErgoBoxCandidate>()],
+        );
+        let tx_ctx = TransactionContext {
+            spending_tx: spending_tx.clone(),
+            boxes_to_spend: boxes_to_spend.clone(),
+            data_boxes: vec![],
+        };
+        let state_ctx = ErgoStateContext {
+            pre_header: PreHeader { height: 42 },
+        };
+        let ctx = Rc::new(Context::new(&state_ctx, &tx_ctx, 1).unwrap());
+        assert_eq!(eval_out::<i32>(&GlobalVars::Height.into(), ctx.clone()), 42);
+        assert_eq!(
+            eval_out::<ErgoBox>(&GlobalVars::SelfBox.into(), ctx.clone()),
+            boxes_to_spend[1]
+        );
+        assert_eq!(
+            eval_out::<Vec<ErgoBox>>(&GlobalVars::Inputs.into(), ctx.clone()),
+            boxes_to_spend
+        );
+        assert_eq!(
+            eval_out::<Vec<ErgoBox>>(&GlobalVars::Outputs.into(), ctx),
+            vec![spending_tx.output_candidates[0]
+                .clone()
+                .into_box(spending_tx.id(), 0)]
+        );
+    }
 }
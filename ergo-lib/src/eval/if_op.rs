@@ -0,0 +1,66 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::if_op::If;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for If {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let condition = self.condition.eval(env, ectx)?.try_extract_into::<bool>()?;
+        // only the taken branch is evaluated
+        if condition {
+            self.true_branch.eval(env, ectx)
+        } else {
+            self.false_branch.eval(env, ectx)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::ops::{BinOp, NumOp};
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_branches() {
+        for b in [true, false].iter() {
+            let expr: Expr = If {
+                condition: Box::new(Constant::from(*b).into()),
+                true_branch: Box::new(Constant::from(1i64).into()),
+                false_branch: Box::new(Constant::from(2i64).into()),
+            }
+            .into();
+            let ctx = Rc::new(force_any_val::<Context>());
+            assert_eq!(eval_out::<i64>(&expr, ctx), if *b { 1 } else { 2 });
+        }
+    }
+
+    #[test]
+    fn eval_only_taken_branch() {
+        // 1L / 0L fails if evaluated
+        let div_by_zero = Expr::BinOp(
+            BinOp::Num(NumOp::Divide),
+            Box::new(Constant::from(1i64).into()),
+            Box::new(Constant::from(0i64).into()),
+        );
+        let expr: Expr = If {
+            condition: Box::new(Constant::from(true).into()),
+            true_branch: Box::new(Constant::from(1i64).into()),
+            false_branch: Box::new(div_by_zero),
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i64>(&expr, ctx), 1);
+    }
+}
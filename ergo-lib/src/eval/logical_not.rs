@@ -0,0 +1,66 @@
+use crate::ast::logical_not::LogicalNot;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for LogicalNot {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(env, ectx)? {
+            Value::Boolean(b) => Ok(Value::Boolean(!b)),
+            v => Err(EvalError::UnexpectedValue(format!(
+                "LogicalNot: expected Boolean, found {:?}",
+                v
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn not(input: Expr) -> Expr {
+        LogicalNot {
+            input: Box::new(input),
+        }
+        .into()
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_double_negation(b in any::<bool>()) {
+            let ctx = Rc::new(force_any_val::<Context>());
+            prop_assert_eq!(
+                try_eval_out::<bool>(&not(Expr::Const(b.into())), ctx.clone()),
+                Ok(!b)
+            );
+            prop_assert_eq!(
+                try_eval_out::<bool>(&not(not(Expr::Const(b.into()))), ctx),
+                Ok(b)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_non_boolean() {
+        assert!(matches!(
+            try_eval_out::<bool>(
+                &not(Expr::Const(1i32.into())),
+                Rc::new(force_any_val::<Context>())
+            ),
+            Err(EvalError::UnexpectedValue(_))
+        ));
+    }
+}
@@ -11,7 +11,7 @@ impl Evaluable for MethodCall {
         let ov = (*self.obj).eval(env, ectx)?;
         let argsv: Result<Vec<Value>, EvalError> =
             self.args.iter().map(|arg| arg.eval(env, ectx)).collect();
-        self.method.eval_fn()(ov, argsv?)
+        self.method.eval_fn()(env, ectx, ov, argsv?)
     }
 }
 
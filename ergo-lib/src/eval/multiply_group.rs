@@ -0,0 +1,55 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::multiply_group::MultiplyGroup;
+use crate::ast::value::Value;
+use crate::sigma_protocol::dlog_group::EcPoint;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for MultiplyGroup {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(env, ectx)?.try_extract_into::<EcPoint>()?;
+        let right = self.right.eval(env, ectx)?.try_extract_into::<EcPoint>()?;
+        Ok((left * &right).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::sigma_protocol::dlog_group;
+    use crate::test_util::force_any_val;
+    use k256::Scalar;
+
+    use super::*;
+
+    fn multiply(left: &EcPoint, right: &EcPoint) -> EcPoint {
+        let expr: Expr = MultiplyGroup {
+            left: Box::new(Constant::from(left.clone()).into()),
+            right: Box::new(Constant::from(right.clone()).into()),
+        }
+        .into();
+        eval_out::<EcPoint>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval() {
+        let g = force_any_val::<EcPoint>();
+        assert_eq!(
+            multiply(&g, &g),
+            dlog_group::exponentiate(&g, &Scalar::from(2u32))
+        );
+        assert_eq!(multiply(&g, &dlog_group::identity()), g);
+        assert_eq!(
+            multiply(&g, &dlog_group::inverse(&g)),
+            dlog_group::identity()
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::ast::negation::Negation;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Negation {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        // no wrapping on overflow (negation of the minimal value), same as in sigmastate
+        let overflow = || EvalError::ArithmeticException("Negation overflow".to_string());
+        match self.input.eval(env, ectx)? {
+            Value::Byte(v) => v.checked_neg().map(Value::Byte).ok_or_else(overflow),
+            Value::Short(v) => v.checked_neg().map(Value::Short).ok_or_else(overflow),
+            Value::Int(v) => v.checked_neg().map(Value::Int).ok_or_else(overflow),
+            Value::Long(v) => v.checked_neg().map(Value::Long).ok_or_else(overflow),
+            Value::BigInt(v) => v.checked_neg().map(Value::BigInt).ok_or_else(overflow),
+            _ => Err(EvalError::UnexpectedExpr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::constant::TryExtractFrom;
+    use crate::ast::expr::Expr;
+    use crate::big_integer::BigInteger;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn neg<T: Into<Constant> + TryExtractFrom<Value>>(v: T) -> Result<T, EvalError> {
+        let expr: Expr = Negation {
+            input: Box::new(v.into().into()),
+        }
+        .into();
+        try_eval_out::<T>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_int(v in any::<i32>()) {
+            prop_assert_eq!(neg(v).ok(), v.checked_neg());
+        }
+
+        #[test]
+        fn eval_long(v in any::<i64>()) {
+            prop_assert_eq!(neg(v).ok(), v.checked_neg());
+        }
+    }
+
+    #[test]
+    fn eval_overflow() {
+        assert!(matches!(
+            neg(i8::MIN),
+            Err(EvalError::ArithmeticException(_))
+        ));
+    }
+
+    #[test]
+    fn eval_big_int() {
+        assert_eq!(neg(BigInteger::from(5i64)), Ok(BigInteger::from(-5i64)));
+    }
+
+    #[test]
+    fn eval_non_numeric() {
+        assert_eq!(neg(true), Err(EvalError::UnexpectedExpr));
+    }
+}
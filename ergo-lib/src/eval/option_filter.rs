@@ -0,0 +1,95 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::option_filter::OptionFilter;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for OptionFilter {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = match self.input.eval(env, ectx)? {
+            Value::Opt(opt) => *opt,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        let condition = match self.condition.as_ref() {
+            Expr::FuncValue(f) => f,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        let res = match input {
+            Some(v) => {
+                if condition
+                    .apply(env, ectx, vec![v.clone()])?
+                    .try_extract_into::<bool>()?
+                {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        Ok(Value::Opt(Box::new(res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::val_use::ValUse;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    // input.filter { (v: Int) => v == 1 }
+    fn filter_eq_one(input: Option<i32>) -> Option<i32> {
+        let expr: Expr = OptionFilter {
+            input: Box::new(
+                Constant {
+                    tpe: SType::SOption(Box::new(SType::SInt)),
+                    v: input.into(),
+                }
+                .into(),
+            ),
+            condition: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SInt,
+                    }],
+                    body: Box::new(Expr::BinOp(
+                        BinOp::Relation(RelationOp::Eq),
+                        Box::new(
+                            ValUse {
+                                val_id: 1,
+                                tpe: SType::SInt,
+                            }
+                            .into(),
+                        ),
+                        Box::new(Constant::from(1i32).into()),
+                    )),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        eval_out::<Option<i32>>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval() {
+        assert_eq!(filter_eq_one(Some(1)), Some(1));
+        assert_eq!(filter_eq_one(Some(2)), None);
+        assert_eq!(filter_eq_one(None), None);
+    }
+}
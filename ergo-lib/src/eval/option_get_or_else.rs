@@ -0,0 +1,61 @@
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::option_get_or_else::OptionGetOrElse;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for OptionGetOrElse {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self.input.eval(env, ectx)?;
+        // default is evaluated regardless of the input (same as in the reference interpreter)
+        let default = self.default.eval(env, ectx)?;
+        match input {
+            Value::Opt(opt) => Ok(opt.unwrap_or(default)),
+            v => Err(TryExtractFromError(format!("expected Option, found {:?}", v)).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn get_var_get_or_else(var_id: u8, default: i64) -> Expr {
+        OptionGetOrElse::new(
+            GetVar {
+                var_id,
+                var_tpe: SType::SLong,
+            }
+            .into(),
+            Expr::Const(default.into()),
+        )
+        .unwrap()
+        .into()
+    }
+
+    #[test]
+    fn eval_some() {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.insert(1, 5i64.into());
+        assert_eq!(eval_out::<i64>(&get_var_get_or_else(1, 7), Rc::new(ctx)), 5);
+    }
+
+    #[test]
+    fn eval_none() {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.clear();
+        assert_eq!(eval_out::<i64>(&get_var_get_or_else(1, 7), Rc::new(ctx)), 7);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::option_is_defined::OptionIsDefined;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for OptionIsDefined {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(env, ectx)? {
+            Value::Opt(opt) => Ok(Value::Boolean(opt.is_some())),
+            v => Err(TryExtractFromError(format!("expected Option, found {:?}", v)).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn get_var_is_defined(var_id: u8) -> Expr {
+        OptionIsDefined {
+            input: Box::new(
+                GetVar {
+                    var_id,
+                    var_tpe: SType::SLong,
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_some() {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.insert(1, 5i64.into());
+        assert!(eval_out::<bool>(&get_var_is_defined(1), Rc::new(ctx)));
+    }
+
+    #[test]
+    fn eval_none() {
+        let mut ctx = force_any_val::<Context>();
+        ctx.extension.values.clear();
+        assert!(!eval_out::<bool>(&get_var_is_defined(1), Rc::new(ctx)));
+    }
+}
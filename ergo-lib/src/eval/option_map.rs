@@ -0,0 +1,120 @@
+use crate::ast::expr::Expr;
+use crate::ast::option_map::OptionMap;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for OptionMap {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = match self.input.eval(env, ectx)? {
+            Value::Opt(opt) => *opt,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        let mapper = match self.mapper.as_ref() {
+            Expr::FuncValue(f) => f,
+            _ => return Err(EvalError::UnexpectedExpr),
+        };
+        let res = match input {
+            Some(v) => Some(mapper.apply(env, ectx, vec![v])?),
+            None => None,
+        };
+        Ok(Value::Opt(Box::new(res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::box_methods::BoxM;
+    use crate::ast::box_methods::RegisterId;
+    use crate::ast::constant::Constant;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, NumOp};
+    use crate::ast::val_use::ValUse;
+    use crate::chain::ergo_box::ErgoBox;
+    use crate::chain::ergo_box::NonMandatoryRegisters;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn ctx_with_self_regs(regs: Vec<Constant>) -> Rc<Context> {
+        let b = force_any_val::<ErgoBox>();
+        let self_box = ErgoBox::new(
+            b.value,
+            b.ergo_tree,
+            b.tokens,
+            NonMandatoryRegisters::from_ordered_values(regs).unwrap(),
+            b.creation_height,
+            b.transaction_id,
+            b.index,
+        );
+        Rc::new(Context {
+            self_box,
+            ..Context::dummy()
+        })
+    }
+
+    // SELF.R4[Int].map { (v: Int) => v + 1 }
+    fn self_r4_plus_one() -> Expr {
+        OptionMap {
+            input: Box::new(
+                BoxM::ExtractRegisterAs {
+                    input: Box::new(GlobalVars::SelfBox.into()),
+                    register_id: RegisterId(4),
+                    tpe: SType::SInt,
+                }
+                .into(),
+            ),
+            mapper: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SInt,
+                    }],
+                    body: Box::new(Expr::BinOp(
+                        BinOp::Num(NumOp::Add),
+                        Box::new(
+                            ValUse {
+                                val_id: 1,
+                                tpe: SType::SInt,
+                            }
+                            .into(),
+                        ),
+                        Box::new(Constant::from(1i32).into()),
+                    )),
+                }
+                .into(),
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_some() {
+        let ctx = ctx_with_self_regs(vec![1i32.into()]);
+        assert_eq!(eval_out::<Option<i32>>(&self_r4_plus_one(), ctx), Some(2));
+    }
+
+    #[test]
+    fn eval_none() {
+        let ctx = ctx_with_self_regs(vec![]);
+        assert_eq!(eval_out::<Option<i32>>(&self_r4_plus_one(), ctx), None);
+    }
+
+    #[test]
+    fn tpe_is_lambda_range() {
+        assert_eq!(
+            self_r4_plus_one().tpe(),
+            SType::SOption(Box::new(SType::SInt))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::ast::collection::Collection;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::expr::Expr;
+use crate::ast::or::Or;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Or {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        if let Expr::Collection(Collection::Exprs { items, .. }) = self.input.as_ref() {
+            // explicitly listed elements (`anyOf(Coll(a, b, c))`), stop on the first true
+            for item in items {
+                if item.eval(env, ectx)?.try_extract_into::<bool>()? {
+                    return Ok(true.into());
+                }
+            }
+            return Ok(false.into());
+        }
+        let input = self
+            .input
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<bool>>()?;
+        Ok(input.into_iter().any(|b| b).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::serialization::SigmaSerializable;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn any_of(coll_bytes: Vec<u8>) -> bool {
+        // Coll[Boolean] constant with bit-packed elements
+        let input = Expr::sigma_parse_bytes(coll_bytes).unwrap();
+        let expr: Expr = Or {
+            input: Box::new(input),
+        }
+        .into();
+        eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_bit_packed() {
+        // Coll(true, false, true)
+        assert!(any_of(vec![0x0d, 0x03, 0x05]));
+        // 10 x false
+        assert!(!any_of(vec![0x0d, 0x0a, 0x00, 0x00]));
+        // 10 x false with the last one true
+        assert!(any_of(vec![0x0d, 0x0a, 0x00, 0x02]));
+        // empty
+        assert!(!any_of(vec![0x0d, 0x00]));
+    }
+
+    #[test]
+    fn eval_exprs_short_circuit() {
+        // the last item is not a boolean and fails if evaluated
+        let expr: Expr = Or {
+            input: Box::new(
+                Collection::Exprs {
+                    elem_tpe: SType::SBoolean,
+                    items: vec![
+                        Constant::from(false).into(),
+                        Constant::from(true).into(),
+                        Constant::from(1i32).into(),
+                    ],
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert!(eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>())));
+    }
+
+    fn any_of_exprs(items: Vec<Constant>) -> Result<bool, EvalError> {
+        let expr: Expr = Or {
+            input: Box::new(
+                Collection::Exprs {
+                    elem_tpe: SType::SBoolean,
+                    items: items.into_iter().map(Expr::Const).collect(),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        try_eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval(bools in vec(any::<bool>(), 0..64)) {
+            let expected = bools.iter().any(|b| *b);
+            let expr: Expr = Or {
+                input: Box::new(Constant::from(bools.clone()).into()),
+            }
+            .into();
+            prop_assert_eq!(
+                eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>())),
+                expected
+            );
+            prop_assert_eq!(
+                any_of_exprs(bools.into_iter().map(Constant::from).collect()),
+                Ok(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn eval_exprs_empty() {
+        assert_eq!(any_of_exprs(vec![]), Ok(false));
+    }
+
+    #[test]
+    fn eval_exprs_non_boolean() {
+        assert!(any_of_exprs(vec![false.into(), 1i32.into()]).is_err());
+    }
+}
@@ -9,7 +9,7 @@ use super::Evaluable;
 impl Evaluable for PropertyCall {
     fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
         let ov = (*self.obj).eval(env, ectx)?;
-        self.method.eval_fn()(ov, vec![])
+        self.method.eval_fn()(env, ectx, ov, vec![])
     }
 }
 
@@ -17,11 +17,15 @@ impl Evaluable for PropertyCall {
 mod tests {
     use std::rc::Rc;
 
+    use crate::ast::constant::TryExtractInto;
     use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
     use crate::chain::ergo_box::ErgoBox;
+    use crate::chain::token::Token;
     use crate::eval::context::Context;
     use crate::eval::tests::eval_out;
     use crate::test_util::force_any_val;
+    use crate::types::sbox;
     use crate::types::scontext;
 
     use super::*;
@@ -38,4 +42,49 @@ mod tests {
             ctx.data_inputs
         );
     }
+
+    #[test]
+    fn eval_context_self_box_index() {
+        let pc = PropertyCall {
+            obj: Box::new(Expr::Context),
+            method: scontext::SELF_BOX_INDEX_PROPERTY.clone(),
+        };
+        let self_box = force_any_val::<ErgoBox>();
+        let ctx = Context {
+            self_box: self_box.clone(),
+            inputs: vec![force_any_val::<ErgoBox>(), self_box],
+            ..Context::dummy()
+        };
+        assert_eq!(eval_out::<i32>(&pc.into(), Rc::new(ctx)), 1);
+    }
+
+    #[test]
+    fn eval_self_box_tokens() {
+        let pc = PropertyCall {
+            obj: Box::new(GlobalVars::SelfBox.into()),
+            method: sbox::TOKENS_PROPERTY.clone(),
+        };
+        let tokens = vec![force_any_val::<Token>(), force_any_val::<Token>()];
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box.tokens = tokens.clone();
+        let ctx = Rc::new(ctx);
+        let res = eval_out::<Vec<(Vec<u8>, i64)>>(&pc.into(), ctx.clone());
+        assert_eq!(
+            res,
+            tokens
+                .iter()
+                .map(|t| (t.token_id.0 .0.to_vec(), i64::from(t.amount)))
+                .collect::<Vec<_>>()
+        );
+        assert!(res.iter().all(|(id, _)| id.len() == 32));
+        // same shape and content as SELF.R2
+        assert_eq!(
+            ctx.self_box
+                .get_register(2)
+                .unwrap()
+                .try_extract_into::<Vec<(Vec<u8>, i64)>>()
+                .unwrap(),
+            res
+        );
+    }
 }
@@ -0,0 +1,147 @@
+use crate::ast::constant::TryExtractFromError;
+use crate::ast::select_field::SelectField;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for SelectField {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        match self.input.eval(env, ectx)? {
+            Value::Tup(items) => {
+                let len = items.len();
+                (self.field_index as usize)
+                    .checked_sub(1)
+                    .and_then(|idx| items.into_iter().nth(idx))
+                    .ok_or(EvalError::TupleFieldIndexOutOfBounds {
+                        index: self.field_index,
+                        len,
+                    })
+            }
+            v => Err(TryExtractFromError(format!("expected tuple, found {:?}", v)).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::block::BlockValue;
+    use crate::ast::coll_by_index::ByIndex;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::property_call::PropertyCall;
+    use crate::ast::tuple::Tuple;
+    use crate::ast::val_def::ValDef;
+    use crate::ast::val_use::ValUse;
+    use crate::chain::token::Token;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+    use crate::types::sbox;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn select_field(input: Expr, field_index: u8) -> Expr {
+        SelectField::new(input, field_index).unwrap().into()
+    }
+
+    #[test]
+    fn eval_self_box_first_token() {
+        // SELF.tokens(0)
+        let first_token: Expr = ByIndex {
+            input: Box::new(
+                PropertyCall {
+                    obj: Box::new(GlobalVars::SelfBox.into()),
+                    method: sbox::TOKENS_PROPERTY.clone(),
+                }
+                .into(),
+            ),
+            index: Box::new(Expr::Const(0i32.into())),
+            default: None,
+        }
+        .into();
+        let token = force_any_val::<Token>();
+        let mut ctx = force_any_val::<Context>();
+        ctx.self_box.tokens = vec![token.clone()];
+        let ctx = Rc::new(ctx);
+        assert_eq!(
+            eval_out::<Vec<u8>>(&select_field(first_token.clone(), 1), ctx.clone()),
+            token.token_id.0 .0.to_vec()
+        );
+        assert_eq!(
+            eval_out::<i64>(&select_field(first_token, 2), ctx),
+            i64::from(token.amount)
+        );
+    }
+
+    fn quad() -> Expr {
+        // (1.toByte, 2.toShort, 3, 4L)
+        Tuple {
+            items: vec![
+                Expr::Const(1i8.into()),
+                Expr::Const(2i16.into()),
+                Expr::Const(3i32.into()),
+                Expr::Const(4i64.into()),
+            ],
+        }
+        .into()
+    }
+
+    #[test]
+    fn eval_quad_fields() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<i8>(&select_field(quad(), 1), ctx.clone()), 1);
+        assert_eq!(eval_out::<i16>(&select_field(quad(), 2), ctx.clone()), 2);
+        assert_eq!(eval_out::<i32>(&select_field(quad(), 3), ctx.clone()), 3);
+        assert_eq!(eval_out::<i64>(&select_field(quad(), 4), ctx), 4);
+    }
+
+    #[test]
+    fn quad_field_types() {
+        assert_eq!(select_field(quad(), 1).tpe(), SType::SByte);
+        assert_eq!(select_field(quad(), 2).tpe(), SType::SShort);
+        assert_eq!(select_field(quad(), 3).tpe(), SType::SInt);
+        assert_eq!(select_field(quad(), 4).tpe(), SType::SLong);
+    }
+
+    #[test]
+    fn quad_field_index_out_of_bounds() {
+        assert!(SelectField::new(quad(), 0).is_err());
+        assert!(SelectField::new(quad(), 5).is_err());
+    }
+
+    #[test]
+    fn eval_field_index_out_of_bounds() {
+        // { val t = (1, 2L); t._3 }, with `t` used as a 3-tuple
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: 1,
+                rhs: Box::new(
+                    Tuple {
+                        items: vec![Expr::Const(1i32.into()), Expr::Const(2i64.into())],
+                    }
+                    .into(),
+                ),
+            }
+            .into()],
+            result: Box::new(select_field(
+                ValUse {
+                    val_id: 1,
+                    tpe: SType::STup(vec![SType::SInt, SType::SLong, SType::SInt]),
+                }
+                .into(),
+                3,
+            )),
+        }
+        .into();
+        assert_eq!(
+            try_eval_out::<i32>(&expr, Rc::new(force_any_val::<Context>())),
+            Err(EvalError::TupleFieldIndexOutOfBounds { index: 3, len: 2 })
+        );
+    }
+}
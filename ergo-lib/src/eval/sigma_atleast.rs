@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use crate::ast::constant::TryExtractInto;
+use crate::ast::sigma_atleast::Atleast;
+use crate::ast::value::Value;
+use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
+use crate::sigma_protocol::sigma_boolean::SigmaProp;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Atleast {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let bound = self.bound.eval(env, ectx)?.try_extract_into::<i32>()?;
+        let props = self
+            .input
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<SigmaProp>>()?;
+        let props_count = props.len();
+        let k = u8::try_from(bound)
+            .ok()
+            .filter(|k| *k as usize <= props_count)
+            .ok_or(EvalError::AtleastBoundOutOfRange { bound, props_count })?;
+        let children = props.into_iter().map(|sp| sp.value().clone()).collect();
+        Ok(SigmaProp::new(SigmaBoolean::CTHRESHOLD { k, children }).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::sigma_protocol::sigma_boolean::ProveDlog;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn atleast(bound: i32, props: Vec<SigmaProp>) -> Expr {
+        Atleast {
+            bound: Box::new(Constant::from(bound).into()),
+            input: Box::new(Constant::from(props).into()),
+        }
+        .into()
+    }
+
+    fn dlog_props() -> Vec<SigmaProp> {
+        vec![
+            force_any_val::<ProveDlog>().into(),
+            force_any_val::<ProveDlog>().into(),
+            force_any_val::<ProveDlog>().into(),
+        ]
+    }
+
+    #[test]
+    fn eval_2_of_3() {
+        let props = dlog_props();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<SigmaProp>(&atleast(2, props.clone()), ctx),
+            SigmaProp::new(SigmaBoolean::CTHRESHOLD {
+                k: 2,
+                children: props.iter().map(|sp| sp.value().clone()).collect(),
+            })
+        );
+    }
+
+    #[test]
+    fn eval_bound_out_of_range() {
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<SigmaProp>(&atleast(-1, dlog_props()), ctx.clone()).is_err());
+        assert!(try_eval_out::<SigmaProp>(&atleast(4, dlog_props()), ctx).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::sigma_or::SigmaOr;
+use crate::ast::value::Value;
+use crate::sigma_protocol::sigma_boolean::SigmaBoolean;
+use crate::sigma_protocol::sigma_boolean::SigmaProp;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for SigmaOr {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let items = self
+            .items
+            .iter()
+            .map(|i| {
+                i.eval(env, ectx)?
+                    .try_extract_into::<SigmaProp>()
+                    .map(|sp| sp.value().clone())
+                    .map_err(EvalError::from)
+            })
+            .collect::<Result<Vec<SigmaBoolean>, EvalError>>()?;
+        Ok(SigmaProp::new(SigmaBoolean::cor_normalized(items)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::{eval_out, try_eval_out};
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_sigma_props() {
+        let a = force_any_val::<SigmaProp>();
+        let b = force_any_val::<SigmaProp>();
+        let expr: Expr = SigmaOr {
+            items: vec![
+                Constant::from(a.clone()).into(),
+                Constant::from(b.clone()).into(),
+            ],
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<SigmaProp>(&expr, ctx),
+            SigmaProp::new(SigmaBoolean::COR(vec![
+                a.value().clone(),
+                b.value().clone()
+            ]))
+        );
+    }
+
+    #[test]
+    fn eval_non_sigma_prop_item() {
+        let expr: Expr = SigmaOr {
+            items: vec![
+                Constant::from(force_any_val::<SigmaProp>()).into(),
+                Constant::from(1i32).into(),
+            ],
+        }
+        .into();
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert!(try_eval_out::<SigmaProp>(&expr, ctx).is_err());
+    }
+
+    fn trivial_with_pk(trivial: bool) -> (Expr, SigmaProp) {
+        let a = force_any_val::<SigmaProp>();
+        let expr: Expr = SigmaOr {
+            items: vec![
+                Constant::from(SigmaProp::new(SigmaBoolean::TrivialProp(trivial))).into(),
+                Constant::from(a.clone()).into(),
+            ],
+        }
+        .into();
+        (expr, a)
+    }
+
+    #[test]
+    fn eval_true_or_pk() {
+        let (expr, _) = trivial_with_pk(true);
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<SigmaProp>(&expr, ctx),
+            SigmaProp::new(SigmaBoolean::TrivialProp(true))
+        );
+    }
+
+    #[test]
+    fn eval_false_or_pk() {
+        let (expr, a) = trivial_with_pk(false);
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(eval_out::<SigmaProp>(&expr, ctx), a.clone());
+    }
+}
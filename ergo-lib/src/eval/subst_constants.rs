@@ -0,0 +1,166 @@
+use crate::ast::constant::Constant;
+use crate::ast::constant::TryExtractInto;
+use crate::ast::subst_constants::SubstConstants;
+use crate::ast::value::Value;
+use crate::ergo_tree::substitute_constants;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for SubstConstants {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let script_bytes = self
+            .script_bytes
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<u8>>()?;
+        let positions = self
+            .positions
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<i32>>()?;
+        let new_values = match self.new_values.eval(env, ectx)? {
+            Value::Coll(coll) => (0..coll.len())
+                .filter_map(|i| coll.get(i))
+                .map(|v| Constant {
+                    tpe: coll.elem_tpe().clone(),
+                    v,
+                })
+                .collect::<Vec<Constant>>(),
+            v => {
+                return Err(EvalError::UnexpectedValue(format!(
+                    "substConstants: expected collection of new values, found {:?}",
+                    v
+                )))
+            }
+        };
+        if positions.len() != new_values.len() {
+            return Err(EvalError::UnexpectedValue(format!(
+                "substConstants: {} positions for {} new values",
+                positions.len(),
+                new_values.len()
+            )));
+        }
+        Ok(substitute_constants(&script_bytes, &positions, &new_values)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::bool_to_sigma::BoolToSigmaProp;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ergo_tree::ErgoTree;
+    use crate::ergo_tree::SetConstantError;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::serialization::SigmaSerializable;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    fn height_gt(h: i32) -> Expr {
+        // sigmaProp(HEIGHT > h)
+        BoolToSigmaProp {
+            input: Box::new(Expr::BinOp(
+                BinOp::Relation(RelationOp::Gt),
+                Box::new(GlobalVars::Height.into()),
+                Box::new(Expr::Const(h.into())),
+            )),
+        }
+        .into()
+    }
+
+    fn subst_constants(
+        script_bytes: Vec<u8>,
+        positions: Vec<i32>,
+        new_values: Constant,
+    ) -> Result<Vec<u8>, EvalError> {
+        let expr: Expr = SubstConstants {
+            script_bytes: Box::new(Constant::from(script_bytes).into()),
+            positions: Box::new(Constant::from(positions).into()),
+            new_values: Box::new(new_values.into()),
+        }
+        .into();
+        try_eval_out::<Vec<u8>>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    #[test]
+    fn eval_single_int() {
+        let tree = ErgoTree::with_segregation(Rc::new(height_gt(100)));
+        let bytes =
+            subst_constants(tree.sigma_serialize_bytes(), vec![0], vec![200i32].into()).unwrap();
+        let new_tree = ErgoTree::sigma_parse_bytes(bytes).unwrap();
+        assert_eq!(*new_tree.proposition().unwrap(), height_gt(200));
+    }
+
+    #[test]
+    fn eval_keeps_root_bytes() {
+        // header, 1 constant (Int 100), root bytes that are not a valid expression
+        let bytes = vec![0x10, 0x01, 0x04, 0xc8, 0x01, 0xff, 0xff];
+        assert_eq!(
+            subst_constants(bytes, vec![0], vec![5i32].into()),
+            Ok(vec![0x10, 0x01, 0x04, 0x0a, 0xff, 0xff])
+        );
+    }
+
+    #[test]
+    fn eval_with_size() {
+        // header with the size flag, size, 1 constant (Int 100), HEIGHT > placeholder 0
+        let bytes = vec![0x18, 0x08, 0x01, 0x04, 0xc8, 0x01, 0x91, 0xa3, 0x73, 0x00];
+        assert_eq!(
+            subst_constants(bytes, vec![0], vec![5i32].into()),
+            Ok(vec![0x18, 0x07, 0x01, 0x04, 0x0a, 0x91, 0xa3, 0x73, 0x00])
+        );
+    }
+
+    #[test]
+    fn eval_out_of_range_position() {
+        let bytes = ErgoTree::with_segregation(Rc::new(height_gt(100))).sigma_serialize_bytes();
+        assert_eq!(
+            subst_constants(bytes.clone(), vec![1], vec![200i32].into()),
+            Ok(bytes.clone())
+        );
+        assert_eq!(
+            subst_constants(bytes.clone(), vec![-1], vec![200i32].into()),
+            Ok(bytes)
+        );
+    }
+
+    #[test]
+    fn eval_without_segregation() {
+        let bytes = ErgoTree::without_segregation(Rc::new(height_gt(100))).sigma_serialize_bytes();
+        assert_eq!(
+            subst_constants(bytes.clone(), vec![0], vec![200i32].into()),
+            Ok(bytes)
+        );
+    }
+
+    #[test]
+    fn eval_type_mismatch() {
+        let bytes = ErgoTree::with_segregation(Rc::new(height_gt(100))).sigma_serialize_bytes();
+        assert_eq!(
+            subst_constants(bytes, vec![0], vec![200i64].into()),
+            Err(EvalError::SetConstantError(
+                SetConstantError::TypeMismatch {
+                    index: 0,
+                    expected: SType::SInt,
+                    actual: SType::SLong,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn eval_positions_count_mismatch() {
+        let bytes = ErgoTree::with_segregation(Rc::new(height_gt(100))).sigma_serialize_bytes();
+        assert!(matches!(
+            subst_constants(bytes, vec![0, 1], vec![200i32].into()),
+            Err(EvalError::UnexpectedValue(_))
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::ast::tuple::Tuple;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Tuple {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let items = self
+            .items
+            .iter()
+            .map(|i| i.eval(env, ectx))
+            .collect::<Result<Vec<Value>, EvalError>>()?;
+        Ok(Value::Tup(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::select_field::SelectField;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use crate::types::stype::SType;
+
+    use super::*;
+
+    #[test]
+    fn eval_pair() {
+        let expr: Expr = Tuple {
+            items: vec![GlobalVars::Height.into(), Expr::Const(1i64.into())],
+        }
+        .into();
+        assert_eq!(expr.tpe(), SType::STup(vec![SType::SInt, SType::SLong]));
+        let ctx = Rc::new(force_any_val::<Context>());
+        assert_eq!(
+            eval_out::<(i32, i64)>(&expr, ctx.clone()),
+            (ctx.height, 1i64)
+        );
+    }
+
+    #[test]
+    fn eval_triple() {
+        let tuple: Expr = Tuple {
+            items: vec![
+                Expr::Const(1i32.into()),
+                Expr::Const(true.into()),
+                Expr::Const(vec![1i8, 2].into()),
+            ],
+        }
+        .into();
+        assert_eq!(
+            tuple.tpe(),
+            SType::STup(vec![
+                SType::SInt,
+                SType::SBoolean,
+                SType::SColl(Box::new(SType::SByte))
+            ])
+        );
+        let third: Expr = SelectField::new(tuple, 3).unwrap().into();
+        assert_eq!(
+            eval_out::<Vec<i8>>(&third, Rc::new(force_any_val::<Context>())),
+            vec![1i8, 2]
+        );
+    }
+}
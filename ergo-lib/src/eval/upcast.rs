@@ -0,0 +1,89 @@
+use std::convert::TryFrom;
+
+use crate::ast::upcast::Upcast;
+use crate::ast::value::Value;
+use crate::big_integer::BigInteger;
+use crate::types::stype::SType;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+/// Numeric conversion to the given type (`toByte`, `toLong`, etc.),
+/// fails if the value does not fit into the target type
+pub(crate) fn cast_numeric(v: Value, tpe: &SType) -> Result<Value, EvalError> {
+    let overflow = || EvalError::ArithmeticException(format!("{:?} overflow", tpe));
+    let wide: i64 = match v {
+        Value::Byte(v) => v as i64,
+        Value::Short(v) => v as i64,
+        Value::Int(v) => v as i64,
+        Value::Long(v) => v,
+        Value::BigInt(v) if *tpe == SType::SBigInt => return Ok(Value::BigInt(v)),
+        Value::BigInt(v) => v.to_i64().ok_or_else(overflow)?,
+        _ => return Err(EvalError::UnexpectedExpr),
+    };
+    match tpe {
+        SType::SByte => i8::try_from(wide).map(Value::Byte).map_err(|_| overflow()),
+        SType::SShort => i16::try_from(wide)
+            .map(Value::Short)
+            .map_err(|_| overflow()),
+        SType::SInt => i32::try_from(wide).map(Value::Int).map_err(|_| overflow()),
+        SType::SLong => Ok(Value::Long(wide)),
+        SType::SBigInt => Ok(Value::BigInt(BigInteger::from(wide))),
+        _ => Err(EvalError::UnexpectedExpr),
+    }
+}
+
+impl Evaluable for Upcast {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        // Upcast::new (used by the parser) checks that the target is not narrower than the input,
+        // so this fails only for hand-built nodes
+        cast_numeric(self.input.eval(env, ectx)?, &self.tpe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    #[test]
+    fn eval_int_to_long() {
+        let expr: Expr = Upcast::new(Expr::Const((-5i32).into()), SType::SLong)
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out::<i64>(&expr, Rc::new(force_any_val::<Context>())),
+            -5
+        );
+    }
+
+    #[test]
+    fn eval_long_to_big_int() {
+        let expr: Expr = Upcast::new(Expr::Const(i64::MIN.into()), SType::SBigInt)
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out::<BigInteger>(&expr, Rc::new(force_any_val::<Context>())),
+            BigInteger::from(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn eval_byte_to_short() {
+        let expr: Expr = Upcast::new(Expr::Const(i8::MIN.into()), SType::SShort)
+            .unwrap()
+            .into();
+        assert_eq!(
+            eval_out::<i16>(&expr, Rc::new(force_any_val::<Context>())),
+            i8::MIN as i16
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::ast::val_use::ValUse;
+use crate::ast::value::Value;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for ValUse {
+    fn eval(&self, env: &Env, _ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        env.get(self.val_id)
+            .cloned()
+            .ok_or_else(|| EvalError::NotFound(format!("no value bound to id {}", self.val_id)))
+    }
+}
@@ -0,0 +1,82 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::ast::xor::Xor;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for Xor {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let left = self.left.eval(env, ectx)?.try_extract_into::<Vec<i8>>()?;
+        let right = self.right.eval(env, ectx)?.try_extract_into::<Vec<i8>>()?;
+        if left.len() != right.len() {
+            return Err(EvalError::UnexpectedValue(format!(
+                "xor: byte arrays of different lengths ({} and {})",
+                left.len(),
+                right.len()
+            )));
+        }
+        Ok(left
+            .iter()
+            .zip(right.iter())
+            .map(|(l, r)| l ^ r)
+            .collect::<Vec<i8>>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn xor(left: Vec<u8>, right: Vec<u8>) -> Result<Vec<u8>, EvalError> {
+        let expr: Expr = Xor {
+            left: Box::new(Constant::from(left).into()),
+            right: Box::new(Constant::from(right).into()),
+        }
+        .into();
+        try_eval_out::<Vec<u8>>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval_zeros_identity(bytes in vec(any::<u8>(), 0..64)) {
+            let zeros = vec![0u8; bytes.len()];
+            prop_assert_eq!(xor(bytes.clone(), zeros), Ok(bytes));
+        }
+
+        #[test]
+        fn eval_self_xor_zeros(bytes in vec(any::<u8>(), 0..64)) {
+            prop_assert_eq!(xor(bytes.clone(), bytes.clone()), Ok(vec![0u8; bytes.len()]));
+        }
+    }
+
+    #[test]
+    fn eval() {
+        assert_eq!(
+            xor(vec![0b1100, 0xff, 0x00], vec![0b1010, 0x0f, 0x80]),
+            Ok(vec![0b0110, 0xf0, 0x80])
+        );
+    }
+
+    #[test]
+    fn eval_different_lengths() {
+        assert!(matches!(
+            xor(vec![1, 2, 3], vec![1, 2]),
+            Err(EvalError::UnexpectedValue(_))
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Value;
+use crate::ast::xor_of::XorOf;
+
+use super::Env;
+use super::EvalContext;
+use super::EvalError;
+use super::Evaluable;
+
+impl Evaluable for XorOf {
+    fn eval(&self, env: &Env, ectx: &mut EvalContext) -> Result<Value, EvalError> {
+        let input = self
+            .input
+            .eval(env, ectx)?
+            .try_extract_into::<Vec<bool>>()?;
+        Ok(input.into_iter().fold(false, |acc, b| acc ^ b).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::test_util::force_any_val;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn xor_of(bools: Vec<bool>) -> bool {
+        let expr: Expr = XorOf {
+            input: Box::new(Constant::from(bools).into()),
+        }
+        .into();
+        eval_out::<bool>(&expr, Rc::new(force_any_val::<Context>()))
+    }
+
+    proptest! {
+
+        #[test]
+        fn eval(bools in vec(any::<bool>(), 0..64)) {
+            let expected = bools.iter().fold(false, |acc, b| acc ^ b);
+            prop_assert_eq!(xor_of(bools), expected);
+        }
+    }
+
+    #[test]
+    fn eval_empty() {
+        assert!(!xor_of(vec![]));
+    }
+}
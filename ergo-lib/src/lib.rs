@@ -13,10 +13,10 @@
 #![allow(clippy::unit_arg)]
 #![deny(broken_intra_doc_links)]
 
-mod big_integer;
 mod eval;
 
 pub mod ast;
+pub mod big_integer;
 pub mod chain;
 pub mod constants;
 pub mod serialization;
@@ -1,14 +1,61 @@
 //! Serializers
 
+mod and;
+mod bin_op;
+mod block;
+mod bool_to_sigma;
+mod box_methods;
+mod byte_array_to_bigint;
+mod calc_blake2b256;
+mod calc_sha256;
+mod coll_append;
+mod coll_by_index;
+mod coll_exists;
+mod coll_forall;
+mod coll_map;
+mod coll_size;
+mod coll_slice;
+mod collection;
 mod constant;
 mod constant_placeholder;
+mod create_provedlog;
 mod data;
+mod decode_point;
+mod downcast;
+mod exponentiate;
 mod expr;
+mod extract_amount;
+mod extract_bytes;
+mod extract_bytes_with_no_ref;
+mod extract_creation_info;
+mod extract_id;
+mod extract_script_bytes;
 mod fold;
+mod func_value;
+mod get_var;
 mod global_vars;
+mod if_op;
+mod logical_not;
 mod method_call;
+mod multiply_group;
+mod negation;
+mod option_filter;
+mod option_get;
+mod option_get_or_else;
+mod option_is_defined;
+mod option_map;
+mod or;
 mod property_call;
+mod select_field;
+mod sigma_and;
+mod sigma_atleast;
+mod sigma_or;
 mod sigmaboolean;
+mod subst_constants;
+mod tuple;
+mod upcast;
+mod val_def;
+mod val_use;
 
 pub(crate) mod constant_store;
 pub(crate) mod ergo_box;
@@ -16,6 +63,9 @@ pub(crate) mod op_code;
 pub(crate) mod sigma_byte_reader;
 pub(crate) mod sigma_byte_writer;
 pub(crate) mod types;
+pub(crate) mod val_def_type_store;
 
 mod serializable;
+mod xor;
+mod xor_of;
 pub use serializable::*;
@@ -0,0 +1,39 @@
+use std::io::Error;
+
+use crate::ast::and::And;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for And {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(And {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::and::And;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = And {
+            input: Box::new(Constant::from(vec![true, false, true]).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,132 @@
+use super::sigma_byte_writer::SigmaByteWrite;
+use crate::ast::expr::Expr;
+use crate::ast::ops::BinOp;
+use crate::serialization::{
+    sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
+};
+
+use std::io;
+
+pub struct BinOpSerializer {}
+
+impl BinOpSerializer {
+    pub fn sigma_serialize<W: SigmaByteWrite>(expr: &Expr, w: &mut W) -> Result<(), io::Error> {
+        match expr {
+            Expr::BinOp(_, left, right) => {
+                left.sigma_serialize(w)?;
+                right.sigma_serialize(w)
+            }
+            _ => panic!("expected BinOp"),
+        }
+    }
+
+    pub fn sigma_parse<R: SigmaByteRead>(op: BinOp, r: &mut R) -> Result<Expr, SerializationError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(Expr::BinOp(op, Box::new(left), Box::new(right)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::ops::{BinOp, BitOp, LogicalOp, NumOp, RelationOp, ShiftOp};
+    use crate::serialization::constant_store::ConstantStore;
+    use crate::serialization::sigma_byte_reader::{SigmaByteRead, SigmaByteReader};
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
+    use sigma_ser::peekable_reader::PeekableReader;
+    use std::io::Cursor;
+
+    /// serialization roundtrip in an ErgoTree v3 (bitwise op codes are rejected in older trees)
+    fn v3_roundtrip(expr: &Expr) -> Expr {
+        let mut data = expr.sigma_serialize_bytes();
+        let cursor = Cursor::new(&mut data[..]);
+        let mut r = SigmaByteReader::new(PeekableReader::new(cursor), ConstantStore::empty());
+        r.set_tree_version(3);
+        Expr::sigma_parse(&mut r).expect("parse failed")
+    }
+
+    #[test]
+    fn ser_roundtrip_eq() {
+        let expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(Expr::Const(1i64.into())),
+            Box::new(Expr::Const(2i64.into())),
+        );
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn ser_roundtrip_ordering() {
+        for op in [
+            RelationOp::Lt,
+            RelationOp::Le,
+            RelationOp::Gt,
+            RelationOp::Ge,
+        ] {
+            let expr = Expr::BinOp(
+                BinOp::Relation(op),
+                Box::new(Expr::Const(1i32.into())),
+                Box::new(Expr::Const(2i32.into())),
+            );
+            assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn ser_roundtrip_logical_ops() {
+        for op in [LogicalOp::And, LogicalOp::Or, LogicalOp::Xor] {
+            let expr = Expr::BinOp(
+                BinOp::Logical(op),
+                Box::new(Expr::Const(true.into())),
+                Box::new(Expr::Const(false.into())),
+            );
+            assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn ser_roundtrip_bit_ops() {
+        for op in [BitOp::And, BitOp::Or, BitOp::Xor] {
+            let expr = Expr::BinOp(
+                BinOp::Bit(op),
+                Box::new(Expr::Const(7i64.into())),
+                Box::new(Expr::Const((-2i64).into())),
+            );
+            assert_eq![v3_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn ser_roundtrip_shift_ops() {
+        for op in [ShiftOp::Left, ShiftOp::Right, ShiftOp::RightZeroed] {
+            let expr = Expr::BinOp(
+                BinOp::Shift(op),
+                Box::new(Expr::Const((-8i32).into())),
+                Box::new(Expr::Const(3i32.into())),
+            );
+            assert_eq![v3_roundtrip(&expr), expr];
+        }
+    }
+
+    #[test]
+    fn ser_roundtrip_num_ops() {
+        for op in [
+            NumOp::Add,
+            NumOp::Subtract,
+            NumOp::Multiply,
+            NumOp::Divide,
+            NumOp::Modulo,
+            NumOp::Min,
+            NumOp::Max,
+        ] {
+            let expr = Expr::BinOp(
+                BinOp::Num(op),
+                Box::new(Expr::Const(7i32.into())),
+                Box::new(Expr::Const(2i32.into())),
+            );
+            assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
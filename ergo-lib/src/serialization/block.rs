@@ -0,0 +1,98 @@
+use std::io::Error;
+
+use crate::ast::block::BlockValue;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for BlockValue {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.items.len() as u32)?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))?;
+        self.result.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let items_count = r.get_u32()?;
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        let result = Expr::sigma_parse(r)?;
+        Ok(BlockValue {
+            items,
+            result: Box::new(result),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::block::BlockValue;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::ops::{BinOp, NumOp};
+    use crate::ast::option_get::OptionGet;
+    use crate::ast::val_def::ValDef;
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        // { val h = HEIGHT; h + h }
+        let h: Expr = ValUse {
+            val_id: 1,
+            tpe: SType::SInt,
+        }
+        .into();
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: 1,
+                rhs: Box::new(GlobalVars::Height.into()),
+            }
+            .into()],
+            result: Box::new(Expr::BinOp(
+                BinOp::Num(NumOp::Add),
+                Box::new(h.clone()),
+                Box::new(h),
+            )),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_ill_typed_val_def() {
+        // { val x = HEIGHT.get; x }
+        let expr: Expr = BlockValue {
+            items: vec![ValDef {
+                id: 1,
+                rhs: Box::new(
+                    OptionGet {
+                        input: Box::new(GlobalVars::Height.into()),
+                    }
+                    .into(),
+                ),
+            }
+            .into()],
+            result: Box::new(
+                ValUse {
+                    val_id: 1,
+                    tpe: SType::SInt,
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert!(matches!(
+            Expr::sigma_parse_bytes(expr.sigma_serialize_bytes()),
+            Err(SerializationError::InvalidArgument(_))
+        ));
+    }
+}
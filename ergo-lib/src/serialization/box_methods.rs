@@ -0,0 +1,58 @@
+use std::io::Error;
+
+use crate::ast::box_methods::BoxM;
+use crate::ast::box_methods::RegisterId;
+use crate::ast::expr::Expr;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for BoxM {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        match self {
+            BoxM::ExtractRegisterAs {
+                input,
+                register_id,
+                tpe,
+            } => {
+                input.sigma_serialize(w)?;
+                w.put_u8(register_id.0)?;
+                tpe.sigma_serialize(w)
+            }
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let register_id = RegisterId(r.get_u8()?);
+        let tpe = SType::sigma_parse(r)?;
+        Ok(BoxM::ExtractRegisterAs {
+            input: Box::new(input),
+            register_id,
+            tpe,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::box_methods::{BoxM, RegisterId};
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = BoxM::ExtractRegisterAs {
+            input: Box::new(GlobalVars::SelfBox.into()),
+            register_id: RegisterId(4),
+            tpe: SType::SLong,
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::Error;
+
+use crate::ast::byte_array_to_bigint::ByteArrayToBigInt;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for ByteArrayToBigInt {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(ByteArrayToBigInt {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::byte_array_to_bigint::ByteArrayToBigInt;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = ByteArrayToBigInt {
+            input: Box::new(Constant::from(vec![1u8, 0]).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        assert_eq!(
+            expr.sigma_serialize_bytes(),
+            vec![0x7b, 0x0e, 0x02, 0x01, 0x00]
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::io::Error;
+
+use crate::ast::calc_sha256::CalcSha256;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for CalcSha256 {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(CalcSha256 {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::calc_sha256::CalcSha256;
+    use crate::ast::expr::Expr;
+    use crate::ast::extract_script_bytes::ExtractScriptBytes;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        // sha256(SELF.propositionBytes)
+        let expr: Expr = CalcSha256 {
+            input: Box::new(
+                ExtractScriptBytes {
+                    input: Box::new(GlobalVars::SelfBox.into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::Error;
+
+use crate::ast::coll_append::Append;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Append {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.col_2.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let col_2 = Expr::sigma_parse(r)?;
+        Ok(Append {
+            input: Box::new(input),
+            col_2: Box::new(col_2),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::coll_append::Append;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = Append {
+            input: Box::new(Constant::from(vec![1i64, 2]).into()),
+            col_2: Box::new(Constant::from(vec![3i64]).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::Error;
+
+use crate::ast::coll_by_index::ByIndex;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for ByIndex {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.index.sigma_serialize(w)?;
+        match &self.default {
+            Some(default) => {
+                w.put_u8(1)?;
+                default.sigma_serialize(w)
+            }
+            None => w.put_u8(0),
+        }
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let index = Expr::sigma_parse(r)?;
+        let default = if r.get_u8()? != 0 {
+            Some(Box::new(Expr::sigma_parse(r)?))
+        } else {
+            None
+        };
+        Ok(ByIndex {
+            input: Box::new(input),
+            index: Box::new(index),
+            default,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::coll_by_index::ByIndex;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = ByIndex {
+            input: Box::new(Expr::Const(vec![1i64, 2, 3].into())),
+            index: Box::new(Expr::Const(1i32.into())),
+            default: None,
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn ser_roundtrip_with_default() {
+        let expr: Expr = ByIndex {
+            input: Box::new(Expr::Const(vec![1i64, 2, 3].into())),
+            index: Box::new(Expr::Const(1i32.into())),
+            default: Some(Box::new(Expr::Const(Constant::from(0i64)))),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::Error;
+
+use crate::ast::coll_forall::ForAll;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for ForAll {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.condition.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let condition = Expr::sigma_parse(r)?;
+        Ok(ForAll {
+            input: Box::new(input),
+            condition: Box::new(condition),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::coll_forall::ForAll;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+    use proptest::prelude::*;
+
+    #[test]
+    fn ser_roundtrip() {
+        // Coll(1L, 2L).forall { (x: Long) => x == 2L }
+        let expr: Expr = ForAll {
+            input: Box::new(Constant::from(vec![1i64, 2]).into()),
+            condition: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SLong,
+                    }],
+                    body: Box::new(Expr::BinOp(
+                        BinOp::Relation(RelationOp::Eq),
+                        Box::new(
+                            ValUse {
+                                val_id: 1,
+                                tpe: SType::SLong,
+                            }
+                            .into(),
+                        ),
+                        Box::new(Constant::from(2i64).into()),
+                    )),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip_arb(coll in any::<Vec<i64>>(), v in any::<i64>()) {
+            let expr: Expr = ForAll {
+                input: Box::new(Constant::from(coll).into()),
+                condition: Box::new(
+                    FuncValue {
+                        args: vec![FuncArg {
+                            idx: 1,
+                            tpe: SType::SLong,
+                        }],
+                        body: Box::new(Expr::BinOp(
+                            BinOp::Relation(RelationOp::Eq),
+                            Box::new(
+                                ValUse {
+                                    val_id: 1,
+                                    tpe: SType::SLong,
+                                }
+                                .into(),
+                            ),
+                            Box::new(Constant::from(v).into()),
+                        )),
+                    }
+                    .into(),
+                ),
+            }
+            .into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::Error;
+
+use crate::ast::coll_map::Map;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Map {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.mapper.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let mapper = Expr::sigma_parse(r)?;
+        Ok(Map {
+            input: Box::new(input),
+            mapper: Box::new(mapper),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::coll_map::Map;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        // Coll(1L, 2L).map { (x: Long) => x }
+        let expr: Expr = Map {
+            input: Box::new(Constant::from(vec![1i64, 2]).into()),
+            mapper: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SLong,
+                    }],
+                    body: Box::new(
+                        ValUse {
+                            val_id: 1,
+                            tpe: SType::SLong,
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
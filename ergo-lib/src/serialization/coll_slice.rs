@@ -0,0 +1,51 @@
+use std::io::Error;
+
+use crate::ast::coll_slice::Slice;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Slice {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.from.sigma_serialize(w)?;
+        self.until.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let from = Expr::sigma_parse(r)?;
+        let until = Expr::sigma_parse(r)?;
+        Ok(Slice {
+            input: Box::new(input),
+            from: Box::new(from),
+            until: Box::new(until),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::coll_slice::Slice;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(coll in any::<Vec<i32>>(), from in any::<i32>(), until in any::<i32>()) {
+            let expr: Expr = Slice {
+                input: Box::new(Constant::from(coll).into()),
+                from: Box::new(Constant::from(from).into()),
+                until: Box::new(Constant::from(until).into()),
+            }
+            .into();
+            prop_assert_eq![sigma_serialize_roundtrip(&expr), expr];
+        }
+    }
+}
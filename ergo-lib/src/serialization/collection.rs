@@ -0,0 +1,74 @@
+use std::io::Error;
+
+use crate::ast::collection::Collection;
+use crate::ast::expr::Expr;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Collection {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        match self {
+            Collection::BoolConstants(bools) => {
+                w.put_u16(bools.len() as u16)?;
+                w.put_bits(bools.as_slice())
+            }
+            Collection::Exprs { elem_tpe, items } => {
+                w.put_u16(items.len() as u16)?;
+                elem_tpe.sigma_serialize(w)?;
+                items.iter().try_for_each(|i| i.sigma_serialize(w))
+            }
+        }
+    }
+
+    // parses `Collection::Exprs`, see `Collection::sigma_parse_bool_constants`
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let items_count = r.get_u16()?;
+        let elem_tpe = SType::sigma_parse(r)?;
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        Ok(Collection::Exprs { elem_tpe, items })
+    }
+}
+
+impl Collection {
+    /// Parses [`Collection::BoolConstants`]
+    pub(crate) fn sigma_parse_bool_constants<R: SigmaByteRead>(
+        r: &mut R,
+    ) -> Result<Self, SerializationError> {
+        let items_count = r.get_u16()?;
+        let bools = r.get_bits(items_count as usize)?;
+        Ok(Collection::BoolConstants(bools))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::collection::Collection;
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip_exprs() {
+        let expr: Expr = Collection::Exprs {
+            elem_tpe: SType::SInt,
+            items: vec![Constant::from(1i32).into(), GlobalVars::Height.into()],
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn ser_roundtrip_bool_constants() {
+        let expr: Expr = Collection::BoolConstants(vec![true, false, true]).into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
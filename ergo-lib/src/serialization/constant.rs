@@ -25,6 +25,9 @@ impl SigmaSerializable for Constant {
 mod tests {
     use super::*;
     use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::dlog_group::EcPoint;
+    use crate::sigma_protocol::sigma_boolean::SigmaProp;
+    use proptest::collection::vec;
     use proptest::prelude::*;
 
     proptest! {
@@ -33,5 +36,32 @@ mod tests {
         fn ser_roundtrip(v in any::<Constant>()) {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
+
+        #[test]
+        fn ser_roundtrip_coll_group_element(v in vec(any::<EcPoint>(), 0..10)) {
+            let c: Constant = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+        }
+
+        #[test]
+        fn ser_roundtrip_coll_sigma_prop(v in vec(any::<SigmaProp>(), 0..10)) {
+            let c: Constant = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+        }
+
+        #[test]
+        fn ser_roundtrip_coll_tokens(v in vec((vec(any::<u8>(), 0..32), any::<i64>()), 0..10)) {
+            let c: Constant = v.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+        }
+    }
+
+    #[test]
+    fn ser_coll_tokens() {
+        let c: Constant = vec![(vec![1u8, 2], 3i64)].into();
+        // Coll type code, (_, Long) pair type code, Coll[Byte] type code, then the value
+        let expected = vec![0x0c, 0x4d, 0x0e, 1, 2, 1, 2, 6];
+        assert_eq!(c.sigma_serialize_bytes(), expected);
+        assert_eq!(Constant::sigma_parse_bytes(expected).unwrap(), c);
     }
 }
@@ -0,0 +1,52 @@
+use std::io::Error;
+
+use crate::ast::create_provedlog::CreateProveDlog;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for CreateProveDlog {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(CreateProveDlog {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::create_provedlog::CreateProveDlog;
+    use crate::ast::decode_point::DecodePoint;
+    use crate::ast::expr::Expr;
+    use crate::ast::extract_script_bytes::ExtractScriptBytes;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        // proveDlog(decodePoint(SELF.propositionBytes))
+        let expr: Expr = CreateProveDlog {
+            input: Box::new(
+                DecodePoint {
+                    input: Box::new(
+                        ExtractScriptBytes {
+                            input: Box::new(GlobalVars::SelfBox.into()),
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
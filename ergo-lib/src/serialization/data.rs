@@ -1,6 +1,8 @@
+use crate::ast::avl_tree_data::AvlTreeData;
 use crate::ast::value::Coll;
 use crate::ast::value::CollPrim;
 use crate::ast::value::Value;
+use crate::big_integer::BigInteger;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
 };
@@ -25,25 +27,63 @@ impl DataSerializer {
             Value::Int(v) => w.put_i32(*v),
             // Value::TInt(v) => w.put_i32(v.raw),
             Value::Long(v) => w.put_i64(*v),
-            Value::BigInt => todo!(),
+            Value::BigInt(v) => {
+                let bytes = v.to_signed_bytes_be();
+                w.put_usize_as_u16(bytes.len())?;
+                w.write_all(&bytes)
+            }
             Value::GroupElement(ecp) => ecp.sigma_serialize(w),
             Value::SigmaProp(s) => s.value().sigma_serialize(w),
             Value::CBox(_) => todo!(),
             // Value::TBox(_) => todo!(),
-            Value::AvlTree => todo!(),
+            Value::AvlTree(a) => a.sigma_serialize(w),
             Value::Coll(ct) => match ct {
                 Coll::Primitive(CollPrim::CollByte(b)) => {
                     w.put_usize_as_u16(b.len())?;
                     w.write_all(b.clone().as_vec_u8().as_slice())
                 }
+                Coll::NonPrimitive {
+                    elem_tpe: SType::SBoolean,
+                    v,
+                } => {
+                    // booleans are bit-packed
+                    let bools = v
+                        .iter()
+                        .map(|b| match b {
+                            Value::Boolean(b) => Ok(*b),
+                            _ => Err(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                format!("expected Boolean, found {:?}", b),
+                            )),
+                        })
+                        .collect::<Result<Vec<bool>, io::Error>>()?;
+                    w.put_usize_as_u16(bools.len())?;
+                    w.put_bits(bools.as_slice())
+                }
                 Coll::NonPrimitive { elem_tpe: _, v } => {
                     w.put_usize_as_u16(v.len())?;
                     v.iter()
                         .try_for_each(|e| DataSerializer::sigma_serialize(e, w))
                 }
             },
-            Value::Tup(_) => todo!(),
-            Value::Context(_) => todo!(), // TODO: throw error? it should not be here
+            Value::Tup(items) => items
+                .iter()
+                .try_for_each(|i| DataSerializer::sigma_serialize(i, w)),
+            Value::Opt(opt) => match opt.as_ref() {
+                Some(v) => {
+                    w.put_u8(1)?;
+                    DataSerializer::sigma_serialize(v, w)
+                }
+                None => w.put_u8(0),
+            },
+            Value::Context(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "context value cannot be serialized as data",
+            )),
+            Value::Lambda(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "lambda values cannot be serialized as data",
+            )),
         }
     }
 
@@ -59,8 +99,26 @@ impl DataSerializer {
             SShort => Value::Short(r.get_i16()?),
             SInt => Value::Int(r.get_i32()?),
             SLong => Value::Long(r.get_i64()?),
+            SBigInt => {
+                let len = r.get_u16()? as usize;
+                // same as in sigmastate, longer encodings are rejected even for small values
+                if len > BigInteger::MAX_SIZE_BYTES {
+                    return Err(SerializationError::ValueOutOfBounds(format!(
+                        "BigInt encoding is {} bytes long, maximum is {}",
+                        len,
+                        BigInteger::MAX_SIZE_BYTES
+                    )));
+                }
+                let mut buf = vec![0u8; len];
+                r.read_exact(&mut buf)?;
+                Value::BigInt(
+                    BigInteger::from_signed_bytes_be(&buf)
+                        .map_err(|e| SerializationError::ValueOutOfBounds(e.to_string()))?,
+                )
+            }
             SGroupElement => Value::GroupElement(Box::new(EcPoint::sigma_parse(r)?)),
             SSigmaProp => Value::sigma_prop(SigmaProp::new(SigmaBoolean::sigma_parse(r)?)),
+            SAvlTree => Value::AvlTree(Box::new(AvlTreeData::sigma_parse(r)?)),
             SColl(elem_type) if **elem_type == SByte => {
                 let len = r.get_u16()? as usize;
                 let mut buf = vec![0u8; len];
@@ -69,6 +127,14 @@ impl DataSerializer {
                     buf.into_iter().map(|v| v as i8).collect(),
                 )))
             }
+            SColl(elem_type) if **elem_type == SBoolean => {
+                let len = r.get_u16()? as usize;
+                let bools = r.get_bits(len)?;
+                Value::Coll(Coll::NonPrimitive {
+                    elem_tpe: SBoolean,
+                    v: bools.into_iter().map(Value::Boolean).collect(),
+                })
+            }
             SColl(elem_type) => {
                 let len = r.get_u16()? as usize;
                 let mut elems = Vec::with_capacity(len as usize);
@@ -80,6 +146,14 @@ impl DataSerializer {
                     v: elems,
                 })
             }
+            SOption(elem_type) => {
+                let v = if r.get_u8()? != 0 {
+                    Some(DataSerializer::sigma_parse(elem_type, r)?)
+                } else {
+                    None
+                };
+                Value::Opt(Box::new(v))
+            }
             STup(types) => {
                 let mut items = Vec::new();
                 types.iter().try_for_each(|tpe| {
@@ -97,3 +171,43 @@ impl DataSerializer {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncValue;
+    use crate::serialization::constant_store::ConstantStore;
+    use crate::serialization::sigma_byte_reader::SigmaByteReader;
+    use crate::serialization::sigma_byte_writer::SigmaByteWriter;
+    use sigma_ser::peekable_reader::PeekableReader;
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn ser_lambda_fails() {
+        let lambda = Value::Lambda(FuncValue {
+            args: vec![],
+            body: Box::new(Expr::Const(true.into())),
+        });
+        let mut data = Vec::new();
+        let mut w = SigmaByteWriter::new(&mut data, None);
+        assert!(DataSerializer::sigma_serialize(&lambda, &mut w).is_err());
+    }
+
+    #[test]
+    fn parse_big_int_too_long() {
+        // 1 encoded in 33 bytes (with leading zeros)
+        let mut bytes = vec![33u8];
+        bytes.extend(vec![0u8; 32]);
+        bytes.push(1);
+        let mut r = SigmaByteReader::new(
+            PeekableReader::new(Cursor::new(&mut bytes[..])),
+            ConstantStore::empty(),
+        );
+        assert!(matches!(
+            DataSerializer::sigma_parse(&SType::SBigInt, &mut r),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+}
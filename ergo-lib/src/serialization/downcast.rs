@@ -0,0 +1,55 @@
+use std::io::Error;
+
+use crate::ast::downcast::Downcast;
+use crate::ast::expr::Expr;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Downcast {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let tpe = SType::sigma_parse(r)?;
+        Ok(Downcast::new(input, tpe)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::downcast::Downcast;
+    use crate::ast::expr::Expr;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = Downcast::new(Expr::Const(1i64.into()), SType::SInt)
+            .unwrap()
+            .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_widening() {
+        // 1 downcasted to Long
+        let expr: Expr = Downcast {
+            input: Box::new(Expr::Const(1i32.into())),
+            tpe: SType::SLong,
+        }
+        .into();
+        assert!(matches!(
+            Expr::sigma_parse_bytes(expr.sigma_serialize_bytes()),
+            Err(SerializationError::InvalidArgument(_))
+        ));
+    }
+}
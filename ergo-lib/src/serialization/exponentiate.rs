@@ -0,0 +1,46 @@
+use std::io::Error;
+
+use crate::ast::exponentiate::Exponentiate;
+use crate::ast::expr::Expr;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Exponentiate {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.left.sigma_serialize(w)?;
+        self.right.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let left = Expr::sigma_parse(r)?;
+        let right = Expr::sigma_parse(r)?;
+        Ok(Exponentiate {
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::constant::Constant;
+    use crate::ast::exponentiate::Exponentiate;
+    use crate::ast::expr::Expr;
+    use crate::big_integer::BigInteger;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::dlog_group::EcPoint;
+    use crate::test_util::force_any_val;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = Exponentiate {
+            left: Box::new(Constant::from(force_any_val::<EcPoint>()).into()),
+            right: Box::new(Constant::from(BigInteger::from(42i64)).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
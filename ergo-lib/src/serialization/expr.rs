@@ -1,17 +1,73 @@
-use super::{fold::FoldSerializer, op_code::OpCode, sigma_byte_writer::SigmaByteWrite};
+use super::{
+    bin_op::BinOpSerializer, fold::FoldSerializer, method_call::parse_method_call, op_code::OpCode,
+    sigma_byte_writer::SigmaByteWrite,
+};
+use crate::ast::and::And;
+use crate::ast::block::BlockValue;
+use crate::ast::bool_to_sigma::BoolToSigmaProp;
+use crate::ast::box_methods::BoxM;
+use crate::ast::byte_array_to_bigint::ByteArrayToBigInt;
+use crate::ast::calc_blake2b256::CalcBlake2b256;
+use crate::ast::calc_sha256::CalcSha256;
+use crate::ast::coll_append::Append;
+use crate::ast::coll_by_index::ByIndex;
+use crate::ast::coll_exists::Exists;
+use crate::ast::coll_forall::ForAll;
+use crate::ast::coll_map::Map;
 use crate::ast::coll_methods::CollM;
+use crate::ast::coll_size::SizeOf;
+use crate::ast::coll_slice::Slice;
+use crate::ast::collection::Collection;
 use crate::ast::constant::Constant;
 use crate::ast::constant::ConstantPlaceholder;
+use crate::ast::create_provedlog::CreateProveDlog;
+use crate::ast::decode_point::DecodePoint;
+use crate::ast::downcast::Downcast;
+use crate::ast::exponentiate::Exponentiate;
 use crate::ast::expr::Expr;
+use crate::ast::extract_amount::ExtractAmount;
+use crate::ast::extract_bytes::ExtractBytes;
+use crate::ast::extract_bytes_with_no_ref::ExtractBytesWithNoRef;
+use crate::ast::extract_creation_info::ExtractCreationInfo;
+use crate::ast::extract_id::ExtractId;
+use crate::ast::extract_script_bytes::ExtractScriptBytes;
+use crate::ast::func_value::FuncValue;
+use crate::ast::get_var::GetVar;
 use crate::ast::global_vars::GlobalVars;
-use crate::ast::method_call::MethodCall;
+use crate::ast::if_op::If;
+use crate::ast::logical_not::LogicalNot;
+use crate::ast::multiply_group::MultiplyGroup;
+use crate::ast::negation::Negation;
+use crate::ast::ops::{BinOp, BitOp, LogicalOp, NumOp, RelationOp, ShiftOp};
+use crate::ast::option_get::OptionGet;
+use crate::ast::option_get_or_else::OptionGetOrElse;
+use crate::ast::option_is_defined::OptionIsDefined;
+use crate::ast::or::Or;
 use crate::ast::property_call::PropertyCall;
+use crate::ast::select_field::SelectField;
+use crate::ast::sigma_and::SigmaAnd;
+use crate::ast::sigma_atleast::Atleast;
+use crate::ast::sigma_or::SigmaOr;
+use crate::ast::subst_constants::SubstConstants;
+use crate::ast::tuple::Tuple;
+use crate::ast::upcast::Upcast;
+use crate::ast::val_def::ValDef;
+use crate::ast::val_use::ValUse;
+use crate::ast::xor::Xor;
+use crate::ast::xor_of::XorOf;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
 };
 
 use std::io;
 
+/// Check that the type of the parsed node can be derived from its children types
+/// (so that [`Expr::tpe`] does not panic on it)
+fn checked_tpe(expr: Expr) -> Result<Expr, SerializationError> {
+    expr.try_tpe()?;
+    Ok(expr)
+}
+
 impl SigmaSerializable for Expr {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         match self {
@@ -35,6 +91,55 @@ impl SigmaSerializable for Expr {
                     Expr::MethodCall(mc) => mc.sigma_serialize(w),
                     Expr::ProperyCall(pc) => pc.sigma_serialize(w),
                     Expr::Context => Ok(()),
+                    Expr::Collection(v) => v.sigma_serialize(w),
+                    Expr::Append(v) => v.sigma_serialize(w),
+                    Expr::Exists(v) => v.sigma_serialize(w),
+                    Expr::ForAll(v) => v.sigma_serialize(w),
+                    Expr::CalcSha256(v) => v.sigma_serialize(w),
+                    Expr::DecodePoint(v) => v.sigma_serialize(w),
+                    Expr::Upcast(v) => v.sigma_serialize(w),
+                    Expr::ValUse(v) => v.sigma_serialize(w),
+                    Expr::FuncValue(v) => v.sigma_serialize(w),
+                    Expr::Slice(v) => v.sigma_serialize(w),
+                    Expr::Map(v) => v.sigma_serialize(w),
+                    Expr::BoxM(v) => v.sigma_serialize(w),
+                    Expr::ByteArrayToBigInt(v) => v.sigma_serialize(w),
+                    Expr::ByIndex(bi) => bi.sigma_serialize(w),
+                    Expr::SelectField(v) => v.sigma_serialize(w),
+                    Expr::ExtractAmount(v) => v.sigma_serialize(w),
+                    Expr::ExtractScriptBytes(v) => v.sigma_serialize(w),
+                    Expr::GetVar(v) => v.sigma_serialize(w),
+                    Expr::OptionGet(v) => v.sigma_serialize(w),
+                    Expr::OptionGetOrElse(v) => v.sigma_serialize(w),
+                    Expr::OptionIsDefined(v) => v.sigma_serialize(w),
+                    Expr::OptionMap(v) => v.sigma_serialize(w),
+                    Expr::OptionFilter(v) => v.sigma_serialize(w),
+                    Expr::And(v) => v.sigma_serialize(w),
+                    Expr::Or(v) => v.sigma_serialize(w),
+                    Expr::SigmaAnd(v) => v.sigma_serialize(w),
+                    Expr::Atleast(v) => v.sigma_serialize(w),
+                    Expr::SigmaOr(v) => v.sigma_serialize(w),
+                    Expr::BoolToSigmaProp(v) => v.sigma_serialize(w),
+                    Expr::ExtractCreationInfo(v) => v.sigma_serialize(w),
+                    Expr::SubstConstants(v) => v.sigma_serialize(w),
+                    Expr::CreateProveDlog(v) => v.sigma_serialize(w),
+                    Expr::SizeOf(v) => v.sigma_serialize(w),
+                    Expr::If(v) => v.sigma_serialize(w),
+                    Expr::ValDef(v) => v.sigma_serialize(w),
+                    Expr::BlockValue(v) => v.sigma_serialize(w),
+                    Expr::ExtractId(v) => v.sigma_serialize(w),
+                    Expr::LogicalNot(v) => v.sigma_serialize(w),
+                    Expr::CalcBlake2b256(v) => v.sigma_serialize(w),
+                    Expr::Negation(v) => v.sigma_serialize(w),
+                    Expr::Xor(v) => v.sigma_serialize(w),
+                    Expr::ExtractBytes(v) => v.sigma_serialize(w),
+                    Expr::ExtractBytesWithNoRef(v) => v.sigma_serialize(w),
+                    Expr::Tuple(v) => v.sigma_serialize(w),
+                    Expr::Downcast(v) => v.sigma_serialize(w),
+                    Expr::XorOf(v) => v.sigma_serialize(w),
+                    Expr::BinOp(..) => BinOpSerializer::sigma_serialize(expr, w),
+                    Expr::Exponentiate(v) => v.sigma_serialize(w),
+                    Expr::MultiplyGroup(v) => v.sigma_serialize(w),
                     _ => panic!(format!("don't know how to serialize {:?}", expr)),
                 }
             }
@@ -55,6 +160,13 @@ impl SigmaSerializable for Expr {
             Ok(Expr::Const(constant))
         } else {
             let op_code = OpCode::sigma_parse(r)?;
+            if op_code.min_tree_version() > r.tree_version() {
+                return Err(SerializationError::OpCodeNotSupportedInVersion {
+                    op_code: op_code.value(),
+                    min_version: op_code.min_tree_version(),
+                    tree_version: r.tree_version(),
+                });
+            }
             match op_code {
                 FoldSerializer::OP_CODE => FoldSerializer::sigma_parse(r),
                 ConstantPlaceholder::OP_CODE => {
@@ -72,9 +184,107 @@ impl SigmaSerializable for Expr {
                 OpCode::SELF_BOX => Ok(Expr::GlobalVars(GlobalVars::SelfBox)),
                 OpCode::INPUTS => Ok(Expr::GlobalVars(GlobalVars::Inputs)),
                 OpCode::OUTPUTS => Ok(Expr::GlobalVars(GlobalVars::Outputs)),
-                OpCode::PROPERTY_CALL => Ok(Expr::ProperyCall(PropertyCall::sigma_parse(r)?)),
-                OpCode::METHOD_CALL => Ok(Expr::MethodCall(MethodCall::sigma_parse(r)?)),
+                OpCode::MINER_PUBKEY => Ok(Expr::GlobalVars(GlobalVars::MinerPubKey)),
+                OpCode::PROPERTY_CALL => {
+                    checked_tpe(Expr::ProperyCall(PropertyCall::sigma_parse(r)?))
+                }
+                OpCode::METHOD_CALL => checked_tpe(parse_method_call(r)?),
                 OpCode::CONTEXT => Ok(Expr::Context),
+                OpCode::GET_VAR => Ok(Expr::GetVar(GetVar::sigma_parse(r)?)),
+                OpCode::OPTION_GET => checked_tpe(Expr::OptionGet(OptionGet::sigma_parse(r)?)),
+                OpCode::OPTION_GET_OR_ELSE => {
+                    Ok(Expr::OptionGetOrElse(OptionGetOrElse::sigma_parse(r)?))
+                }
+                OpCode::OPTION_IS_DEFINED => {
+                    Ok(Expr::OptionIsDefined(OptionIsDefined::sigma_parse(r)?))
+                }
+                OpCode::EQ => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::Eq), r),
+                OpCode::NEQ => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::NEq), r),
+                OpCode::LT => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::Lt), r),
+                OpCode::LE => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::Le), r),
+                OpCode::GT => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::Gt), r),
+                OpCode::GE => BinOpSerializer::sigma_parse(BinOp::Relation(RelationOp::Ge), r),
+                OpCode::BIN_AND => BinOpSerializer::sigma_parse(BinOp::Logical(LogicalOp::And), r),
+                OpCode::BIN_OR => BinOpSerializer::sigma_parse(BinOp::Logical(LogicalOp::Or), r),
+                OpCode::BIN_XOR => BinOpSerializer::sigma_parse(BinOp::Logical(LogicalOp::Xor), r),
+                OpCode::BIT_OR => BinOpSerializer::sigma_parse(BinOp::Bit(BitOp::Or), r),
+                OpCode::BIT_AND => BinOpSerializer::sigma_parse(BinOp::Bit(BitOp::And), r),
+                OpCode::BIT_XOR => BinOpSerializer::sigma_parse(BinOp::Bit(BitOp::Xor), r),
+                OpCode::BIT_SHIFT_RIGHT => {
+                    BinOpSerializer::sigma_parse(BinOp::Shift(ShiftOp::Right), r)
+                }
+                OpCode::BIT_SHIFT_LEFT => {
+                    BinOpSerializer::sigma_parse(BinOp::Shift(ShiftOp::Left), r)
+                }
+                OpCode::BIT_SHIFT_RIGHT_ZEROED => {
+                    BinOpSerializer::sigma_parse(BinOp::Shift(ShiftOp::RightZeroed), r)
+                }
+                OpCode::PLUS => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Add), r),
+                OpCode::MINUS => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Subtract), r),
+                OpCode::MULTIPLY => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Multiply), r),
+                OpCode::DIVISION => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Divide), r),
+                OpCode::MODULO => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Modulo), r),
+                OpCode::MIN => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Min), r),
+                OpCode::MAX => BinOpSerializer::sigma_parse(BinOp::Num(NumOp::Max), r),
+                OpCode::EXPONENTIATE => Ok(Expr::Exponentiate(Exponentiate::sigma_parse(r)?)),
+                OpCode::MULTIPLY_GROUP => Ok(Expr::MultiplyGroup(MultiplyGroup::sigma_parse(r)?)),
+                OpCode::VAL_USE => Ok(Expr::ValUse(ValUse::sigma_parse(r)?)),
+                OpCode::FUNC_VALUE => Ok(Expr::FuncValue(FuncValue::sigma_parse(r)?)),
+                OpCode::MAP_COLLECTION => checked_tpe(Expr::Map(Map::sigma_parse(r)?)),
+                OpCode::EXISTS => Ok(Expr::Exists(Exists::sigma_parse(r)?)),
+                OpCode::FOR_ALL => Ok(Expr::ForAll(ForAll::sigma_parse(r)?)),
+                OpCode::COLL => Ok(Expr::Collection(Collection::sigma_parse(r)?)),
+                OpCode::COLL_OF_BOOL_CONST => {
+                    Ok(Expr::Collection(Collection::sigma_parse_bool_constants(r)?))
+                }
+                OpCode::APPEND => Ok(Expr::Append(Append::sigma_parse(r)?)),
+                OpCode::SLICE => Ok(Expr::Slice(Slice::sigma_parse(r)?)),
+                OpCode::BY_INDEX => checked_tpe(Expr::ByIndex(ByIndex::sigma_parse(r)?)),
+                OpCode::SELECT_FIELD => Ok(Expr::SelectField(SelectField::sigma_parse(r)?)),
+                OpCode::EXTRACT_AMOUNT => Ok(Expr::ExtractAmount(ExtractAmount::sigma_parse(r)?)),
+                OpCode::EXTRACT_SCRIPT_BYTES => Ok(Expr::ExtractScriptBytes(
+                    ExtractScriptBytes::sigma_parse(r)?,
+                )),
+                OpCode::BYTE_ARRAY_TO_BIGINT => {
+                    Ok(Expr::ByteArrayToBigInt(ByteArrayToBigInt::sigma_parse(r)?))
+                }
+                OpCode::EXTRACT_REGISTER_AS => Ok(Expr::BoxM(BoxM::sigma_parse(r)?)),
+                OpCode::CALC_SHA256 => Ok(Expr::CalcSha256(CalcSha256::sigma_parse(r)?)),
+                OpCode::DECODE_POINT => Ok(Expr::DecodePoint(DecodePoint::sigma_parse(r)?)),
+                OpCode::UPCAST => Ok(Expr::Upcast(Upcast::sigma_parse(r)?)),
+                OpCode::AND => Ok(Expr::And(And::sigma_parse(r)?)),
+                OpCode::OR => Ok(Expr::Or(Or::sigma_parse(r)?)),
+                OpCode::SIGMA_AND => Ok(Expr::SigmaAnd(SigmaAnd::sigma_parse(r)?)),
+                OpCode::ATLEAST => Ok(Expr::Atleast(Atleast::sigma_parse(r)?)),
+                OpCode::SIGMA_OR => Ok(Expr::SigmaOr(SigmaOr::sigma_parse(r)?)),
+                OpCode::BOOL_TO_SIGMA_PROP => {
+                    Ok(Expr::BoolToSigmaProp(BoolToSigmaProp::sigma_parse(r)?))
+                }
+                OpCode::EXTRACT_CREATION_INFO => Ok(Expr::ExtractCreationInfo(
+                    ExtractCreationInfo::sigma_parse(r)?,
+                )),
+                OpCode::SUBST_CONSTANTS => {
+                    Ok(Expr::SubstConstants(SubstConstants::sigma_parse(r)?))
+                }
+                OpCode::PROVE_DLOG => Ok(Expr::CreateProveDlog(CreateProveDlog::sigma_parse(r)?)),
+                OpCode::SIZE_OF => Ok(Expr::SizeOf(SizeOf::sigma_parse(r)?)),
+                OpCode::IF => Ok(Expr::If(If::sigma_parse(r)?)),
+                OpCode::VAL_DEF => Ok(Expr::ValDef(ValDef::sigma_parse(r)?)),
+                OpCode::BLOCK_VALUE => Ok(Expr::BlockValue(BlockValue::sigma_parse(r)?)),
+                OpCode::EXTRACT_ID => Ok(Expr::ExtractId(ExtractId::sigma_parse(r)?)),
+                OpCode::LOGICAL_NOT => Ok(Expr::LogicalNot(LogicalNot::sigma_parse(r)?)),
+                OpCode::CALC_BLAKE2B256 => {
+                    Ok(Expr::CalcBlake2b256(CalcBlake2b256::sigma_parse(r)?))
+                }
+                OpCode::NEGATION => Ok(Expr::Negation(Negation::sigma_parse(r)?)),
+                OpCode::XOR => Ok(Expr::Xor(Xor::sigma_parse(r)?)),
+                OpCode::EXTRACT_BYTES => Ok(Expr::ExtractBytes(ExtractBytes::sigma_parse(r)?)),
+                OpCode::EXTRACT_BYTES_WITH_NO_REF => Ok(Expr::ExtractBytesWithNoRef(
+                    ExtractBytesWithNoRef::sigma_parse(r)?,
+                )),
+                OpCode::TUPLE => Ok(Expr::Tuple(Tuple::sigma_parse(r)?)),
+                OpCode::DOWNCAST => Ok(Expr::Downcast(Downcast::sigma_parse(r)?)),
+                OpCode::XOR_OF => Ok(Expr::XorOf(XorOf::sigma_parse(r)?)),
                 o => Err(SerializationError::NotImplementedOpCode(o.value())),
             }
         }
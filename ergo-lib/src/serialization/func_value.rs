@@ -0,0 +1,85 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::func_value::FuncArg;
+use crate::ast::func_value::FuncValue;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for FuncArg {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.idx)?;
+        self.tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let idx = r.get_u32()?;
+        let tpe = SType::sigma_parse(r)?;
+        Ok(FuncArg { idx, tpe })
+    }
+}
+
+impl SigmaSerializable for FuncValue {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.args.len() as u32)?;
+        for arg in &self.args {
+            arg.sigma_serialize(w)?;
+        }
+        self.body.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let args_count = r.get_u32()?;
+        let mut args = Vec::with_capacity(args_count as usize);
+        for _ in 0..args_count {
+            let arg = FuncArg::sigma_parse(r)?;
+            // make the argument types known to the ValUse nodes in the body
+            r.val_def_type_store().insert(arg.idx, arg.tpe.clone());
+            args.push(arg);
+        }
+        let body = Expr::sigma_parse(r)?;
+        Ok(FuncValue {
+            args,
+            body: Box::new(body),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        // (x: Int) => x == 1
+        let expr: Expr = FuncValue {
+            args: vec![FuncArg {
+                idx: 1,
+                tpe: SType::SInt,
+            }],
+            body: Box::new(Expr::BinOp(
+                BinOp::Relation(RelationOp::Eq),
+                Box::new(
+                    ValUse {
+                        val_id: 1,
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                Box::new(Expr::Const(1i32.into())),
+            )),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,40 @@
+use std::io::Error;
+
+use crate::ast::get_var::GetVar;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for GetVar {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u8(self.var_id)?;
+        self.var_tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let var_id = r.get_u8()?;
+        let var_tpe = SType::sigma_parse(r)?;
+        Ok(GetVar { var_id, var_tpe })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SLong,
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,53 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::if_op::If;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for If {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.condition.sigma_serialize(w)?;
+        self.true_branch.sigma_serialize(w)?;
+        self.false_branch.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let condition = Expr::sigma_parse(r)?;
+        let true_branch = Expr::sigma_parse(r)?;
+        let false_branch = Expr::sigma_parse(r)?;
+        Ok(If {
+            condition: Box::new(condition),
+            true_branch: Box::new(true_branch),
+            false_branch: Box::new(false_branch),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::if_op::If;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        // if (HEIGHT > 100) 1L else 2L
+        let expr: Expr = If {
+            condition: Box::new(Expr::BinOp(
+                BinOp::Relation(RelationOp::Gt),
+                Box::new(GlobalVars::Height.into()),
+                Box::new(Expr::Const(100i32.into())),
+            )),
+            true_branch: Box::new(Expr::Const(1i64.into())),
+            false_branch: Box::new(Expr::Const(2i64.into())),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,45 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::logical_not::LogicalNot;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for LogicalNot {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(LogicalNot {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::logical_not::LogicalNot;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        // !(HEIGHT > 100)
+        let expr: Expr = LogicalNot {
+            input: Box::new(Expr::BinOp(
+                BinOp::Relation(RelationOp::Gt),
+                Box::new(GlobalVars::Height.into()),
+                Box::new(Expr::Const(100i32.into())),
+            )),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -2,8 +2,11 @@ use std::io::Error;
 
 use crate::ast::expr::Expr;
 use crate::ast::method_call::MethodCall;
+use crate::ast::option_filter::OptionFilter;
+use crate::ast::option_map::OptionMap;
 use crate::types::smethod::MethodId;
 use crate::types::smethod::SMethod;
+use crate::types::soption;
 use crate::types::stype_companion::TypeId;
 
 use super::sigma_byte_reader::SigmaByteRead;
@@ -22,22 +25,62 @@ impl SigmaSerializable for MethodCall {
     }
 
     fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
-        let type_id = TypeId::sigma_parse(r)?;
-        let method_id = MethodId::sigma_parse(r)?;
-        let obj = Expr::sigma_parse(r)?;
-        let args_count = r.get_u32()?;
-        let mut args = Vec::with_capacity(args_count as usize);
-        for _ in 0..args_count {
-            args.push(Expr::sigma_parse(r)?);
+        match parse_method_call(r)? {
+            Expr::MethodCall(mc) => Ok(mc),
+            expr => Err(SerializationError::Misc(format!(
+                "expected MethodCall, got {:?}",
+                expr
+            ))),
         }
-        Ok(MethodCall {
-            obj: Box::new(obj),
-            method: SMethod::from_ids(type_id, method_id),
-            args,
-        })
     }
 }
 
+/// Parse method call (after the op code), `SOption.map` and `SOption.filter` calls are parsed
+/// into the dedicated nodes
+pub(crate) fn parse_method_call<R: SigmaByteRead>(r: &mut R) -> Result<Expr, SerializationError> {
+    let type_id = TypeId::sigma_parse(r)?;
+    let method_id = MethodId::sigma_parse(r)?;
+    let obj = Expr::sigma_parse(r)?;
+    let args_count = r.get_u32()?;
+    let mut args = Vec::with_capacity(args_count as usize);
+    for _ in 0..args_count {
+        args.push(Expr::sigma_parse(r)?);
+    }
+    if type_id == soption::TYPE_ID {
+        let func = match args.pop() {
+            Some(func) if args.is_empty() => Box::new(func),
+            _ => {
+                return Err(SerializationError::Misc(format!(
+                    "expected one argument for SOption method {:?}, got {}",
+                    method_id, args_count
+                )))
+            }
+        };
+        return match method_id {
+            soption::MAP_METHOD_ID => Ok(OptionMap {
+                input: Box::new(obj),
+                mapper: func,
+            }
+            .into()),
+            soption::FILTER_METHOD_ID => Ok(OptionFilter {
+                input: Box::new(obj),
+                condition: func,
+            }
+            .into()),
+            _ => Err(SerializationError::NotImplementedYet(format!(
+                "SOption method {:?}",
+                method_id
+            ))),
+        };
+    }
+    Ok(MethodCall {
+        obj: Box::new(obj),
+        method: SMethod::from_ids(type_id, method_id),
+        args,
+    }
+    .into())
+}
+
 #[cfg(test)]
 mod tests {
     // use crate::ast::expr::Expr;
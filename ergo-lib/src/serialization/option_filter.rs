@@ -0,0 +1,68 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::option_filter::OptionFilter;
+use crate::types::soption;
+
+use super::method_call::parse_method_call;
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for OptionFilter {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        soption::TYPE_ID.sigma_serialize(w)?;
+        soption::FILTER_METHOD_ID.sigma_serialize(w)?;
+        self.input.sigma_serialize(w)?;
+        w.put_u32(1)?;
+        self.condition.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        match parse_method_call(r)? {
+            Expr::OptionFilter(v) => Ok(v),
+            expr => Err(SerializationError::Misc(format!(
+                "expected OptionFilter, got {:?}",
+                expr
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::option_filter::OptionFilter;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        // Some(1L).filter { (x: Long) => true }
+        let expr: Expr = OptionFilter {
+            input: Box::new(
+                Constant {
+                    tpe: SType::SOption(Box::new(SType::SLong)),
+                    v: Some(1i64).into(),
+                }
+                .into(),
+            ),
+            condition: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SLong,
+                    }],
+                    body: Box::new(Constant::from(true).into()),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::option_get::OptionGet;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for OptionGet {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        Ok(OptionGet {
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::option_get::OptionGet;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = OptionGet {
+            input: Box::new(
+                GetVar {
+                    var_id: 1,
+                    var_tpe: SType::SLong,
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_non_option_input() {
+        // HEIGHT.get
+        let expr: Expr = OptionGet {
+            input: Box::new(GlobalVars::Height.into()),
+        }
+        .into();
+        assert!(matches!(
+            Expr::sigma_parse_bytes(expr.sigma_serialize_bytes()),
+            Err(SerializationError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::option_get_or_else::OptionGetOrElse;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for OptionGetOrElse {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.default.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let default = Expr::sigma_parse(r)?;
+        Ok(OptionGetOrElse::new(input, default)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::ast::option_get_or_else::OptionGetOrElse;
+    use crate::serialization::op_code::OpCode;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = OptionGetOrElse::new(
+            GetVar {
+                var_id: 1,
+                var_tpe: SType::SLong,
+            }
+            .into(),
+            Expr::Const(1i64.into()),
+        )
+        .unwrap()
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_default_type_mismatch() {
+        let input: Expr = GetVar {
+            var_id: 1,
+            var_tpe: SType::SLong,
+        }
+        .into();
+        let mut bytes = vec![OpCode::OPTION_GET_OR_ELSE.value()];
+        bytes.extend(input.sigma_serialize_bytes());
+        bytes.extend(Expr::Const(1i32.into()).sigma_serialize_bytes());
+        assert!(Expr::sigma_parse_bytes(bytes).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::option_map::OptionMap;
+use crate::types::soption;
+
+use super::method_call::parse_method_call;
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for OptionMap {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        soption::TYPE_ID.sigma_serialize(w)?;
+        soption::MAP_METHOD_ID.sigma_serialize(w)?;
+        self.input.sigma_serialize(w)?;
+        w.put_u32(1)?;
+        self.mapper.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        match parse_method_call(r)? {
+            Expr::OptionMap(v) => Ok(v),
+            expr => Err(SerializationError::Misc(format!(
+                "expected OptionMap, got {:?}",
+                expr
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::box_methods::BoxM;
+    use crate::ast::box_methods::RegisterId;
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::func_value::FuncValue;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::option_map::OptionMap;
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        // SELF.R4[Int].map { (x: Int) => x }
+        let expr: Expr = OptionMap {
+            input: Box::new(
+                BoxM::ExtractRegisterAs {
+                    input: Box::new(GlobalVars::SelfBox.into()),
+                    register_id: RegisterId(4),
+                    tpe: SType::SInt,
+                }
+                .into(),
+            ),
+            mapper: Box::new(
+                FuncValue {
+                    args: vec![FuncArg {
+                        idx: 1,
+                        tpe: SType::SInt,
+                    }],
+                    body: Box::new(
+                        ValUse {
+                            val_id: 1,
+                            tpe: SType::SInt,
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -33,8 +33,11 @@ impl SigmaSerializable for PropertyCall {
 #[cfg(test)]
 mod tests {
     use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
     use crate::ast::property_call::PropertyCall;
     use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::sbox;
     use crate::types::scontext;
 
     #[test]
@@ -46,4 +49,28 @@ mod tests {
         let expr = Expr::ProperyCall(mc);
         assert_eq![sigma_serialize_roundtrip(&expr), expr];
     }
+
+    #[test]
+    fn ser_context_data_inputs_bytes() {
+        // CONTEXT.dataInputs as serialized by sigmastate
+        // (PropertyCall op code, SContext type id, method id, CONTEXT op code)
+        let expr: Expr = PropertyCall {
+            obj: Box::new(Expr::Context),
+            method: scontext::DATA_INPUTS_PROPERTY.clone(),
+        }
+        .into();
+        let bytes = vec![0xdb, 0x65, 0x01, 0xfe];
+        assert_eq!(expr.sigma_serialize_bytes(), bytes);
+        assert_eq!(Expr::sigma_parse_bytes(bytes).unwrap(), expr);
+    }
+
+    #[test]
+    fn ser_roundtrip_box_tokens() {
+        let mc = PropertyCall {
+            obj: Box::new(GlobalVars::SelfBox.into()),
+            method: sbox::TOKENS_PROPERTY.clone(),
+        };
+        let expr = Expr::ProperyCall(mc);
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
 }
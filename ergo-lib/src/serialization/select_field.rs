@@ -0,0 +1,48 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::select_field::SelectField;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for SelectField {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        w.put_u8(self.field_index)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let field_index = r.get_u8()?;
+        Ok(SelectField::new(input, field_index)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::select_field::SelectField;
+    use crate::serialization::op_code::OpCode;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SigmaSerializable;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = SelectField::new(Expr::Const((1i32, 2i64).into()), 2)
+            .unwrap()
+            .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_field_index_out_of_bounds() {
+        // (1, 2L)._3
+        let mut bytes = Expr::Const((1i32, 2i64).into()).sigma_serialize_bytes();
+        bytes.insert(0, OpCode::SELECT_FIELD.value());
+        bytes.push(3);
+        assert!(Expr::sigma_parse_bytes(bytes).is_err());
+    }
+}
@@ -4,6 +4,7 @@ use super::{
     sigma_byte_reader::{SigmaByteRead, SigmaByteReader},
     sigma_byte_writer::{SigmaByteWrite, SigmaByteWriter},
 };
+use crate::ast::expr::InvalidArgumentError;
 use io::Cursor;
 use sigma_ser::{peekable_reader::PeekableReader, vlq_encode};
 use std::io;
@@ -18,6 +19,16 @@ pub enum SerializationError {
     /// Lacking support for the op
     #[error("not implemented op error")]
     NotImplementedOpCode(u8),
+    /// Op code is not supported by the version of the ErgoTree being parsed
+    #[error("op code {op_code} requires ErgoTree version {min_version}, got {tree_version}")]
+    OpCodeNotSupportedInVersion {
+        /// Op code
+        op_code: u8,
+        /// Minimal ErgoTree version that supports the op code
+        min_version: u8,
+        /// Version of the ErgoTree being parsed
+        tree_version: u8,
+    },
     /// Failed to parse type
     #[error("type parsing error")]
     InvalidTypePrefix,
@@ -39,6 +50,12 @@ pub enum SerializationError {
     /// Value out of bounds
     #[error("Value out of bounds: {0}")]
     ValueOutOfBounds(String),
+    /// Type of the value with the given id is unknown (the value is not bound)
+    #[error("Value with id {0} is not defined")]
+    ValDefIdNotFound(u32),
+    /// Invalid arguments on the node construction
+    #[error("invalid argument: {0}")]
+    InvalidArgument(#[from] InvalidArgumentError),
 }
 
 impl From<vlq_encode::VlqEncodingError> for SerializationError {
@@ -0,0 +1,47 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::sigma_and::SigmaAnd;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for SigmaAnd {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.items.len() as u32)?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let items_count = r.get_u32()?;
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        Ok(SigmaAnd { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::sigma_and::SigmaAnd;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::sigma_boolean::SigmaProp;
+    use crate::test_util::force_any_val;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = SigmaAnd {
+            items: vec![
+                Constant::from(force_any_val::<SigmaProp>()).into(),
+                Constant::from(force_any_val::<SigmaProp>()).into(),
+            ],
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
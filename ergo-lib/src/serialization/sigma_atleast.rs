@@ -0,0 +1,50 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::sigma_atleast::Atleast;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Atleast {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.bound.sigma_serialize(w)?;
+        self.input.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let bound = Expr::sigma_parse(r)?;
+        let input = Expr::sigma_parse(r)?;
+        Ok(Atleast {
+            bound: Box::new(bound),
+            input: Box::new(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::sigma_atleast::Atleast;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::sigma_boolean::SigmaProp;
+    use crate::test_util::force_any_val;
+
+    #[test]
+    fn ser_roundtrip() {
+        let props = vec![
+            force_any_val::<SigmaProp>(),
+            force_any_val::<SigmaProp>(),
+            force_any_val::<SigmaProp>(),
+        ];
+        let expr: Expr = Atleast {
+            bound: Box::new(Constant::from(2i32).into()),
+            input: Box::new(Constant::from(props).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -1,5 +1,7 @@
 //! Sigma byte stream writer
 use super::constant_store::ConstantStore;
+use super::val_def_type_store::ValDefTypeStore;
+use crate::ergo_tree::ErgoTree;
 use sigma_ser::{peekable_reader::Peekable, vlq_encode::ReadSigmaVlqExt};
 use std::io::Read;
 
@@ -8,6 +10,9 @@ pub struct SigmaByteReader<R> {
     inner: R,
     constant_store: ConstantStore,
     substitute_placeholders: bool,
+    tree_version: u8,
+    position: u64,
+    val_def_type_store: ValDefTypeStore,
 }
 
 impl<R: Peekable> SigmaByteReader<R> {
@@ -17,6 +22,9 @@ impl<R: Peekable> SigmaByteReader<R> {
             inner: pr,
             constant_store,
             substitute_placeholders: false,
+            tree_version: ErgoTree::MAX_VERSION,
+            position: 0,
+            val_def_type_store: ValDefTypeStore::new(),
         }
     }
 
@@ -30,6 +38,9 @@ impl<R: Peekable> SigmaByteReader<R> {
             inner: pr,
             constant_store,
             substitute_placeholders: true,
+            tree_version: ErgoTree::MAX_VERSION,
+            position: 0,
+            val_def_type_store: ValDefTypeStore::new(),
         }
     }
 }
@@ -44,11 +55,25 @@ pub trait SigmaByteRead: ReadSigmaVlqExt {
 
     /// Set new constant store
     fn set_constant_store(&mut self, constant_store: ConstantStore);
+
+    /// Version of the ErgoTree being parsed (defaults to [`ErgoTree::MAX_VERSION`])
+    fn tree_version(&self) -> u8;
+
+    /// Set version of the ErgoTree being parsed
+    fn set_tree_version(&mut self, tree_version: u8);
+
+    /// Types of the values bound so far (used to parse ValUse)
+    fn val_def_type_store(&mut self) -> &mut ValDefTypeStore;
+
+    /// Number of bytes read so far
+    fn position(&self) -> u64;
 }
 
 impl<R: Peekable> Read for SigmaByteReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        self.inner.read(buf)
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
     }
 }
 
@@ -70,4 +95,20 @@ impl<R: ReadSigmaVlqExt> SigmaByteRead for SigmaByteReader<R> {
     fn set_constant_store(&mut self, constant_store: ConstantStore) {
         self.constant_store = constant_store;
     }
+
+    fn tree_version(&self) -> u8 {
+        self.tree_version
+    }
+
+    fn set_tree_version(&mut self, tree_version: u8) {
+        self.tree_version = tree_version;
+    }
+
+    fn val_def_type_store(&mut self) -> &mut ValDefTypeStore {
+        &mut self.val_def_type_store
+    }
+
+    fn position(&self) -> u64 {
+        self.position
+    }
 }
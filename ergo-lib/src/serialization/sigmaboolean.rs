@@ -4,7 +4,7 @@ use crate::serialization::{
 };
 use crate::sigma_protocol::{
     dlog_group::EcPoint,
-    sigma_boolean::{ProveDlog, SigmaBoolean, SigmaProofOfKnowledgeTree},
+    sigma_boolean::{ProveDHTuple, ProveDlog, SigmaBoolean, SigmaProofOfKnowledgeTree},
 };
 
 use std::io;
@@ -14,10 +14,15 @@ impl SigmaSerializable for SigmaBoolean {
         self.op_code().sigma_serialize(w)?;
         match self {
             SigmaBoolean::ProofOfKnowledge(proof) => match proof {
-                SigmaProofOfKnowledgeTree::ProveDHTuple { .. } => todo!(),
+                SigmaProofOfKnowledgeTree::ProveDHTuple(v) => v.sigma_serialize(w),
                 SigmaProofOfKnowledgeTree::ProveDlog(v) => v.sigma_serialize(w),
             },
-            SigmaBoolean::CAND(_) => todo!(),
+            SigmaBoolean::CAND(items) | SigmaBoolean::COR(items) => serialize_items(items, w),
+            SigmaBoolean::CTHRESHOLD { k, children } => {
+                // k is written as u16 in sigmastate
+                w.put_u16(*k as u16)?;
+                serialize_items(children, w)
+            }
             SigmaBoolean::TrivialProp(_) => Ok(()), // besides opCode no additional bytes
         }
     }
@@ -28,11 +33,49 @@ impl SigmaSerializable for SigmaBoolean {
             OpCode::PROVE_DLOG => Ok(SigmaBoolean::ProofOfKnowledge(
                 SigmaProofOfKnowledgeTree::ProveDlog(ProveDlog::sigma_parse(r)?),
             )),
-            _ => todo!(),
+            OpCode::PROVE_DIFFIE_HELLMAN_TUPLE => Ok(SigmaBoolean::ProofOfKnowledge(
+                SigmaProofOfKnowledgeTree::ProveDHTuple(ProveDHTuple::sigma_parse(r)?),
+            )),
+            OpCode::TRIVIAL_PROP_TRUE => Ok(SigmaBoolean::TrivialProp(true)),
+            OpCode::TRIVIAL_PROP_FALSE => Ok(SigmaBoolean::TrivialProp(false)),
+            OpCode::AND => Ok(SigmaBoolean::CAND(parse_items(r)?)),
+            OpCode::OR => Ok(SigmaBoolean::COR(parse_items(r)?)),
+            OpCode::ATLEAST => {
+                let k = r.get_u16()?;
+                if k > u8::MAX as u16 {
+                    return Err(SerializationError::ValueOutOfBounds(format!(
+                        "CTHRESHOLD k: {}",
+                        k
+                    )));
+                }
+                let children = parse_items(r)?;
+                Ok(SigmaBoolean::CTHRESHOLD {
+                    k: k as u8,
+                    children,
+                })
+            }
+            o => Err(SerializationError::Misc(format!(
+                "unexpected op code in SigmaBoolean: {}",
+                o.value()
+            ))),
         }
     }
 }
 
+fn serialize_items<W: SigmaByteWrite>(items: &[SigmaBoolean], w: &mut W) -> Result<(), io::Error> {
+    w.put_u16(items.len() as u16)?;
+    items.iter().try_for_each(|i| i.sigma_serialize(w))
+}
+
+fn parse_items<R: SigmaByteRead>(r: &mut R) -> Result<Vec<SigmaBoolean>, SerializationError> {
+    let items_count = r.get_u16()?;
+    let mut items = Vec::with_capacity(items_count as usize);
+    for _ in 0..items_count {
+        items.push(SigmaBoolean::sigma_parse(r)?);
+    }
+    Ok(items)
+}
+
 impl SigmaSerializable for ProveDlog {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         self.h.sigma_serialize(w)
@@ -43,3 +86,100 @@ impl SigmaSerializable for ProveDlog {
         Ok(ProveDlog::new(p))
     }
 }
+
+impl SigmaSerializable for ProveDHTuple {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
+        self.gv().sigma_serialize(w)?;
+        self.hv().sigma_serialize(w)?;
+        self.uv().sigma_serialize(w)?;
+        self.vv().sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let gv = EcPoint::sigma_parse(r)?;
+        let hv = EcPoint::sigma_parse(r)?;
+        let uv = EcPoint::sigma_parse(r)?;
+        let vv = EcPoint::sigma_parse(r)?;
+        Ok(ProveDHTuple::new(gv, hv, uv, vv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::dlog_group;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn arb_sigma_tree() -> impl Strategy<Value = SigmaBoolean> {
+        let leaf = prop_oneof![
+            any::<bool>().prop_map(SigmaBoolean::TrivialProp),
+            any::<ProveDlog>().prop_map(SigmaBoolean::from),
+            any::<[EcPoint; 4]>()
+                .prop_map(|[g, h, u, v]| SigmaBoolean::from(ProveDHTuple::new(g, h, u, v))),
+        ];
+        // depth 3, up to 16 nodes, up to 4 children per node
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                vec(inner.clone(), 1..4).prop_map(SigmaBoolean::CAND),
+                vec(inner.clone(), 1..4).prop_map(SigmaBoolean::COR),
+                vec(inner, 1..4).prop_flat_map(|children| {
+                    (1..=children.len() as u8).prop_map(move |k| SigmaBoolean::CTHRESHOLD {
+                        k,
+                        children: children.clone(),
+                    })
+                }),
+            ]
+        })
+    }
+
+    proptest! {
+
+        #[test]
+        fn ser_roundtrip(v in arb_sigma_tree()) {
+            let bytes = v.sigma_serialize_bytes();
+            prop_assert_eq![&sigma_serialize_roundtrip(&v), &v];
+            prop_assert_eq![SigmaBoolean::sigma_parse_bytes(bytes.clone()).unwrap().sigma_serialize_bytes(), bytes];
+        }
+    }
+
+    #[test]
+    fn op_code_prefixes() {
+        let pk = SigmaBoolean::from(ProveDlog::new(dlog_group::generator()));
+        let first_byte = |sb: SigmaBoolean| sb.sigma_serialize_bytes()[0];
+        assert_eq!(first_byte(pk.clone()), OpCode::PROVE_DLOG.value());
+        assert_eq!(
+            first_byte(SigmaBoolean::from(ProveDHTuple::new(
+                dlog_group::generator(),
+                dlog_group::generator(),
+                dlog_group::generator(),
+                dlog_group::generator()
+            ))),
+            OpCode::PROVE_DIFFIE_HELLMAN_TUPLE.value()
+        );
+        assert_eq!(
+            first_byte(SigmaBoolean::CAND(vec![pk.clone()])),
+            OpCode::AND.value()
+        );
+        assert_eq!(
+            first_byte(SigmaBoolean::COR(vec![pk.clone()])),
+            OpCode::OR.value()
+        );
+        assert_eq!(
+            first_byte(SigmaBoolean::CTHRESHOLD {
+                k: 1,
+                children: vec![pk]
+            }),
+            OpCode::ATLEAST.value()
+        );
+        assert_eq!(
+            first_byte(SigmaBoolean::TrivialProp(true)),
+            OpCode::TRIVIAL_PROP_TRUE.value()
+        );
+        assert_eq!(
+            first_byte(SigmaBoolean::TrivialProp(false)),
+            OpCode::TRIVIAL_PROP_FALSE.value()
+        );
+    }
+}
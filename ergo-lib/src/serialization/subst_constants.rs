@@ -0,0 +1,47 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::subst_constants::SubstConstants;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for SubstConstants {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.script_bytes.sigma_serialize(w)?;
+        self.positions.sigma_serialize(w)?;
+        self.new_values.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let script_bytes = Expr::sigma_parse(r)?;
+        let positions = Expr::sigma_parse(r)?;
+        let new_values = Expr::sigma_parse(r)?;
+        Ok(SubstConstants {
+            script_bytes: Box::new(script_bytes),
+            positions: Box::new(positions),
+            new_values: Box::new(new_values),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::subst_constants::SubstConstants;
+    use crate::serialization::sigma_serialize_roundtrip;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = SubstConstants {
+            script_bytes: Box::new(Constant::from(vec![16u8, 1, 4, 2, 115, 0]).into()),
+            positions: Box::new(Constant::from(vec![0i32]).into()),
+            new_values: Box::new(Constant::from(vec![4i32]).into()),
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+}
@@ -0,0 +1,116 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::ast::expr::Expr;
+use crate::ast::tuple::Tuple;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Tuple {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        if !Tuple::ITEMS_COUNT_RANGE.contains(&self.items.len()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "tuple must have from 2 to 4 items, got {}",
+                    self.items.len()
+                ),
+            ));
+        }
+        w.put_u8(self.items.len() as u8)?;
+        self.items.iter().try_for_each(|i| i.sigma_serialize(w))
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let items_count = r.get_u8()?;
+        if !Tuple::ITEMS_COUNT_RANGE.contains(&(items_count as usize)) {
+            return Err(SerializationError::ValueOutOfBounds(format!(
+                "tuple must have from 2 to 4 items, got {}",
+                items_count
+            )));
+        }
+        let mut items = Vec::with_capacity(items_count as usize);
+        for _ in 0..items_count {
+            items.push(Expr::sigma_parse(r)?);
+        }
+        Ok(Tuple { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::global_vars::GlobalVars;
+    use crate::ast::tuple::Tuple;
+    use crate::serialization::sigma_byte_writer::SigmaByteWriter;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+
+    #[test]
+    fn ser_roundtrip_pair() {
+        // (HEIGHT, 1L)
+        let expr: Expr = Tuple {
+            items: vec![GlobalVars::Height.into(), Expr::Const(1i64.into())],
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn ser_roundtrip_triple() {
+        let expr: Expr = Tuple {
+            items: vec![
+                Expr::Const(1i32.into()),
+                Expr::Const(true.into()),
+                Expr::Const(vec![1i8, 2].into()),
+            ],
+        }
+        .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_single_item_tuple() {
+        let mut bytes = Expr::from(Tuple {
+            items: vec![Expr::Const(1i32.into()), Expr::Const(2i32.into())],
+        })
+        .sigma_serialize_bytes();
+        // items count
+        bytes[1] = 1;
+        assert!(matches!(
+            Expr::sigma_parse_bytes(bytes),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn parse_five_item_tuple() {
+        let mut bytes = Expr::from(Tuple {
+            items: vec![Expr::Const(1i32.into()), Expr::Const(2i32.into())],
+        })
+        .sigma_serialize_bytes();
+        // items count
+        bytes[1] = 5;
+        bytes.extend(Expr::Const(3i32.into()).sigma_serialize_bytes());
+        bytes.extend(Expr::Const(4i32.into()).sigma_serialize_bytes());
+        bytes.extend(Expr::Const(5i32.into()).sigma_serialize_bytes());
+        assert!(matches!(
+            Expr::sigma_parse_bytes(bytes),
+            Err(SerializationError::ValueOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn serialize_single_item_tuple() {
+        let expr = Tuple {
+            items: vec![Expr::Const(1i32.into())],
+        };
+        let mut data = Vec::new();
+        let mut w = SigmaByteWriter::new(&mut data, None);
+        assert!(expr.sigma_serialize(&mut w).is_err());
+    }
+}
@@ -34,6 +34,35 @@ impl TypeCode {
     pub const COLLECTION_TYPE_CODE: TypeCode =
         Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::COLLECTION_TYPE_CONSTR_ID);
 
+    pub const NESTED_COLLECTION_TYPE_CONSTR_ID: u8 = 2;
+    pub const NESTED_COLLECTION_TYPE_CODE: TypeCode =
+        Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::NESTED_COLLECTION_TYPE_CONSTR_ID);
+
+    pub const OPTION_TYPE_CONSTR_ID: u8 = 3;
+    pub const OPTION_TYPE_CODE: TypeCode =
+        Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::OPTION_TYPE_CONSTR_ID);
+
+    pub const OPTION_COLLECTION_TYPE_CONSTR_ID: u8 = 4;
+    pub const OPTION_COLLECTION_TYPE_CODE: TypeCode =
+        Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::OPTION_COLLECTION_TYPE_CONSTR_ID);
+
+    pub const TUPLE_PAIR1_TYPE_CONSTR_ID: u8 = 5;
+    pub const TUPLE_PAIR1_TYPE_CODE: TypeCode =
+        Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::TUPLE_PAIR1_TYPE_CONSTR_ID);
+
+    pub const TUPLE_PAIR2_TYPE_CONSTR_ID: u8 = 6;
+    pub const TUPLE_PAIR2_TYPE_CODE: TypeCode =
+        Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::TUPLE_PAIR2_TYPE_CONSTR_ID);
+    pub const TUPLE_TRIPLE_TYPE_CODE: TypeCode = TypeCode::TUPLE_PAIR2_TYPE_CODE;
+
+    pub const TUPLE_PAIR_SYMMETRIC_TYPE_CONSTR_ID: u8 = 7;
+    pub const TUPLE_PAIR_SYMMETRIC_TYPE_CODE: TypeCode = Self::new(
+        (TypeCode::MAX_PRIM_TYPECODE + 1) * TypeCode::TUPLE_PAIR_SYMMETRIC_TYPE_CONSTR_ID,
+    );
+    pub const TUPLE_QUADRUPLE_TYPE_CODE: TypeCode = TypeCode::TUPLE_PAIR_SYMMETRIC_TYPE_CODE;
+
+    pub const TUPLE_TYPE_CODE: TypeCode = Self::new((TypeCode::MAX_PRIM_TYPECODE + 1) * 8);
+
     const fn new(c: u8) -> TypeCode {
         TypeCode(c)
     }
@@ -120,13 +149,68 @@ impl SigmaSerializable for SType {
 
             SType::SBox => todo!(),
             SType::SAvlTree => todo!(),
-            SType::SOption(_) => todo!(),
+            SType::SOption(elem_type) if is_stype_embeddable(elem_type) => {
+                let code = TypeCode::OPTION_TYPE_CODE + elem_type.type_code();
+                code.sigma_serialize(w)
+            }
+            SType::SOption(elem_type) => match &**elem_type {
+                SType::SColl(inner_elem_type) if is_stype_embeddable(inner_elem_type) => {
+                    let code = TypeCode::OPTION_COLLECTION_TYPE_CODE + inner_elem_type.type_code();
+                    code.sigma_serialize(w)
+                }
+                _ => {
+                    TypeCode::OPTION_TYPE_CODE.sigma_serialize(w)?;
+                    elem_type.sigma_serialize(w)
+                }
+            },
             SType::SColl(elem_type) if is_stype_embeddable(elem_type) => {
                 let code = TypeCode::COLLECTION_TYPE_CODE + elem_type.type_code();
                 code.sigma_serialize(w)
             }
-            SType::SColl(_) => todo!(),
-            SType::STup(_) => todo!(),
+            SType::SColl(elem_type) => match &**elem_type {
+                SType::SColl(inner_elem_type) if is_stype_embeddable(inner_elem_type) => {
+                    let code = TypeCode::NESTED_COLLECTION_TYPE_CODE + inner_elem_type.type_code();
+                    code.sigma_serialize(w)
+                }
+                _ => {
+                    TypeCode::COLLECTION_TYPE_CODE.sigma_serialize(w)?;
+                    elem_type.sigma_serialize(w)
+                }
+            },
+            SType::STup(items) => match items.as_slice() {
+                [t1, t2] if is_stype_embeddable(t1) && t1 == t2 => {
+                    let code = TypeCode::TUPLE_PAIR_SYMMETRIC_TYPE_CODE + t1.type_code();
+                    code.sigma_serialize(w)
+                }
+                [t1, t2] if is_stype_embeddable(t1) => {
+                    let code = TypeCode::TUPLE_PAIR1_TYPE_CODE + t1.type_code();
+                    code.sigma_serialize(w)?;
+                    t2.sigma_serialize(w)
+                }
+                [t1, t2] if is_stype_embeddable(t2) => {
+                    let code = TypeCode::TUPLE_PAIR2_TYPE_CODE + t2.type_code();
+                    code.sigma_serialize(w)?;
+                    t1.sigma_serialize(w)
+                }
+                [t1, t2] => {
+                    TypeCode::TUPLE_PAIR1_TYPE_CODE.sigma_serialize(w)?;
+                    t1.sigma_serialize(w)?;
+                    t2.sigma_serialize(w)
+                }
+                [_, _, _] => {
+                    TypeCode::TUPLE_TRIPLE_TYPE_CODE.sigma_serialize(w)?;
+                    items.iter().try_for_each(|i| i.sigma_serialize(w))
+                }
+                [_, _, _, _] => {
+                    TypeCode::TUPLE_QUADRUPLE_TYPE_CODE.sigma_serialize(w)?;
+                    items.iter().try_for_each(|i| i.sigma_serialize(w))
+                }
+                _ => {
+                    TypeCode::TUPLE_TYPE_CODE.sigma_serialize(w)?;
+                    w.put_u8(items.len() as u8)?;
+                    items.iter().try_for_each(|i| i.sigma_serialize(w))
+                }
+            },
             SType::SFunc(_) => todo!(),
             SType::SContext(_) => todo!(),
         }
@@ -141,9 +225,74 @@ impl SigmaSerializable for SType {
             // primitive
             0 => get_embeddable_type(type_code.value())?,
             // Coll[_]
-            1 => {
+            TypeCode::COLLECTION_TYPE_CONSTR_ID => {
+                if prim_id == 0 {
+                    SType::SColl(Box::new(SType::sigma_parse(r)?))
+                } else {
+                    SType::SColl(Box::new(get_embeddable_type(prim_id)?))
+                }
+            }
+            // Coll[Coll[_]]
+            TypeCode::NESTED_COLLECTION_TYPE_CONSTR_ID => {
+                let t_elem = get_embeddable_type(prim_id)?;
+                SType::SColl(Box::new(SType::SColl(Box::new(t_elem))))
+            }
+            // Option[_]
+            TypeCode::OPTION_TYPE_CONSTR_ID => {
+                if prim_id == 0 {
+                    SType::SOption(Box::new(SType::sigma_parse(r)?))
+                } else {
+                    SType::SOption(Box::new(get_embeddable_type(prim_id)?))
+                }
+            }
+            // Option[Coll[_]]
+            TypeCode::OPTION_COLLECTION_TYPE_CONSTR_ID => {
                 let t_elem = get_embeddable_type(prim_id)?;
-                SType::SColl(Box::new(t_elem))
+                SType::SOption(Box::new(SType::SColl(Box::new(t_elem))))
+            }
+            // (_, t2) or (t1, t2)
+            TypeCode::TUPLE_PAIR1_TYPE_CONSTR_ID => {
+                let t1 = if prim_id == 0 {
+                    SType::sigma_parse(r)?
+                } else {
+                    get_embeddable_type(prim_id)?
+                };
+                let t2 = SType::sigma_parse(r)?;
+                SType::STup(vec![t1, t2])
+            }
+            // (t1, _) or (t1, t2, t3)
+            TypeCode::TUPLE_PAIR2_TYPE_CONSTR_ID => {
+                if prim_id == 0 {
+                    let t1 = SType::sigma_parse(r)?;
+                    let t2 = SType::sigma_parse(r)?;
+                    let t3 = SType::sigma_parse(r)?;
+                    SType::STup(vec![t1, t2, t3])
+                } else {
+                    let t2 = get_embeddable_type(prim_id)?;
+                    let t1 = SType::sigma_parse(r)?;
+                    SType::STup(vec![t1, t2])
+                }
+            }
+            // (_, _) or (t1, t2, t3, t4)
+            TypeCode::TUPLE_PAIR_SYMMETRIC_TYPE_CONSTR_ID => {
+                if prim_id == 0 {
+                    let mut items = Vec::with_capacity(4);
+                    for _ in 0..4 {
+                        items.push(SType::sigma_parse(r)?);
+                    }
+                    SType::STup(items)
+                } else {
+                    let t = get_embeddable_type(prim_id)?;
+                    SType::STup(vec![t.clone(), t])
+                }
+            }
+            _ if type_code == TypeCode::TUPLE_TYPE_CODE => {
+                let len = r.get_u8()?;
+                let mut items = Vec::with_capacity(len as usize);
+                for _ in 0..len {
+                    items.push(SType::sigma_parse(r)?);
+                }
+                SType::STup(items)
             }
             _ => {
                 return Err(SerializationError::NotImplementedYet(
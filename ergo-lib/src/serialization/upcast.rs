@@ -0,0 +1,55 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::upcast::Upcast;
+use crate::types::stype::SType;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for Upcast {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        self.input.sigma_serialize(w)?;
+        self.tpe.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let input = Expr::sigma_parse(r)?;
+        let tpe = SType::sigma_parse(r)?;
+        Ok(Upcast::new(input, tpe)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::upcast::Upcast;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn ser_roundtrip() {
+        let expr: Expr = Upcast::new(Expr::Const(1i32.into()), SType::SLong)
+            .unwrap()
+            .into();
+        assert_eq![sigma_serialize_roundtrip(&expr), expr];
+    }
+
+    #[test]
+    fn parse_narrowing() {
+        // 1L upcasted to Int
+        let expr: Expr = Upcast {
+            input: Box::new(Expr::Const(1i64.into())),
+            tpe: SType::SInt,
+        }
+        .into();
+        assert!(matches!(
+            Expr::sigma_parse_bytes(expr.sigma_serialize_bytes()),
+            Err(SerializationError::InvalidArgument(_))
+        ));
+    }
+}
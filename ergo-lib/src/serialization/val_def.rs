@@ -0,0 +1,27 @@
+use std::io::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::val_def::ValDef;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for ValDef {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.id)?;
+        self.rhs.sigma_serialize(w)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let id = r.get_u32()?;
+        let rhs = Expr::sigma_parse(r)?;
+        // make the value type known to the ValUse nodes referencing it
+        r.val_def_type_store().insert(id, rhs.try_tpe()?);
+        Ok(ValDef {
+            id,
+            rhs: Box::new(rhs),
+        })
+    }
+}
@@ -0,0 +1,25 @@
+//! Types of the values bound during parsing (for ValUse nodes)
+use std::collections::HashMap;
+
+use crate::types::stype::SType;
+
+/// Types of the bound values (e.g. function arguments) by their ids
+#[derive(Debug, Default)]
+pub struct ValDefTypeStore(HashMap<u32, SType>);
+
+impl ValDefTypeStore {
+    /// Empty store
+    pub fn new() -> Self {
+        ValDefTypeStore(HashMap::new())
+    }
+
+    /// Store the type of the value bound to `id`
+    pub fn insert(&mut self, id: u32, tpe: SType) {
+        self.0.insert(id, tpe);
+    }
+
+    /// Type of the value bound to `id`
+    pub fn get(&self, id: u32) -> Option<&SType> {
+        self.0.get(&id)
+    }
+}
@@ -0,0 +1,47 @@
+use std::io::Error;
+
+use crate::ast::val_use::ValUse;
+
+use super::sigma_byte_reader::SigmaByteRead;
+use super::sigma_byte_writer::SigmaByteWrite;
+use super::SerializationError;
+use super::SigmaSerializable;
+
+impl SigmaSerializable for ValUse {
+    fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), Error> {
+        w.put_u32(self.val_id)
+    }
+
+    fn sigma_parse<R: SigmaByteRead>(r: &mut R) -> Result<Self, SerializationError> {
+        let val_id = r.get_u32()?;
+        // the type is known from the place where the value was bound (e.g. FuncValue)
+        let tpe = r
+            .val_def_type_store()
+            .get(val_id)
+            .ok_or(SerializationError::ValDefIdNotFound(val_id))?
+            .clone();
+        Ok(ValUse { val_id, tpe })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr::Expr;
+    use crate::ast::val_use::ValUse;
+    use crate::serialization::SerializationError;
+    use crate::serialization::SigmaSerializable;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn parse_unbound_val_id() {
+        let expr: Expr = ValUse {
+            val_id: 1,
+            tpe: SType::SInt,
+        }
+        .into();
+        assert_eq!(
+            Expr::sigma_parse_bytes(expr.sigma_serialize_bytes()),
+            Err(SerializationError::ValDefIdNotFound(1))
+        );
+    }
+}
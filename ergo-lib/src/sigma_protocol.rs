@@ -3,6 +3,7 @@
 pub mod dlog_group;
 pub mod private_input;
 pub mod prover;
+pub mod reduce;
 pub mod sigma_boolean;
 pub mod verifier;
 
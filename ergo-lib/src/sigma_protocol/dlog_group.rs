@@ -15,10 +15,13 @@
 //!
 //! On the other hand, any group element can be mapped to some string.
 
+use crate::big_integer::BigInteger;
 use crate::serialization::{
     sigma_byte_reader::SigmaByteRead, SerializationError, SigmaSerializable,
 };
 use k256::{AffinePoint, ProjectivePoint, PublicKey, Scalar};
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, Sign};
 use sigma_ser::vlq_encode;
 
 use elliptic_curve::weierstrass::public_key::FromPublicKey;
@@ -36,6 +39,16 @@ pub struct EcPoint(ProjectivePoint);
 impl EcPoint {
     /// Number of bytes to represent any group element as byte array
     pub const GROUP_SIZE: usize = 33;
+
+    /// The identity(infinity) element
+    pub const fn identity() -> EcPoint {
+        EcPoint(ProjectivePoint::identity())
+    }
+
+    /// Check if point is identity(infinity) element
+    pub fn is_identity(&self) -> bool {
+        *self == EcPoint::identity()
+    }
 }
 
 impl Eq for EcPoint {}
@@ -62,14 +75,14 @@ pub fn generator() -> EcPoint {
     EcPoint(ProjectivePoint::generator())
 }
 
-/// The identity(infinity) element
+/// The identity(infinity) element, see [`EcPoint::identity`]
 pub const fn identity() -> EcPoint {
-    EcPoint(ProjectivePoint::identity())
+    EcPoint::identity()
 }
 
-/// Check if point is identity(infinity) element
+/// Check if point is identity(infinity) element, see [`EcPoint::is_identity`]
 pub fn is_identity(ge: &EcPoint) -> bool {
-    *ge == identity()
+    ge.is_identity()
 }
 
 /// Calculates the inverse of the given group element
@@ -87,6 +100,33 @@ pub fn exponentiate(base: &EcPoint, exponent: &Scalar) -> EcPoint {
     }
 }
 
+/// Group order `n` of the secp256k1 curve (big-endian)
+const GROUP_ORDER_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Cofactor of the secp256k1 curve (the group of points has prime order)
+pub const COFACTOR: u32 = 1;
+
+lazy_static! {
+    /// Group order `n` of the secp256k1 curve
+    pub static ref ORDER: BigInt = BigInt::from_bytes_be(Sign::Plus, &GROUP_ORDER_BYTES);
+    /// Generator of the group, see [`generator`]
+    pub static ref GENERATOR: EcPoint = generator();
+}
+
+/// Converts the big integer to a scalar reducing it modulo the group order
+/// (negative values wrap around, e.g. `-1` becomes `n - 1`)
+pub fn bigint_to_scalar(bi: &BigInteger) -> Scalar {
+    let order: &BigInt = &ORDER;
+    let reduced = ((BigInt::from(bi.clone()) % order) + order) % order;
+    let (_, bytes) = reduced.to_bytes_be();
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    Scalar::from_bytes_reduced(padded.as_ref().into())
+}
+
 /// Creates a random member of this Dlog group
 pub fn random_element() -> EcPoint {
     let sk = DlogProverInput::random();
@@ -130,7 +170,7 @@ impl SigmaSerializable for EcPoint {
             }
         } else {
             // infinity point
-            Ok(EcPoint(ProjectivePoint::identity()))
+            Ok(EcPoint::identity())
         }
     }
 }
@@ -141,12 +181,20 @@ mod tests {
     use crate::serialization::sigma_serialize_roundtrip;
     use proptest::prelude::*;
 
+    lazy_static! {
+        // precomputed to keep scalar multiplication out of every generated case
+        static ref ARB_POINTS: Vec<EcPoint> = (1..=256u32)
+            .map(|n| exponentiate(&generator(), &Scalar::from(n.wrapping_mul(2_654_435_761))))
+            .collect();
+    }
+
     impl Arbitrary for EcPoint {
         type Parameters = ();
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-            prop_oneof![Just(generator()), Just(identity()), Just(random_element()),].boxed()
+            // valid (on-curve, non-infinity) points, picked by the proptest RNG
+            proptest::sample::select(ARB_POINTS.clone()).boxed()
         }
     }
 
@@ -157,4 +205,71 @@ mod tests {
             prop_assert_eq![sigma_serialize_roundtrip(&v), v];
         }
     }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1024))]
+
+        #[test]
+        fn arbitrary_is_valid_point(v in any::<EcPoint>()) {
+            prop_assert!(!is_identity(&v));
+            prop_assert_ne!(v.sigma_serialize_bytes()[0], 0);
+            prop_assert_eq![sigma_serialize_roundtrip(&v), v];
+        }
+    }
+
+    #[test]
+    fn bigint_to_scalar_wraps_negative() {
+        assert_eq!(bigint_to_scalar(&BigInteger::from(0i64)), Scalar::zero());
+        assert_eq!(
+            bigint_to_scalar(&BigInteger::from(7i64)),
+            Scalar::from(7u32)
+        );
+        assert_eq!(
+            bigint_to_scalar(&BigInteger::from(-1i64)),
+            -Scalar::from(1u32)
+        );
+    }
+
+    #[test]
+    fn order_is_secp256k1_n() {
+        assert_eq!(
+            *ORDER,
+            BigInt::parse_bytes(
+                b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+                16
+            )
+            .unwrap()
+        );
+        assert_eq!(*GENERATOR, generator());
+    }
+
+    #[test]
+    fn generator_exp_order_is_identity() {
+        let order_scalar = Scalar::from_bytes_reduced(GROUP_ORDER_BYTES.as_ref().into());
+        assert!(is_identity(&exponentiate(&GENERATOR, &order_scalar)));
+    }
+
+    #[test]
+    fn identity_ser_roundtrip() {
+        assert_eq!(sigma_serialize_roundtrip(&identity()), identity());
+    }
+
+    #[test]
+    fn identity_ser_zero_bytes() {
+        // same as in sigmastate, all GROUP_SIZE bytes are zero
+        assert_eq!(
+            EcPoint::identity().sigma_serialize_bytes(),
+            vec![0u8; EcPoint::GROUP_SIZE]
+        );
+        assert_eq!(
+            EcPoint::sigma_parse_bytes(vec![0u8; EcPoint::GROUP_SIZE]),
+            Ok(EcPoint::identity())
+        );
+    }
+
+    #[test]
+    fn generator_exp_zero_is_identity() {
+        assert!(exponentiate(&GENERATOR, &Scalar::zero()).is_identity());
+        assert!(!GENERATOR.is_identity());
+    }
 }
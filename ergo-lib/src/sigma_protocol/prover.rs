@@ -46,6 +46,9 @@ pub enum ProverError {
     /// Cannot find a secret for "real" unproven leaf
     #[error("Cannot find a secret for \"real\" unproven leaf")]
     SecretNotFound,
+    /// Proving of the given sigma proposition is not supported yet
+    #[error("Proving is not supported yet for: {0}")]
+    NotSupportedYet(String),
 }
 
 impl From<ErgoTreeParsingError> for ProverError {
@@ -75,20 +78,20 @@ pub trait Prover: Evaluator {
     ) -> Result<ProverResult, ProverError> {
         let expr = tree.proposition()?;
         let proof = self
-            .reduce_to_crypto(expr.as_ref(), env, ctx)
+            .reduce_to_crypto(expr.as_ref(), env, ctx.clone())
             .map_err(ProverError::EvalError)
             .and_then(|v| match v.sigma_prop {
                 SigmaBoolean::TrivialProp(true) => Ok(UncheckedTree::NoProof),
                 SigmaBoolean::TrivialProp(false) => Err(ProverError::ReducedToFalse),
                 sb => {
-                    let tree = convert_to_unproven(sb);
+                    let tree = convert_to_unproven(sb)?;
                     let unchecked_tree = self.prove_to_unchecked(tree, message)?;
                     Ok(UncheckedTree::UncheckedSigmaTree(unchecked_tree))
                 }
             });
         proof.map(|v| ProverResult {
             proof: serialize_sig(v),
-            extension: ContextExtension::empty(),
+            extension: ctx.extension.clone(),
         })
     }
 
@@ -267,8 +270,8 @@ pub trait Prover: Evaluator {
     }
 }
 
-fn convert_to_unproven(ergo_lib: SigmaBoolean) -> UnprovenTree {
-    match ergo_lib {
+fn convert_to_unproven(ergo_lib: SigmaBoolean) -> Result<UnprovenTree, ProverError> {
+    Ok(match ergo_lib {
         // TODO: why it's even here? Make another SigmaBoolean without trivial props?
         SigmaBoolean::TrivialProp(_) => todo!(),
         SigmaBoolean::ProofOfKnowledge(pok) => match pok {
@@ -283,7 +286,11 @@ fn convert_to_unproven(ergo_lib: SigmaBoolean) -> UnprovenTree {
             .into(),
         },
         SigmaBoolean::CAND(_) => todo!(),
-    }
+        SigmaBoolean::COR(_) => return Err(ProverError::NotSupportedYet("COR".to_string())),
+        SigmaBoolean::CTHRESHOLD { .. } => {
+            return Err(ProverError::NotSupportedYet("CTHRESHOLD".to_string()))
+        }
+    })
 }
 
 /// Test prover implementation
@@ -306,6 +313,7 @@ mod tests {
     use crate::ast::expr::Expr;
     use crate::ast::value::Value;
     use crate::sigma_protocol::private_input::DlogProverInput;
+    use crate::sigma_protocol::sigma_boolean::SigmaProp;
     use crate::types::stype::SType;
     use std::rc::Rc;
 
@@ -369,4 +377,28 @@ mod tests {
         assert!(res.is_ok());
         assert_ne!(res.unwrap().proof, ProofBytes::Empty);
     }
+
+    #[test]
+    fn test_prove_threshold_not_supported() {
+        let secret = DlogProverInput::random();
+        let pk: SigmaBoolean = secret.public_image().into();
+        let threshold = SigmaBoolean::CTHRESHOLD {
+            k: 1,
+            children: vec![pk.clone(), pk],
+        };
+        let tree = ErgoTree::from(Rc::new(Expr::Const(Constant {
+            tpe: SType::SSigmaProp,
+            v: SigmaProp::new(threshold).into(),
+        })));
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        let res = prover.prove(
+            &tree,
+            &Env::empty(),
+            Rc::new(Context::dummy()),
+            vec![0u8; 100].as_slice(),
+        );
+        assert!(matches!(res, Err(ProverError::NotSupportedYet(_))));
+    }
 }
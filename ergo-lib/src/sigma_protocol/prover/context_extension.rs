@@ -7,7 +7,7 @@ use crate::serialization::{
 use indexmap::IndexMap;
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::{convert::TryFrom, io, num::ParseIntError};
 
 /// User-defined variables to be put into context
@@ -16,7 +16,7 @@ use std::{convert::TryFrom, io, num::ParseIntError};
     feature = "json",
     derive(Serialize, Deserialize),
     serde(
-        into = "HashMap<String, Constant>",
+        into = "BTreeMap<u8, Constant>",
         try_from = "HashMap<String, Constant>"
     )
 )]
@@ -34,6 +34,12 @@ impl ContextExtension {
     }
 }
 
+impl Default for ContextExtension {
+    fn default() -> Self {
+        ContextExtension::empty()
+    }
+}
+
 impl SigmaSerializable for ContextExtension {
     fn sigma_serialize<W: SigmaByteWrite>(&self, w: &mut W) -> Result<(), io::Error> {
         w.put_u8(self.values.len() as u8)?;
@@ -61,12 +67,10 @@ impl SigmaSerializable for ContextExtension {
     }
 }
 
-impl Into<HashMap<String, Constant>> for ContextExtension {
-    fn into(self) -> HashMap<String, Constant> {
-        self.values
-            .into_iter()
-            .map(|(k, v)| (format!("{}", k), v))
-            .collect()
+// keys are sorted numerically (JSON encoder emits them as strings, i.e. "1", "3", "10")
+impl From<ContextExtension> for BTreeMap<u8, Constant> {
+    fn from(v: ContextExtension) -> Self {
+        v.values.into_iter().collect()
     }
 }
 
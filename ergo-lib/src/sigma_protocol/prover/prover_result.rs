@@ -75,8 +75,8 @@ pub struct ProverResult {
     /// proof that satisfies final sigma proposition
     #[cfg_attr(feature = "json", serde(rename = "proofBytes"))]
     pub proof: ProofBytes,
-    /// user-defined variables to be put into context
-    #[cfg_attr(feature = "json", serde(rename = "extension"))]
+    /// user-defined variables to be put into context (empty if absent in JSON)
+    #[cfg_attr(feature = "json", serde(rename = "extension", default))]
     pub extension: ContextExtension,
 }
 
@@ -0,0 +1,241 @@
+//! Reduction of the ErgoTree to the sigma proposition (for tooling)
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use thiserror::Error;
+
+use crate::ast::expr::Expr;
+use crate::ast::global_vars::GlobalVars;
+use crate::chain::ergo_box::ErgoBox;
+use crate::chain::{blake2b256_hash, Digest32};
+use crate::ergo_tree::{ErgoTree, ErgoTreeParsingError};
+use crate::eval::context::Context;
+use crate::eval::{Env, EvalError, Evaluator, ReductionResult};
+use crate::serialization::{SerializationError, SigmaSerializable};
+
+/// Errors on ErgoTree reduction
+#[derive(Error, PartialEq, Eq, Debug, Clone)]
+pub enum ReduceError {
+    /// Invalid Base16 encoding
+    #[error("Base16 decoding error: {0}")]
+    Base16DecodingError(#[from] base16::DecodeError),
+    /// Failed to parse ErgoTree from bytes
+    #[error("ErgoTree serialization error: {0:?}")]
+    SerializationError(#[from] SerializationError),
+    /// Failed to parse ErgoTree root expression
+    #[error("ErgoTree parsing error: {0:?}")]
+    ErgoTreeError(#[from] ErgoTreeParsingError),
+    /// Failed to evaluate ErgoTree
+    #[error("Evaluation error: {0}")]
+    EvalError(#[from] EvalError),
+}
+
+struct Reducer;
+
+impl Evaluator for Reducer {}
+
+/// Parse ErgoTree from Base16-encoded bytes, reduce it in the given context
+/// and return Base16-encoded serialized sigma proposition
+pub fn reduce_tree_to_sigma_hex(tree_hex: &str, ctx: &Context) -> Result<String, ReduceError> {
+    let tree = ErgoTree::sigma_parse_bytes(base16::decode(tree_hex)?)?;
+    let expr = tree.proposition()?;
+    let reduction_result =
+        Reducer.reduce_to_crypto(expr.as_ref(), &Env::empty(), Rc::new(ctx.clone()))?;
+    Ok(base16::encode_lower(
+        &reduction_result.sigma_prop.sigma_serialize_bytes(),
+    ))
+}
+
+/// Cache of the reduction results (opt-in) for the repeated reductions of the same script.
+/// Entries are keyed on the hash of the serialized tree and the fingerprint of the context fields
+/// the tree reads, so changing a field the tree does not read still hits the cache.
+#[derive(Default)]
+pub struct ReductionCache {
+    entries: HashMap<(Digest32, Digest32), ReductionResult>,
+}
+
+impl ReductionCache {
+    /// Empty cache
+    pub fn new() -> Self {
+        ReductionCache {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Cached reduction result of the tree in the given context, reduces the tree (and caches
+    /// the result) on a cache miss. Failed reductions are not cached.
+    pub fn get_or_reduce(
+        &mut self,
+        tree: &ErgoTree,
+        ctx: &Context,
+    ) -> Result<ReductionResult, ReduceError> {
+        let expr = tree.proposition()?;
+        let key = (
+            blake2b256_hash(&tree.sigma_serialize_bytes()),
+            context_fingerprint(expr.as_ref(), ctx),
+        );
+        if let Some(res) = self.entries.get(&key) {
+            return Ok(res.clone());
+        }
+        let res = Reducer.reduce_to_crypto(expr.as_ref(), &Env::empty(), Rc::new(ctx.clone()))?;
+        self.entries.insert(key, res.clone());
+        Ok(res)
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Context fields that can be read by the tree
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum ContextField {
+    Height,
+    SelfBox,
+    Inputs,
+    Outputs,
+    DataInputs,
+    Extension,
+}
+
+impl ContextField {
+    const ALL: [ContextField; 6] = [
+        ContextField::Height,
+        ContextField::SelfBox,
+        ContextField::Inputs,
+        ContextField::Outputs,
+        ContextField::DataInputs,
+        ContextField::Extension,
+    ];
+}
+
+fn collect_read_fields(expr: &Expr, fields: &mut Vec<ContextField>) {
+    let read = match expr {
+        Expr::GlobalVars(GlobalVars::Height) => vec![ContextField::Height],
+        Expr::GlobalVars(GlobalVars::SelfBox) => vec![ContextField::SelfBox],
+        Expr::GlobalVars(GlobalVars::Inputs) => vec![ContextField::Inputs],
+        Expr::GlobalVars(GlobalVars::Outputs) => vec![ContextField::Outputs],
+        Expr::GetVar(_) => vec![ContextField::Extension],
+        // CONTEXT object gives access to any field
+        Expr::Context => ContextField::ALL.to_vec(),
+        _ => vec![],
+    };
+    for f in read {
+        if !fields.contains(&f) {
+            fields.push(f);
+        }
+    }
+    expr.children()
+        .into_iter()
+        .for_each(|c| collect_read_fields(c, fields));
+}
+
+/// Hash of the context fields read by the expression
+fn context_fingerprint(expr: &Expr, ctx: &Context) -> Digest32 {
+    let mut fields = Vec::new();
+    collect_read_fields(expr, &mut fields);
+    let mut bytes = Vec::new();
+    fn put_box_ids(boxes: &[ErgoBox], bytes: &mut Vec<u8>) {
+        boxes
+            .iter()
+            .for_each(|b| bytes.extend_from_slice(&b.box_id().0 .0[..]))
+    }
+    // fixed order of the fields, regardless of the order they are read in
+    for field in ContextField::ALL.iter().filter(|f| fields.contains(f)) {
+        bytes.push(*field as u8);
+        match field {
+            ContextField::Height => bytes.extend_from_slice(&ctx.height.to_be_bytes()),
+            ContextField::SelfBox => put_box_ids(std::slice::from_ref(&ctx.self_box), &mut bytes),
+            ContextField::Inputs => put_box_ids(&ctx.inputs, &mut bytes),
+            ContextField::Outputs => put_box_ids(&ctx.outputs, &mut bytes),
+            ContextField::DataInputs => put_box_ids(&ctx.data_inputs, &mut bytes),
+            ContextField::Extension => bytes.extend(ctx.extension.sigma_serialize_bytes()),
+        }
+    }
+    blake2b256_hash(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::constant::Constant;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::serialization::op_code::OpCode;
+    use crate::sigma_protocol::sigma_boolean::{ProveDlog, SigmaBoolean, SigmaProp};
+    use crate::test_util::force_any_val;
+
+    #[test]
+    fn reduce_p2pk() {
+        let pk = force_any_val::<ProveDlog>();
+        let tree: ErgoTree =
+            Rc::new(Expr::Const(Constant::from(SigmaProp::from(pk.clone())))).into();
+        let tree_hex = base16::encode_lower(&tree.sigma_serialize_bytes());
+        let sigma_hex = reduce_tree_to_sigma_hex(&tree_hex, &Context::dummy()).unwrap();
+        assert!(sigma_hex.starts_with(&base16::encode_lower(&[OpCode::PROVE_DLOG.value()])));
+        assert_eq!(
+            sigma_hex,
+            base16::encode_lower(&SigmaBoolean::from(pk).sigma_serialize_bytes())
+        );
+    }
+
+    fn height_eq_tree(height: i32) -> ErgoTree {
+        // HEIGHT == height
+        ErgoTree::without_segregation(Rc::new(Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(GlobalVars::Height.into()),
+            Box::new(Expr::Const(height.into())),
+        )))
+    }
+
+    #[test]
+    fn reduction_cache_hit() {
+        let mut cache = ReductionCache::new();
+        let tree = height_eq_tree(1);
+        let ctx = Context {
+            height: 1,
+            ..Context::dummy()
+        };
+        let res = cache.get_or_reduce(&tree, &ctx).unwrap();
+        assert_eq!(res.sigma_prop, SigmaBoolean::TrivialProp(true));
+        assert_eq!(cache.get_or_reduce(&tree, &ctx).unwrap(), res);
+        // outputs are not read by the tree
+        let ctx_other_outputs = Context {
+            outputs: vec![force_any_val::<ErgoBox>()],
+            ..ctx
+        };
+        assert_eq!(cache.get_or_reduce(&tree, &ctx_other_outputs).unwrap(), res);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn reduction_cache_read_field_changed() {
+        let mut cache = ReductionCache::new();
+        let tree = height_eq_tree(1);
+        let ctx = Context {
+            height: 1,
+            ..Context::dummy()
+        };
+        let res = cache.get_or_reduce(&tree, &ctx).unwrap();
+        let res_new_height = cache
+            .get_or_reduce(&tree, &Context { height: 2, ..ctx })
+            .unwrap();
+        assert_ne!(res_new_height, res);
+        assert_eq!(res_new_height.sigma_prop, SigmaBoolean::TrivialProp(false));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn reduce_invalid_hex() {
+        assert!(matches!(
+            reduce_tree_to_sigma_hex("xyz", &Context::dummy()),
+            Err(ReduceError::Base16DecodingError(_))
+        ));
+    }
+}
@@ -42,6 +42,44 @@ pub struct ProveDHTuple {
     vv: Box<EcPoint>,
 }
 
+impl ProveDHTuple {
+    /// create new public key
+    pub fn new(gv: EcPoint, hv: EcPoint, uv: EcPoint, vv: EcPoint) -> ProveDHTuple {
+        ProveDHTuple {
+            gv: Box::new(gv),
+            hv: Box::new(hv),
+            uv: Box::new(uv),
+            vv: Box::new(vv),
+        }
+    }
+
+    /// generator `g`
+    pub fn gv(&self) -> &EcPoint {
+        &self.gv
+    }
+
+    /// `h = g^w`
+    pub fn hv(&self) -> &EcPoint {
+        &self.hv
+    }
+
+    /// `u = g^x`
+    pub fn uv(&self) -> &EcPoint {
+        &self.uv
+    }
+
+    /// `v = h^x`
+    pub fn vv(&self) -> &EcPoint {
+        &self.vv
+    }
+}
+
+impl From<ProveDHTuple> for SigmaProofOfKnowledgeTree {
+    fn from(pdht: ProveDHTuple) -> Self {
+        SigmaProofOfKnowledgeTree::ProveDHTuple(pdht)
+    }
+}
+
 /// Sigma proposition
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum SigmaProofOfKnowledgeTree {
@@ -61,6 +99,15 @@ pub enum SigmaBoolean {
     ProofOfKnowledge(SigmaProofOfKnowledgeTree),
     /// AND conjunction for sigma propositions
     CAND(Vec<SigmaBoolean>),
+    /// OR disjunction for sigma propositions
+    COR(Vec<SigmaBoolean>),
+    /// THRESHOLD connector for sigma propositions (at least `k` of the `children` should be proven)
+    CTHRESHOLD {
+        /// Minimal number of the children to be proven
+        k: u8,
+        /// Child propositions
+        children: Vec<SigmaBoolean>,
+    },
 }
 
 impl SigmaBoolean {
@@ -70,7 +117,50 @@ impl SigmaBoolean {
             SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(_)) => {
                 OpCode::PROVE_DLOG
             }
-            _ => todo!(),
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDHTuple(_)) => {
+                OpCode::PROVE_DIFFIE_HELLMAN_TUPLE
+            }
+            SigmaBoolean::TrivialProp(true) => OpCode::TRIVIAL_PROP_TRUE,
+            SigmaBoolean::TrivialProp(false) => OpCode::TRIVIAL_PROP_FALSE,
+            SigmaBoolean::CAND(_) => OpCode::AND,
+            SigmaBoolean::COR(_) => OpCode::OR,
+            SigmaBoolean::CTHRESHOLD { .. } => OpCode::ATLEAST,
+        }
+    }
+
+    /// AND conjunction of the given propositions with trivial propositions simplified
+    /// (`true && x` is `x`, `false && x` is `false`)
+    pub fn cand_normalized(items: Vec<SigmaBoolean>) -> SigmaBoolean {
+        let mut res = Vec::new();
+        for item in items {
+            match item {
+                SigmaBoolean::TrivialProp(false) => return item,
+                SigmaBoolean::TrivialProp(true) => (),
+                _ => res.push(item),
+            }
+        }
+        match res.len() {
+            0 => SigmaBoolean::TrivialProp(true),
+            1 => res.remove(0),
+            _ => SigmaBoolean::CAND(res),
+        }
+    }
+
+    /// OR disjunction of the given propositions with trivial propositions simplified
+    /// (`true || x` is `true`, `false || x` is `x`)
+    pub fn cor_normalized(items: Vec<SigmaBoolean>) -> SigmaBoolean {
+        let mut res = Vec::new();
+        for item in items {
+            match item {
+                SigmaBoolean::TrivialProp(true) => return item,
+                SigmaBoolean::TrivialProp(false) => (),
+                _ => res.push(item),
+            }
+        }
+        match res.len() {
+            0 => SigmaBoolean::TrivialProp(false),
+            1 => res.remove(0),
+            _ => SigmaBoolean::COR(res),
         }
     }
 }
@@ -109,6 +199,20 @@ impl SigmaProp {
     pub fn value(&self) -> &SigmaBoolean {
         &self.0
     }
+
+    /// AND conjunction of the given propositions (see [`SigmaBoolean::cand_normalized`])
+    pub fn and(items: Vec<SigmaProp>) -> Self {
+        SigmaProp(SigmaBoolean::cand_normalized(
+            items.into_iter().map(|p| p.0).collect(),
+        ))
+    }
+
+    /// OR disjunction of the given propositions (see [`SigmaBoolean::cor_normalized`])
+    pub fn or(items: Vec<SigmaProp>) -> Self {
+        SigmaProp(SigmaBoolean::cor_normalized(
+            items.into_iter().map(|p| p.0).collect(),
+        ))
+    }
 }
 
 impl<T: Into<SigmaBoolean>> From<T> for SigmaProp {
@@ -120,6 +224,8 @@ impl<T: Into<SigmaBoolean>> From<T> for SigmaProp {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::constant::Constant;
+    use crate::serialization::sigma_serialize_roundtrip;
     use proptest::prelude::*;
 
     impl Arbitrary for ProveDlog {
@@ -152,4 +258,56 @@ mod tests {
             (any::<SigmaBoolean>()).prop_map(SigmaProp::new).boxed()
         }
     }
+
+    proptest! {
+
+        #[test]
+        fn cand_normalized(x in any::<SigmaBoolean>(), y in any::<SigmaBoolean>()) {
+            let t = SigmaBoolean::TrivialProp(true);
+            let f = SigmaBoolean::TrivialProp(false);
+            prop_assert_eq![SigmaBoolean::cand_normalized(vec![t.clone(), x.clone()]), x.clone()];
+            prop_assert_eq![SigmaBoolean::cand_normalized(vec![f.clone(), x.clone()]), f.clone()];
+            prop_assert_eq![SigmaBoolean::cand_normalized(vec![x.clone(), f.clone()]), f];
+            prop_assert_eq![SigmaBoolean::cand_normalized(vec![t.clone(), t.clone()]), t];
+            prop_assert_eq![
+                SigmaBoolean::cand_normalized(vec![x.clone(), y.clone()]),
+                SigmaBoolean::CAND(vec![x, y])
+            ];
+        }
+
+        #[test]
+        fn cor_normalized(x in any::<SigmaBoolean>(), y in any::<SigmaBoolean>()) {
+            let t = SigmaBoolean::TrivialProp(true);
+            let f = SigmaBoolean::TrivialProp(false);
+            prop_assert_eq![SigmaBoolean::cor_normalized(vec![t.clone(), x.clone()]), t.clone()];
+            prop_assert_eq![SigmaBoolean::cor_normalized(vec![x.clone(), t.clone()]), t];
+            prop_assert_eq![SigmaBoolean::cor_normalized(vec![f.clone(), x.clone()]), x.clone()];
+            prop_assert_eq![SigmaBoolean::cor_normalized(vec![f.clone(), f.clone()]), f];
+            prop_assert_eq![
+                SigmaBoolean::cor_normalized(vec![x.clone(), y.clone()]),
+                SigmaBoolean::COR(vec![x, y])
+            ];
+        }
+
+        #[test]
+        fn ser_roundtrip_cand_cor(a in any::<SigmaBoolean>(), b in any::<SigmaBoolean>()) {
+            let cand = SigmaBoolean::CAND(vec![a.clone(), b.clone()]);
+            let cor = SigmaBoolean::COR(vec![a, cand.clone()]);
+            prop_assert_eq![sigma_serialize_roundtrip(&cand), cand];
+            prop_assert_eq![sigma_serialize_roundtrip(&cor), cor];
+        }
+
+        #[test]
+        fn and_or_constant_roundtrip(a in any::<ProveDlog>(), b in any::<ProveDlog>()) {
+            let and = SigmaProp::and(vec![a.clone().into(), b.clone().into()]);
+            prop_assert_eq![
+                and.value(),
+                &SigmaBoolean::CAND(vec![a.clone().into(), b.clone().into()])
+            ];
+            let c: Constant = and.into();
+            prop_assert_eq![sigma_serialize_roundtrip(&c), c];
+            let or: Constant = SigmaProp::or(vec![a.into(), b.into()]).into();
+            prop_assert_eq![sigma_serialize_roundtrip(&or), or];
+        }
+    }
 }
@@ -59,8 +59,8 @@ pub trait Verifier: Evaluator {
         message: &[u8],
     ) -> Result<VerificationResult, VerifierError> {
         let expr = tree.proposition()?;
-        let cprop = self.reduce_to_crypto(expr.as_ref(), env, ctx)?.sigma_prop;
-        let res: bool = match cprop {
+        let reduction_result = self.reduce_to_crypto(expr.as_ref(), env, ctx)?;
+        let res: bool = match reduction_result.sigma_prop {
             SigmaBoolean::TrivialProp(b) => b,
             sb => {
                 // Perform Verifier Steps 1-3
@@ -84,7 +84,7 @@ pub trait Verifier: Evaluator {
         };
         Ok(VerificationResult {
             result: res,
-            cost: 0,
+            cost: reduction_result.cost,
         })
     }
 }
@@ -156,6 +156,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prover_verifier_true_proposition() {
+        let tree = ErgoTree::true_proposition();
+        let message = vec![0u8; 100];
+        let prover = TestProver { secrets: vec![] };
+        let proof = prover
+            .prove(&tree, &Env::empty(), Rc::new(Context::dummy()), &message)
+            .unwrap()
+            .proof;
+        assert_eq!(proof, ProofBytes::Empty);
+        let ver_res = TestVerifier.verify(
+            &tree,
+            &Env::empty(),
+            Rc::new(Context::dummy()),
+            &proof,
+            &message,
+        );
+        assert!(ver_res.unwrap().result);
+    }
+
     #[test]
     #[cfg(feature = "json")]
     fn test_proof_from_mainnet() {
@@ -254,6 +274,6 @@ mod tests {
             &tx.inputs.get(1).unwrap().spending_proof.proof,
             message.as_slice(),
         );
-        assert_eq!(ver_res.unwrap().result, true);
+        assert!(ver_res.unwrap().result);
     }
 }
@@ -2,8 +2,13 @@
 
 pub mod stype;
 
+pub(crate) mod sbox;
+pub(crate) mod scoll;
 pub(crate) mod scontext;
 pub(crate) mod sfunc;
+pub(crate) mod sgroup_elem;
 pub(crate) mod smethod;
+pub(crate) mod snumeric;
+pub(crate) mod soption;
 pub(crate) mod stype_companion;
 pub(crate) mod stype_param;
@@ -0,0 +1,62 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+use crate::chain::ergo_box::ErgoBox;
+
+use super::sfunc::SFunc;
+use super::smethod::EvalFn;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+static S_BOX_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(99),
+    type_name: "Box",
+};
+
+static TOKENS_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| {
+    Ok(Value::Coll(Coll::NonPrimitive {
+        v: obj
+            .try_extract_into::<ErgoBox>()?
+            .tokens
+            .into_iter()
+            .map(|t| {
+                let id: Vec<u8> = t.token_id.0 .0.to_vec();
+                let amount: i64 = t.amount.into();
+                (id, amount).into()
+            })
+            .collect(),
+        elem_tpe: SType::STup(vec![SType::SColl(Box::new(SType::SByte)), SType::SLong]),
+    }))
+};
+
+lazy_static! {
+    static ref TOKENS_PROPERTY_RAW: SMethodDesc = SMethodDesc {
+        method_id: MethodId(8),
+        name: "tokens",
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![SType::SBox],
+            t_range: SType::SColl(Box::new(SType::STup(vec![
+                SType::SColl(Box::new(SType::SByte)),
+                SType::SLong
+            ]))),
+            tpe_params: vec![],
+        })),
+        eval_fn: TOKENS_EVAL_FN,
+    };
+}
+
+lazy_static! {
+    pub static ref S_BOX_TYPE_COMPANION: STypeCompanion =
+        STypeCompanion::new(&S_BOX_TYPE_COMPANION_HEAD, vec![&TOKENS_PROPERTY_RAW]);
+}
+
+lazy_static! {
+    pub static ref TOKENS_PROPERTY: SMethod =
+        SMethod::new(&S_BOX_TYPE_COMPANION, &TOKENS_PROPERTY_RAW);
+}
@@ -0,0 +1,356 @@
+use crate::ast::constant::TryExtractInto;
+use crate::ast::func_value::FuncValue;
+use crate::ast::value::Coll;
+use crate::ast::value::Value;
+use crate::eval::Env;
+use crate::eval::EvalContext;
+use crate::eval::EvalError;
+
+use super::sfunc::SFunc;
+use super::smethod::EvalFn;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+static S_COLL_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(12),
+    type_name: "Coll",
+};
+
+/// `zipWithIndex`, each element paired with its index.
+/// Not in the sigmastate `SCollection` method table, so it has no method id and cannot be
+/// serialized as a method call.
+#[allow(dead_code)]
+pub(crate) fn zip_with_index(coll: &Coll) -> Coll {
+    Coll::NonPrimitive {
+        v: (0..coll.len())
+            .filter_map(|i| {
+                coll.get(i)
+                    .map(|v| Value::Tup(vec![v, Value::Int(i as i32)]))
+            })
+            .collect(),
+        elem_tpe: SType::STup(vec![coll.elem_tpe().clone(), SType::SInt]),
+    }
+}
+
+static INDEX_OF_EVAL_FN: EvalFn = |_env, _ectx, obj, args| {
+    let coll = obj.try_extract_into::<Coll>()?;
+    let mut args = args.into_iter();
+    let elem = args.next().ok_or(EvalError::UnexpectedExpr)?;
+    let from = args
+        .next()
+        .ok_or(EvalError::UnexpectedExpr)?
+        .try_extract_into::<i32>()?;
+    // compared the same way as by the EQ operation
+    Ok(Value::Int(
+        (from.max(0) as usize..coll.len())
+            .find(|i| coll.get(*i).as_ref() == Some(&elem))
+            .map(|i| i as i32)
+            .unwrap_or(-1),
+    ))
+};
+
+/// Length of the longest segment starting at `from` whose elements satisfy the predicate
+fn segment_length(
+    env: &Env,
+    ectx: &mut EvalContext,
+    coll: &Coll,
+    pred: &FuncValue,
+    from: usize,
+) -> Result<usize, EvalError> {
+    let mut len = 0;
+    // stop on the first element the predicate does not hold for
+    for item in (from..coll.len()).filter_map(|i| coll.get(i)) {
+        if !pred
+            .apply(env, ectx, vec![item])?
+            .try_extract_into::<bool>()?
+        {
+            break;
+        }
+        len += 1;
+    }
+    Ok(len)
+}
+
+/// `takeWhile(pred)`, the longest prefix whose elements satisfy the predicate.
+/// Not in the sigmastate `SCollection` method table, so it has no method id and cannot be
+/// serialized as a method call.
+#[allow(dead_code)]
+pub(crate) fn take_while(
+    env: &Env,
+    ectx: &mut EvalContext,
+    coll: &Coll,
+    pred: &FuncValue,
+) -> Result<Coll, EvalError> {
+    let prefix_len = segment_length(env, ectx, coll, pred, 0)?;
+    Ok(coll.slice(0, prefix_len))
+}
+
+/// `dropWhile(pred)`, the rest of the collection after the longest prefix whose elements
+/// satisfy the predicate.
+/// Not in the sigmastate `SCollection` method table, so it has no method id and cannot be
+/// serialized as a method call.
+#[allow(dead_code)]
+pub(crate) fn drop_while(
+    env: &Env,
+    ectx: &mut EvalContext,
+    coll: &Coll,
+    pred: &FuncValue,
+) -> Result<Coll, EvalError> {
+    let prefix_len = segment_length(env, ectx, coll, pred, 0)?;
+    Ok(coll.slice(prefix_len, coll.len()))
+}
+
+static SEGMENT_LENGTH_EVAL_FN: EvalFn = |env, ectx, obj, args| {
+    let coll = obj.try_extract_into::<Coll>()?;
+    let mut args = args.into_iter();
+    let pred = args
+        .next()
+        .ok_or(EvalError::UnexpectedExpr)?
+        .try_extract_into::<FuncValue>()?;
+    let from = args
+        .next()
+        .ok_or(EvalError::UnexpectedExpr)?
+        .try_extract_into::<i32>()?;
+    let len = segment_length(env, ectx, &coll, &pred, from.max(0) as usize)?;
+    Ok(Value::Int(len as i32))
+};
+
+// sigmastate declares segmentLength with id 16, but it is not among the SCollection methods
+// accepted by the node's interpreter, so trees using it are not valid on-chain (non-consensus)
+lazy_static! {
+    static ref SEGMENT_LENGTH_METHOD_RAW: SMethodDesc = SMethodDesc {
+        method_id: MethodId(16),
+        name: "segmentLength",
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![
+                SType::SColl(Box::new(SType::SAny)),
+                SType::SFunc(Box::new(SFunc {
+                    t_dom: vec![SType::SAny],
+                    t_range: SType::SBoolean,
+                    tpe_params: vec![],
+                })),
+                SType::SInt
+            ],
+            t_range: SType::SInt,
+            tpe_params: vec![],
+        })),
+        eval_fn: SEGMENT_LENGTH_EVAL_FN,
+    };
+}
+
+lazy_static! {
+    static ref INDEX_OF_METHOD_RAW: SMethodDesc = SMethodDesc {
+        method_id: MethodId(26),
+        name: "indexOf",
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![
+                SType::SColl(Box::new(SType::SAny)),
+                SType::SAny,
+                SType::SInt
+            ],
+            t_range: SType::SInt,
+            tpe_params: vec![],
+        })),
+        eval_fn: INDEX_OF_EVAL_FN,
+    };
+}
+
+lazy_static! {
+    pub static ref S_COLL_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_COLL_TYPE_COMPANION_HEAD,
+        vec![&SEGMENT_LENGTH_METHOD_RAW, &INDEX_OF_METHOD_RAW]
+    );
+}
+
+lazy_static! {
+    pub static ref SEGMENT_LENGTH_METHOD: SMethod =
+        SMethod::new(&S_COLL_TYPE_COMPANION, &SEGMENT_LENGTH_METHOD_RAW);
+}
+
+lazy_static! {
+    pub static ref INDEX_OF_METHOD: SMethod =
+        SMethod::new(&S_COLL_TYPE_COMPANION, &INDEX_OF_METHOD_RAW);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::constant::TryExtractFrom;
+    use crate::ast::expr::Expr;
+    use crate::ast::func_value::FuncArg;
+    use crate::ast::method_call::MethodCall;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::val_use::ValUse;
+    use crate::eval::context::Context;
+    use crate::eval::cost_accum::CostAccumulator;
+    use crate::eval::tests::eval_out;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn index_of(input: Constant, elem: Constant, from: i32) -> i32 {
+        let expr: Expr = MethodCall {
+            obj: Box::new(input.into()),
+            method: INDEX_OF_METHOD.clone(),
+            args: vec![elem.into(), Constant::from(from).into()],
+        }
+        .into();
+        eval_out::<i32>(
+            &sigma_serialize_roundtrip(&expr),
+            Rc::new(force_any_val::<Context>()),
+        )
+    }
+
+    #[test]
+    fn eval_index_of() {
+        let coll = || Constant::from(vec![5i64, 7, 5]);
+        assert_eq!(index_of(coll(), 5i64.into(), 0), 0);
+        assert_eq!(index_of(coll(), 7i64.into(), 0), 1);
+        // search starts at `from`
+        assert_eq!(index_of(coll(), 5i64.into(), 1), 2);
+        // negative `from` is the same as 0
+        assert_eq!(index_of(coll(), 5i64.into(), -1), 0);
+    }
+
+    #[test]
+    fn eval_index_of_not_found() {
+        assert_eq!(index_of(vec![5i64, 7].into(), 8i64.into(), 0), -1);
+        assert_eq!(index_of(vec![5i64, 7].into(), 5i64.into(), 1), -1);
+        assert_eq!(index_of(Vec::<i64>::new().into(), 5i64.into(), 0), -1);
+    }
+
+    #[test]
+    fn eval_index_of_from_beyond_length() {
+        assert_eq!(index_of(vec![5i64, 7].into(), 7i64.into(), 2), -1);
+        assert_eq!(index_of(vec![5i64, 7].into(), 7i64.into(), i32::MAX), -1);
+    }
+
+    #[test]
+    fn eval_index_of_bytes() {
+        assert_eq!(index_of(vec![1i8, 2, 3].into(), 3i8.into(), 0), 2);
+    }
+
+    #[test]
+    fn eval_index_of_nested_coll() {
+        let coll: Constant = vec![vec![1i64, 2], vec![3i64]].into();
+        assert_eq!(index_of(coll, vec![3i64].into(), 0), 1);
+    }
+
+    #[test]
+    fn eval_zip_with_index() {
+        let coll = Constant::from(vec![5i64, 7, 11])
+            .v
+            .try_extract_into::<Coll>()
+            .unwrap();
+        assert_eq!(
+            Value::Coll(zip_with_index(&coll)).try_extract_into::<Vec<(i64, i32)>>(),
+            Ok(vec![(5i64, 0i32), (7, 1), (11, 2)])
+        );
+    }
+
+    /// `{ (x: Int) => x < 3 }`
+    fn lt_3() -> Expr {
+        FuncValue {
+            args: vec![FuncArg {
+                idx: 1,
+                tpe: SType::SInt,
+            }],
+            body: Box::new(Expr::BinOp(
+                BinOp::Relation(RelationOp::Lt),
+                Box::new(
+                    ValUse {
+                        val_id: 1,
+                        tpe: SType::SInt,
+                    }
+                    .into(),
+                ),
+                Box::new(Expr::Const(3i32.into())),
+            )),
+        }
+        .into()
+    }
+
+    fn coll_method<T: TryExtractFrom<Value>>(
+        input: Vec<i32>,
+        method: &SMethod,
+        args: Vec<Expr>,
+    ) -> T {
+        let expr: Expr = MethodCall {
+            obj: Box::new(Constant::from(input).into()),
+            method: method.clone(),
+            args,
+        }
+        .into();
+        eval_out::<T>(
+            &sigma_serialize_roundtrip(&expr),
+            Rc::new(force_any_val::<Context>()),
+        )
+    }
+
+    fn lt_3_while(
+        f: fn(&Env, &mut EvalContext, &Coll, &FuncValue) -> Result<Coll, EvalError>,
+        input: Vec<i32>,
+    ) -> Vec<i32> {
+        let pred = match lt_3() {
+            Expr::FuncValue(f) => f,
+            _ => unreachable!(),
+        };
+        let coll = Constant::from(input).v.try_extract_into::<Coll>().unwrap();
+        let mut ectx = EvalContext::new(
+            Rc::new(force_any_val::<Context>()),
+            CostAccumulator::new(0, None),
+        );
+        Value::Coll(f(&Env::empty(), &mut ectx, &coll, &pred).unwrap())
+            .try_extract_into::<Vec<i32>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn eval_take_while() {
+        assert_eq!(lt_3_while(take_while, vec![1, 2, 3, 1]), vec![1, 2]);
+        // all match
+        assert_eq!(lt_3_while(take_while, vec![1, 2]), vec![1, 2]);
+        // no match
+        assert_eq!(lt_3_while(take_while, vec![3, 1]), Vec::<i32>::new());
+        assert_eq!(lt_3_while(take_while, vec![]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn eval_drop_while() {
+        assert_eq!(lt_3_while(drop_while, vec![1, 2, 3, 1]), vec![3, 1]);
+        // all match
+        assert_eq!(lt_3_while(drop_while, vec![1, 2]), Vec::<i32>::new());
+        // no match
+        assert_eq!(lt_3_while(drop_while, vec![3, 1]), vec![3, 1]);
+    }
+
+    fn segment_length(input: Vec<i32>, from: i32) -> i32 {
+        coll_method(
+            input,
+            &SEGMENT_LENGTH_METHOD,
+            vec![lt_3(), Expr::Const(from.into())],
+        )
+    }
+
+    #[test]
+    fn eval_segment_length() {
+        assert_eq!(segment_length(vec![1, 2, 3, 1, 0], 0), 2);
+        assert_eq!(segment_length(vec![1, 2, 3, 1, 0], 3), 2);
+        // negative `from` is the same as 0
+        assert_eq!(segment_length(vec![1, 2, 3], -1), 2);
+        // all match
+        assert_eq!(segment_length(vec![1, 2], 0), 2);
+        // no match
+        assert_eq!(segment_length(vec![3, 4], 0), 0);
+        assert_eq!(segment_length(vec![1, 2], 5), 0);
+    }
+}
@@ -4,6 +4,7 @@ use crate::ast::constant::TryExtractInto;
 use crate::ast::value::Coll;
 use crate::ast::value::Value;
 use crate::eval::context::Context;
+use crate::eval::EvalError;
 
 use super::sfunc::SFunc;
 use super::smethod::EvalFn;
@@ -20,11 +21,11 @@ use lazy_static::lazy_static;
 pub struct SContext();
 
 static S_CONTEXT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
-    type_id: TypeId(108),
+    type_id: TypeId(101),
     type_name: "Context",
 };
 
-static DATA_INPUTS_EVAL_FN: EvalFn = |obj, _args| {
+static DATA_INPUTS_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| {
     Ok(Value::Coll(Coll::NonPrimitive {
         v: obj
             .try_extract_into::<Rc<Context>>()?
@@ -37,6 +38,16 @@ static DATA_INPUTS_EVAL_FN: EvalFn = |obj, _args| {
     }))
 };
 
+static SELF_BOX_INDEX_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| {
+    let ctx = obj.try_extract_into::<Rc<Context>>()?;
+    // position of SELF in INPUTS
+    ctx.inputs
+        .iter()
+        .position(|b| b.box_id() == ctx.self_box.box_id())
+        .map(|idx| Value::Int(idx as i32))
+        .ok_or_else(|| EvalError::ContextFieldMissing("selfBoxIndex".to_string()))
+};
+
 lazy_static! {
     static ref DATA_INPUTS_PROPERTY_RAW: SMethodDesc = SMethodDesc {
         method_id: MethodId(1),
@@ -50,10 +61,23 @@ lazy_static! {
     };
 }
 
+lazy_static! {
+    static ref SELF_BOX_INDEX_PROPERTY_RAW: SMethodDesc = SMethodDesc {
+        method_id: MethodId(8),
+        name: "selfBoxIndex",
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![SType::SContext(SContext())],
+            t_range: SType::SInt,
+            tpe_params: vec![],
+        })),
+        eval_fn: SELF_BOX_INDEX_EVAL_FN,
+    };
+}
+
 lazy_static! {
     pub static ref S_CONTEXT_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
         &S_CONTEXT_TYPE_COMPANION_HEAD,
-        vec![&DATA_INPUTS_PROPERTY_RAW]
+        vec![&DATA_INPUTS_PROPERTY_RAW, &SELF_BOX_INDEX_PROPERTY_RAW]
     );
 }
 
@@ -61,3 +85,9 @@ lazy_static! {
     pub static ref DATA_INPUTS_PROPERTY: SMethod =
         SMethod::new(&S_CONTEXT_TYPE_COMPANION, &DATA_INPUTS_PROPERTY_RAW,);
 }
+
+lazy_static! {
+    /// `CONTEXT.selfBoxIndex`, index of the SELF box in INPUTS
+    pub static ref SELF_BOX_INDEX_PROPERTY: SMethod =
+        SMethod::new(&S_CONTEXT_TYPE_COMPANION, &SELF_BOX_INDEX_PROPERTY_RAW);
+}
@@ -0,0 +1,96 @@
+use crate::ast::constant::TryExtractInto;
+use crate::serialization::SigmaSerializable;
+use crate::sigma_protocol::dlog_group::EcPoint;
+
+use super::sfunc::SFunc;
+use super::smethod::EvalFn;
+use super::smethod::MethodId;
+use super::smethod::SMethod;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+static S_GROUP_ELEM_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(7),
+    type_name: "GroupElement",
+};
+
+// same bytes as `EcPoint` serialization (33-byte compressed SEC encoding)
+static GET_ENCODED_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| {
+    Ok(obj
+        .try_extract_into::<EcPoint>()?
+        .sigma_serialize_bytes()
+        .into())
+};
+
+lazy_static! {
+    static ref GET_ENCODED_PROPERTY_RAW: SMethodDesc = SMethodDesc {
+        method_id: MethodId(2),
+        name: "getEncoded",
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![SType::SGroupElement],
+            t_range: SType::SColl(Box::new(SType::SByte)),
+            tpe_params: vec![],
+        })),
+        eval_fn: GET_ENCODED_EVAL_FN,
+    };
+}
+
+lazy_static! {
+    pub static ref S_GROUP_ELEM_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_GROUP_ELEM_TYPE_COMPANION_HEAD,
+        vec![&GET_ENCODED_PROPERTY_RAW]
+    );
+}
+
+lazy_static! {
+    pub static ref GET_ENCODED_PROPERTY: SMethod =
+        SMethod::new(&S_GROUP_ELEM_TYPE_COMPANION, &GET_ENCODED_PROPERTY_RAW);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::expr::Expr;
+    use crate::ast::property_call::PropertyCall;
+    use crate::eval::context::Context;
+    use crate::eval::tests::eval_out;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::sigma_protocol::dlog_group;
+    use crate::test_util::force_any_val;
+
+    use super::*;
+
+    fn get_encoded(p: EcPoint) -> Vec<u8> {
+        let expr: Expr = PropertyCall {
+            obj: Box::new(Constant::from(p).into()),
+            method: GET_ENCODED_PROPERTY.clone(),
+        }
+        .into();
+        eval_out::<Vec<u8>>(
+            &sigma_serialize_roundtrip(&expr),
+            Rc::new(force_any_val::<Context>()),
+        )
+    }
+
+    #[test]
+    fn eval_get_encoded_decodes_back() {
+        let p = force_any_val::<EcPoint>();
+        let encoded = get_encoded(p.clone());
+        assert_eq!(encoded.len(), EcPoint::GROUP_SIZE);
+        assert_eq!(EcPoint::sigma_parse_bytes(encoded).unwrap(), p);
+    }
+
+    #[test]
+    fn eval_get_encoded_identity() {
+        assert_eq!(
+            get_encoded(dlog_group::identity()),
+            vec![0u8; EcPoint::GROUP_SIZE]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use std::io::Error;
 
 use crate::ast::value::Value;
+use crate::eval::Env;
+use crate::eval::EvalContext;
 use crate::eval::EvalError;
 use crate::serialization::sigma_byte_reader::SigmaByteRead;
 use crate::serialization::sigma_byte_writer::SigmaByteWrite;
@@ -61,7 +63,7 @@ impl SMethod {
     }
 }
 
-pub type EvalFn = fn(Value, Vec<Value>) -> Result<Value, EvalError>;
+pub type EvalFn = fn(&Env, &mut EvalContext, Value, Vec<Value>) -> Result<Value, EvalError>;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct SMethodDesc {
@@ -0,0 +1,190 @@
+use crate::eval::upcast::cast_numeric;
+
+use super::sfunc::SFunc;
+use super::smethod::EvalFn;
+use super::smethod::MethodId;
+use super::smethod::SMethodDesc;
+use super::stype::SType;
+use super::stype_companion::STypeCompanion;
+use super::stype_companion::STypeCompanionHead;
+use super::stype_companion::TypeId;
+use lazy_static::lazy_static;
+
+static S_BYTE_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(2),
+    type_name: "Byte",
+};
+
+static S_SHORT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(3),
+    type_name: "Short",
+};
+
+static S_INT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(4),
+    type_name: "Int",
+};
+
+static S_LONG_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(5),
+    type_name: "Long",
+};
+
+static S_BIG_INT_TYPE_COMPANION_HEAD: STypeCompanionHead = STypeCompanionHead {
+    type_id: TypeId(6),
+    type_name: "BigInt",
+};
+
+static TO_BYTE_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| cast_numeric(obj, &SType::SByte);
+static TO_SHORT_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| cast_numeric(obj, &SType::SShort);
+static TO_INT_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| cast_numeric(obj, &SType::SInt);
+static TO_LONG_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| cast_numeric(obj, &SType::SLong);
+static TO_BIG_INT_EVAL_FN: EvalFn = |_env, _ectx, obj, _args| cast_numeric(obj, &SType::SBigInt);
+
+/// Conversion methods (`toByte`, `toShort`, `toInt`, `toLong`, `toBigInt`) of the numeric type
+fn conversion_methods(tpe: SType) -> Vec<SMethodDesc> {
+    vec![
+        (1, "toByte", SType::SByte, TO_BYTE_EVAL_FN),
+        (2, "toShort", SType::SShort, TO_SHORT_EVAL_FN),
+        (3, "toInt", SType::SInt, TO_INT_EVAL_FN),
+        (4, "toLong", SType::SLong, TO_LONG_EVAL_FN),
+        (5, "toBigInt", SType::SBigInt, TO_BIG_INT_EVAL_FN),
+    ]
+    .into_iter()
+    .map(|(method_id, name, t_range, eval_fn)| SMethodDesc {
+        method_id: MethodId(method_id),
+        name,
+        tpe: SType::SFunc(Box::new(SFunc {
+            t_dom: vec![tpe.clone()],
+            t_range,
+            tpe_params: vec![],
+        })),
+        eval_fn,
+    })
+    .collect()
+}
+
+lazy_static! {
+    static ref S_BYTE_METHODS_RAW: Vec<SMethodDesc> = conversion_methods(SType::SByte);
+    static ref S_SHORT_METHODS_RAW: Vec<SMethodDesc> = conversion_methods(SType::SShort);
+    static ref S_INT_METHODS_RAW: Vec<SMethodDesc> = conversion_methods(SType::SInt);
+    static ref S_LONG_METHODS_RAW: Vec<SMethodDesc> = conversion_methods(SType::SLong);
+    static ref S_BIG_INT_METHODS_RAW: Vec<SMethodDesc> = conversion_methods(SType::SBigInt);
+}
+
+lazy_static! {
+    pub static ref S_BYTE_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_BYTE_TYPE_COMPANION_HEAD,
+        S_BYTE_METHODS_RAW.iter().collect()
+    );
+    pub static ref S_SHORT_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_SHORT_TYPE_COMPANION_HEAD,
+        S_SHORT_METHODS_RAW.iter().collect()
+    );
+    pub static ref S_INT_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_INT_TYPE_COMPANION_HEAD,
+        S_INT_METHODS_RAW.iter().collect()
+    );
+    pub static ref S_LONG_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_LONG_TYPE_COMPANION_HEAD,
+        S_LONG_METHODS_RAW.iter().collect()
+    );
+    pub static ref S_BIG_INT_TYPE_COMPANION: STypeCompanion = STypeCompanion::new(
+        &S_BIG_INT_TYPE_COMPANION_HEAD,
+        S_BIG_INT_METHODS_RAW.iter().collect()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::ast::constant::Constant;
+    use crate::ast::constant::TryExtractFrom;
+    use crate::ast::expr::Expr;
+    use crate::ast::method_call::MethodCall;
+    use crate::ast::value::Value;
+    use crate::big_integer::BigInteger;
+    use crate::eval::context::Context;
+    use crate::eval::tests::try_eval_out;
+    use crate::eval::EvalError;
+    use crate::serialization::sigma_serialize_roundtrip;
+    use crate::test_util::force_any_val;
+    use crate::types::smethod::SMethod;
+
+    use super::*;
+
+    /// Conversion method of the numeric type `obj_tpe` to the numeric type `target`
+    /// (e.g. `toLong` of Int)
+    fn conversion_method(obj_tpe: &SType, target: &SType) -> Option<SMethod> {
+        let companion: &'static STypeCompanion = match obj_tpe {
+            SType::SByte => &S_BYTE_TYPE_COMPANION,
+            SType::SShort => &S_SHORT_TYPE_COMPANION,
+            SType::SInt => &S_INT_TYPE_COMPANION,
+            SType::SLong => &S_LONG_TYPE_COMPANION,
+            SType::SBigInt => &S_BIG_INT_TYPE_COMPANION,
+            _ => return None,
+        };
+        companion.methods().into_iter().find(|m| match m.tpe() {
+            SType::SFunc(sfunc) => sfunc.t_range == *target,
+            _ => false,
+        })
+    }
+
+    fn convert<T: TryExtractFrom<Value>>(v: Constant, target: SType) -> Result<T, EvalError> {
+        let method = conversion_method(&v.tpe, &target).unwrap();
+        let expr: Expr = MethodCall {
+            obj: Box::new(v.into()),
+            method,
+            args: vec![],
+        }
+        .into();
+        try_eval_out::<T>(
+            &sigma_serialize_roundtrip(&expr),
+            Rc::new(force_any_val::<Context>()),
+        )
+    }
+
+    #[test]
+    fn eval_int_to_long() {
+        assert_eq!(convert::<i64>(42i32.into(), SType::SLong), Ok(42));
+    }
+
+    #[test]
+    fn eval_int_to_byte_overflow() {
+        assert!(matches!(
+            convert::<i8>(1000i32.into(), SType::SByte),
+            Err(EvalError::ArithmeticException(_))
+        ));
+        assert_eq!(convert::<i8>((-128i32).into(), SType::SByte), Ok(-128));
+    }
+
+    #[test]
+    fn eval_int_to_big_int() {
+        assert_eq!(
+            convert::<BigInteger>(5i32.into(), SType::SBigInt),
+            Ok(BigInteger::from(5i64))
+        );
+    }
+
+    #[test]
+    fn eval_big_int_to_long() {
+        assert_eq!(
+            convert::<i64>(BigInteger::from(i64::MAX).into(), SType::SLong),
+            Ok(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn conversion_method_ids() {
+        assert_eq!(
+            conversion_method(&SType::SInt, &SType::SByte).map(|m| m.method_id()),
+            Some(MethodId(1))
+        );
+        assert_eq!(
+            conversion_method(&SType::SByte, &SType::SBigInt).map(|m| m.method_id()),
+            Some(MethodId(5))
+        );
+        assert!(conversion_method(&SType::SBoolean, &SType::SLong).is_none());
+    }
+}
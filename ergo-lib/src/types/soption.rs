@@ -0,0 +1,11 @@
+//! `SOption` methods that are evaluated by the dedicated nodes
+//! ([`OptionMap`](crate::ast::option_map::OptionMap),
+//! [`OptionFilter`](crate::ast::option_filter::OptionFilter))
+//! but serialized as method calls
+
+use super::smethod::MethodId;
+use super::stype_companion::TypeId;
+
+pub const TYPE_ID: TypeId = TypeId(36);
+pub const MAP_METHOD_ID: MethodId = MethodId(7);
+pub const FILTER_METHOD_ID: MethodId = MethodId(8);
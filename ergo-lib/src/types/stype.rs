@@ -72,6 +72,24 @@ impl SType {
         }
     }
 
+    /// Returns true if the type is numeric (Byte, Short, Int, Long, BigInt)
+    pub fn is_numeric(&self) -> bool {
+        self.numeric_rank().is_some()
+    }
+
+    /// Position of the numeric type in the widening order (Byte < Short < Int < Long < BigInt),
+    /// `None` for non-numeric types
+    pub(crate) fn numeric_rank(&self) -> Option<u8> {
+        match self {
+            SType::SByte => Some(0),
+            SType::SShort => Some(1),
+            SType::SInt => Some(2),
+            SType::SLong => Some(3),
+            SType::SBigInt => Some(4),
+            _ => None,
+        }
+    }
+
     /// Get STypeCompanion instance associated with this SType
     pub fn type_companion(&self) -> Option<Box<STypeCompanion>> {
         todo!()
@@ -95,6 +113,18 @@ impl<T: LiftIntoSType> LiftIntoSType for Vec<T> {
     }
 }
 
+impl<T: LiftIntoSType> LiftIntoSType for Option<T> {
+    fn stype() -> SType {
+        SType::SOption(Box::new(T::stype()))
+    }
+}
+
+impl<A: LiftIntoSType, B: LiftIntoSType> LiftIntoSType for (A, B) {
+    fn stype() -> SType {
+        SType::STup(vec![A::stype(), B::stype()])
+    }
+}
+
 impl LiftIntoSType for bool {
     fn stype() -> SType {
         SType::SBoolean
@@ -107,6 +137,12 @@ impl LiftIntoSType for i8 {
     }
 }
 
+impl LiftIntoSType for u8 {
+    fn stype() -> SType {
+        SType::SByte
+    }
+}
+
 impl LiftIntoSType for i16 {
     fn stype() -> SType {
         SType::SShort
@@ -164,6 +200,7 @@ impl LiftIntoSType for EcPoint {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::collection::vec;
     use proptest::prelude::*;
 
     fn primitive_type() -> BoxedStrategy<SType> {
@@ -185,11 +222,15 @@ mod tests {
         type Strategy = BoxedStrategy<Self>;
 
         fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-            prop_oneof![
-                primitive_type(),
-                primitive_type().prop_map(SType::new_scoll),
-            ]
-            .boxed()
+            primitive_type()
+                .prop_recursive(3, 16, 4, |elem| {
+                    prop_oneof![
+                        elem.clone().prop_map(SType::new_scoll),
+                        elem.clone().prop_map(|t| SType::SOption(Box::new(t))),
+                        vec(elem, 2..=5).prop_map(SType::STup),
+                    ]
+                })
+                .boxed()
         }
     }
 }
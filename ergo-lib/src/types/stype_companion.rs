@@ -6,10 +6,14 @@ use crate::serialization::sigma_byte_writer::SigmaByteWrite;
 use crate::serialization::SerializationError;
 use crate::serialization::SigmaSerializable;
 
+use super::sbox;
+use super::scoll;
 use super::scontext;
+use super::sgroup_elem;
 use super::smethod::MethodId;
 use super::smethod::SMethod;
 use super::smethod::SMethodDesc;
+use super::snumeric;
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub struct TypeId(pub u8);
@@ -38,15 +42,35 @@ pub struct STypeCompanion {
 
 impl STypeCompanion {
     pub fn new(head: &'static STypeCompanionHead, methods: Vec<&'static SMethodDesc>) -> Self {
+        debug_assert!(
+            duplicate_method_ids(&methods).is_empty(),
+            "duplicate method ids {:?} in {}",
+            duplicate_method_ids(&methods),
+            head.type_name
+        );
         STypeCompanion { head, methods }
     }
 
+    /// All registered type companions
+    pub fn all() -> Vec<&'static STypeCompanion> {
+        vec![
+            &scontext::S_CONTEXT_TYPE_COMPANION,
+            &sbox::S_BOX_TYPE_COMPANION,
+            &scoll::S_COLL_TYPE_COMPANION,
+            &sgroup_elem::S_GROUP_ELEM_TYPE_COMPANION,
+            &snumeric::S_BYTE_TYPE_COMPANION,
+            &snumeric::S_SHORT_TYPE_COMPANION,
+            &snumeric::S_INT_TYPE_COMPANION,
+            &snumeric::S_LONG_TYPE_COMPANION,
+            &snumeric::S_BIG_INT_TYPE_COMPANION,
+        ]
+    }
+
     pub fn type_by_id(type_id: TypeId) -> &'static STypeCompanion {
-        if type_id == scontext::S_CONTEXT_TYPE_COMPANION.type_id() {
-            &scontext::S_CONTEXT_TYPE_COMPANION
-        } else {
-            todo!("cannot find STypeCompanion for {0:?} type id", type_id)
-        }
+        STypeCompanion::all()
+            .into_iter()
+            .find(|c| c.type_id() == type_id)
+            .unwrap_or_else(|| todo!("cannot find STypeCompanion for {0:?} type id", type_id))
     }
 
     pub fn method_by_id(&'static self, method_id: MethodId) -> Option<SMethod> {
@@ -68,3 +92,61 @@ impl STypeCompanion {
         self.head.type_name
     }
 }
+
+/// Method ids that are used by more than one method
+fn duplicate_method_ids(methods: &[&SMethodDesc]) -> Vec<MethodId> {
+    methods
+        .iter()
+        .enumerate()
+        .filter(|(idx, m)| methods[..*idx].iter().any(|p| p.method_id == m.method_id))
+        .map(|(_, m)| m.method_id.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::stype::SType;
+
+    #[test]
+    fn unique_type_ids() {
+        let companions = STypeCompanion::all();
+        companions.iter().enumerate().for_each(|(idx, c)| {
+            assert!(
+                companions[..idx].iter().all(|p| p.type_id() != c.type_id()),
+                "duplicate type id {:?}",
+                c.type_id()
+            )
+        });
+    }
+
+    #[test]
+    fn unique_method_ids() {
+        STypeCompanion::all().into_iter().for_each(|c| {
+            assert_eq!(
+                duplicate_method_ids(&c.methods),
+                vec![],
+                "in {}",
+                c.type_name()
+            );
+            c.methods()
+                .into_iter()
+                .for_each(|m| assert_eq!(c.method_by_id(m.method_id()), Some(m)));
+        });
+    }
+
+    #[test]
+    fn duplicate_method_id_detected() {
+        let m = SMethodDesc {
+            name: "m",
+            method_id: MethodId(1),
+            tpe: SType::SAny,
+            eval_fn: |_env, _ectx, obj, _args| Ok(obj),
+        };
+        let other = SMethodDesc {
+            name: "other",
+            ..m.clone()
+        };
+        assert_eq!(duplicate_method_ids(&[&m, &other]), vec![MethodId(1)]);
+    }
+}
@@ -18,6 +18,11 @@ impl<T> IntoOption<T> for CtOption<T> {
     }
 }
 
+/// Decode `Coll[Byte]` elements as UTF-8 text
+pub fn coll_bytes_to_utf8(bs: &[i8]) -> Result<String, std::string::FromUtf8Error> {
+    String::from_utf8(bs.iter().map(|b| *b as u8).collect())
+}
+
 /// Vec<i8> to Vec<u8> conversion
 pub trait FromVecI8 {
     /// Convert Vec<i8> to Vec<u8>
@@ -6,7 +6,7 @@ pub mod signing;
 pub mod tx_builder;
 
 use secret_key::SecretKey;
-use signing::{sign_transaction, TxSigningError};
+use signing::{reduce_tx_inputs, sign_transaction, ReductionResult, TxSigningError};
 use thiserror::Error;
 
 use crate::chain::ergo_state_context::ErgoStateContext;
@@ -56,4 +56,14 @@ impl Wallet {
     ) -> Result<Transaction, WalletError> {
         sign_transaction(self.prover.as_ref(), tx_context, state_context).map_err(WalletError::from)
     }
+
+    /// Reduces the transaction inputs to sigma propositions along with the estimated cost
+    /// of each reduction (e.g. for the fee estimation)
+    pub fn reduce_transaction_inputs(
+        &self,
+        tx_context: &TransactionContext,
+        state_context: &ErgoStateContext,
+    ) -> Result<Vec<ReductionResult>, WalletError> {
+        reduce_tx_inputs(self.prover.as_ref(), tx_context, state_context).map_err(WalletError::from)
+    }
 }
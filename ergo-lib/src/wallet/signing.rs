@@ -7,7 +7,7 @@ use crate::eval::context::Context;
 use crate::eval::context::ContextError;
 use crate::{
     chain::{
-        ergo_box::ErgoBox,
+        ergo_box::{BoxId, ErgoBox},
         ergo_state_context::ErgoStateContext,
         transaction::{unsigned::UnsignedTransaction, Transaction},
     },
@@ -15,6 +15,8 @@ use crate::{
     sigma_protocol::prover::{Prover, ProverError},
 };
 
+pub use crate::eval::ReductionResult;
+
 use thiserror::Error;
 
 /// Errors on transaction signing
@@ -23,9 +25,12 @@ pub enum TxSigningError {
     /// error on proving an input
     #[error("Prover error (tx input index {1}): {0}")]
     ProverError(ProverError, usize),
-    /// failed to find an input in boxes_to_spend
+    /// failed to find an input in boxes_to_spend (at the same index as in the tx inputs)
     #[error("Input box not found (index {0})")]
     InputBoxNotFound(usize),
+    /// failed to find a data input in data_boxes
+    #[error("Data input box not found (index {0})")]
+    DataInputBoxNotFound(usize),
     /// Context creation error
     #[error("Context error: {0:?}")]
     ContextError(#[from] ContextError),
@@ -42,6 +47,43 @@ pub struct TransactionContext {
     pub data_boxes: Vec<ErgoBox>,
 }
 
+impl TransactionContext {
+    /// Create new instance, looking up the boxes for every input and data input of the
+    /// transaction (`boxes_to_spend` and `data_boxes` can be in any order)
+    pub fn new(
+        spending_tx: UnsignedTransaction,
+        boxes_to_spend: Vec<ErgoBox>,
+        data_boxes: Vec<ErgoBox>,
+    ) -> Result<Self, TxSigningError> {
+        let find_box = |boxes: &[ErgoBox], box_id: &BoxId| {
+            boxes.iter().find(|b| b.box_id() == *box_id).cloned()
+        };
+        let boxes_to_spend = spending_tx
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(idx, input)| {
+                find_box(&boxes_to_spend, &input.box_id)
+                    .ok_or(TxSigningError::InputBoxNotFound(idx))
+            })
+            .collect::<Result<Vec<ErgoBox>, TxSigningError>>()?;
+        let data_boxes = spending_tx
+            .data_inputs
+            .iter()
+            .enumerate()
+            .map(|(idx, data_input)| {
+                find_box(&data_boxes, &data_input.box_id)
+                    .ok_or(TxSigningError::DataInputBoxNotFound(idx))
+            })
+            .collect::<Result<Vec<ErgoBox>, TxSigningError>>()?;
+        Ok(TransactionContext {
+            spending_tx,
+            boxes_to_spend,
+            data_boxes,
+        })
+    }
+}
+
 /// Signs a transaction (generating proofs for inputs)
 pub fn sign_transaction(
     prover: &dyn Prover,
@@ -50,33 +92,33 @@ pub fn sign_transaction(
 ) -> Result<Transaction, TxSigningError> {
     let tx = tx_context.spending_tx.clone();
     let message_to_sign = tx.bytes_to_sign();
-    let mut signed_inputs: Vec<Input> = vec![];
-    tx_context
-        .boxes_to_spend
+    // signed inputs are in the same order as the unsigned ones, since proofs are matched
+    // with the inputs by index
+    let signed_inputs = tx
+        .inputs
         .iter()
         .enumerate()
-        .try_for_each(|(idx, input_box)| {
-            if let Some(unsigned_input) = tx.inputs.get(idx) {
-                let ctx = Rc::new(Context::new(state_context, &tx_context, idx)?);
-                prover
-                    .prove(
-                        &input_box.ergo_tree,
-                        &Env::empty(),
-                        ctx,
-                        message_to_sign.as_slice(),
-                    )
-                    .map(|proof| {
-                        let input = Input {
-                            box_id: unsigned_input.box_id.clone(),
-                            spending_proof: proof,
-                        };
-                        signed_inputs.push(input);
-                    })
-                    .map_err(|e| TxSigningError::ProverError(e, idx))
-            } else {
-                Err(TxSigningError::InputBoxNotFound(idx))
-            }
-        })?;
+        .map(|(idx, unsigned_input)| {
+            let input_box = tx_context
+                .boxes_to_spend
+                .get(idx)
+                .filter(|b| b.box_id() == unsigned_input.box_id)
+                .ok_or(TxSigningError::InputBoxNotFound(idx))?;
+            let ctx = Rc::new(Context::new(state_context, &tx_context, idx)?);
+            prover
+                .prove(
+                    &input_box.ergo_tree,
+                    &Env::empty(),
+                    ctx,
+                    message_to_sign.as_slice(),
+                )
+                .map(|proof| Input {
+                    box_id: unsigned_input.box_id.clone(),
+                    spending_proof: proof,
+                })
+                .map_err(|e| TxSigningError::ProverError(e, idx))
+        })
+        .collect::<Result<Vec<Input>, TxSigningError>>()?;
     Ok(Transaction::new(
         signed_inputs,
         tx.data_inputs,
@@ -84,6 +126,33 @@ pub fn sign_transaction(
     ))
 }
 
+/// Reduces the guarding scripts of the transaction inputs to sigma propositions (without
+/// generating the proofs), along with the estimated cost of each reduction
+pub fn reduce_tx_inputs(
+    prover: &dyn Prover,
+    tx_context: &TransactionContext,
+    state_context: &ErgoStateContext,
+) -> Result<Vec<ReductionResult>, TxSigningError> {
+    tx_context
+        .boxes_to_spend
+        .iter()
+        .enumerate()
+        .map(|(idx, input_box)| {
+            let ctx = Rc::new(Context::new(state_context, tx_context, idx)?);
+            input_box
+                .ergo_tree
+                .proposition()
+                .map_err(ProverError::from)
+                .and_then(|expr| {
+                    prover
+                        .reduce_to_crypto(expr.as_ref(), &Env::empty(), ctx)
+                        .map_err(ProverError::EvalError)
+                })
+                .map_err(|e| TxSigningError::ProverError(e, idx))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,10 +161,19 @@ mod tests {
 
     use crate::ast::constant::Constant;
     use crate::ast::expr::Expr;
+    use crate::ast::get_var::GetVar;
+    use crate::ast::ops::{BinOp, RelationOp};
+    use crate::ast::option_get::OptionGet;
+    use crate::eval::EvalError;
+    use crate::sigma_protocol::prover::ContextExtension;
+    use crate::sigma_protocol::sigma_boolean::{SigmaBoolean, SigmaProofOfKnowledgeTree};
+    use crate::test_util::force_any_val;
     use crate::types::stype::SType;
     use crate::{
         chain::{
-            ergo_box::{box_builder::ErgoBoxCandidateBuilder, BoxValue, NonMandatoryRegisters},
+            ergo_box::{
+                box_builder::ErgoBoxCandidateBuilder, BoxId, BoxValue, NonMandatoryRegisters,
+            },
             transaction::{TxId, UnsignedInput},
         },
         ergo_tree::ErgoTree,
@@ -168,4 +246,253 @@ mod tests {
         }
 
     }
+
+    #[test]
+    fn test_reduce_tx_inputs_p2pk() {
+        let secret = DlogProverInput::random();
+        let pk = secret.public_image();
+        let tree = ErgoTree::from(Rc::new(Expr::Const(Constant {
+            tpe: SType::SSigmaProp,
+            v: pk.clone().into(),
+        })));
+        let input_box = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree.clone(),
+            vec![],
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        );
+        let candidate = ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, tree, 0)
+            .build()
+            .unwrap();
+        let tx = UnsignedTransaction::new(
+            vec![UnsignedInput::from(input_box.clone())],
+            vec![],
+            vec![candidate],
+        );
+        let tx_context = TransactionContext {
+            spending_tx: tx,
+            boxes_to_spend: vec![input_box],
+            data_boxes: vec![],
+        };
+        let prover = TestProver {
+            secrets: vec![PrivateInput::DlogProverInput(secret)],
+        };
+        let res = reduce_tx_inputs(&prover, &tx_context, &ErgoStateContext::dummy()).unwrap();
+        assert_eq!(res.len(), 1);
+        let reduced = res.first().unwrap();
+        assert!(reduced.cost > 0);
+        assert_eq!(
+            reduced.sigma_prop,
+            SigmaBoolean::ProofOfKnowledge(SigmaProofOfKnowledgeTree::ProveDlog(pk))
+        );
+    }
+
+    fn p2pk_box(secret: &DlogProverInput, creation_height: u32) -> ErgoBox {
+        let tree = ErgoTree::from(Rc::new(Expr::Const(Constant {
+            tpe: SType::SSigmaProp,
+            v: secret.public_image().into(),
+        })));
+        ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            creation_height,
+            TxId::zero(),
+            0,
+        )
+    }
+
+    fn three_inputs_tx_context() -> (TransactionContext, TestProver) {
+        let secrets: Vec<DlogProverInput> = (0..3).map(|_| DlogProverInput::random()).collect();
+        let boxes_to_spend: Vec<ErgoBox> = secrets
+            .iter()
+            .enumerate()
+            .map(|(idx, secret)| p2pk_box(secret, idx as u32))
+            .collect();
+        let inputs = boxes_to_spend
+            .clone()
+            .into_iter()
+            .map(UnsignedInput::from)
+            .collect();
+        let candidate = ErgoBoxCandidateBuilder::new(
+            BoxValue::SAFE_USER_MIN,
+            p2pk_box(&secrets[0], 0).ergo_tree,
+            0,
+        )
+        .build()
+        .unwrap();
+        let tx_context = TransactionContext {
+            spending_tx: UnsignedTransaction::new(inputs, vec![], vec![candidate]),
+            boxes_to_spend,
+            data_boxes: vec![],
+        };
+        // secrets order differs from the inputs order
+        let prover = TestProver {
+            secrets: secrets
+                .into_iter()
+                .rev()
+                .map(PrivateInput::DlogProverInput)
+                .collect(),
+        };
+        (tx_context, prover)
+    }
+
+    #[test]
+    fn test_sign_preserves_inputs_order() {
+        let (tx_context, prover) = three_inputs_tx_context();
+        let boxes_to_spend = tx_context.boxes_to_spend.clone();
+        let unsigned_box_ids: Vec<BoxId> = tx_context
+            .spending_tx
+            .inputs
+            .iter()
+            .map(|i| i.box_id.clone())
+            .collect();
+        let signed_tx = sign_transaction(&prover, tx_context, &ErgoStateContext::dummy()).unwrap();
+        let signed_box_ids: Vec<BoxId> =
+            signed_tx.inputs.iter().map(|i| i.box_id.clone()).collect();
+        assert_eq!(signed_box_ids, unsigned_box_ids);
+        assert!(verify_tx_proofs(&signed_tx, &boxes_to_spend).unwrap());
+    }
+
+    #[test]
+    fn test_tx_context_new_sign_and_verify() {
+        let (tx_context, prover) = three_inputs_tx_context();
+        let mut shuffled_boxes = tx_context.boxes_to_spend.clone();
+        shuffled_boxes.rotate_left(1);
+        let data_box = force_any_val::<ErgoBox>();
+        let tx = UnsignedTransaction::new(
+            tx_context.spending_tx.inputs.clone(),
+            vec![data_box.box_id().into()],
+            tx_context.spending_tx.output_candidates.clone(),
+        );
+        let new_tx_context =
+            TransactionContext::new(tx, shuffled_boxes, vec![force_any_val(), data_box.clone()])
+                .unwrap();
+        assert_eq!(new_tx_context.boxes_to_spend, tx_context.boxes_to_spend);
+        assert_eq!(new_tx_context.data_boxes, vec![data_box]);
+        let signed_tx =
+            sign_transaction(&prover, new_tx_context, &ErgoStateContext::dummy()).unwrap();
+        assert!(verify_tx_proofs(&signed_tx, &tx_context.boxes_to_spend).unwrap());
+    }
+
+    #[test]
+    fn test_tx_context_new_missing_box() {
+        let (tx_context, _) = three_inputs_tx_context();
+        let mut boxes_to_spend = tx_context.boxes_to_spend.clone();
+        boxes_to_spend.remove(2);
+        assert_eq!(
+            TransactionContext::new(tx_context.spending_tx.clone(), boxes_to_spend, vec![]),
+            Err(TxSigningError::InputBoxNotFound(2))
+        );
+        let tx = UnsignedTransaction::new(
+            tx_context.spending_tx.inputs,
+            vec![force_any_val::<ErgoBox>().box_id().into()],
+            tx_context.spending_tx.output_candidates,
+        );
+        assert_eq!(
+            TransactionContext::new(tx, tx_context.boxes_to_spend, vec![]),
+            Err(TxSigningError::DataInputBoxNotFound(0))
+        );
+    }
+
+    #[test]
+    fn test_sign_misaligned_boxes_to_spend() {
+        let (mut tx_context, prover) = three_inputs_tx_context();
+        tx_context.boxes_to_spend.swap(1, 2);
+        assert_eq!(
+            sign_transaction(&prover, tx_context, &ErgoStateContext::dummy()),
+            Err(TxSigningError::InputBoxNotFound(1))
+        );
+    }
+
+    fn get_var_long_eq_tree(var_id: u8, expected: i64) -> ErgoTree {
+        // getVar[Long](var_id).get == expected
+        let expr = Expr::BinOp(
+            BinOp::Relation(RelationOp::Eq),
+            Box::new(
+                OptionGet {
+                    input: Box::new(
+                        GetVar {
+                            var_id,
+                            var_tpe: SType::SLong,
+                        }
+                        .into(),
+                    ),
+                }
+                .into(),
+            ),
+            Box::new(Expr::Const(expected.into())),
+        );
+        ErgoTree::from(Rc::new(expr))
+    }
+
+    fn sign_with_extension(
+        tree: ErgoTree,
+        extension: ContextExtension,
+    ) -> Result<Transaction, TxSigningError> {
+        let input_box = ErgoBox::new(
+            BoxValue::SAFE_USER_MIN,
+            tree,
+            vec![],
+            NonMandatoryRegisters::empty(),
+            0,
+            TxId::zero(),
+            0,
+        );
+        let input = UnsignedInput {
+            box_id: input_box.box_id(),
+            extension,
+        };
+        let out_tree = ErgoTree::from(Rc::new(Expr::Const(Constant {
+            tpe: SType::SSigmaProp,
+            v: DlogProverInput::random().public_image().into(),
+        })));
+        let candidate = ErgoBoxCandidateBuilder::new(BoxValue::SAFE_USER_MIN, out_tree, 0)
+            .build()
+            .unwrap();
+        let tx = UnsignedTransaction::new(vec![input], vec![], vec![candidate]);
+        let tx_context = TransactionContext {
+            spending_tx: tx,
+            boxes_to_spend: vec![input_box],
+            data_boxes: vec![],
+        };
+        let prover = TestProver { secrets: vec![] };
+        sign_transaction(&prover, tx_context, &ErgoStateContext::dummy())
+    }
+
+    #[test]
+    fn test_sign_with_context_extension() {
+        let mut extension = ContextExtension::empty();
+        extension.values.insert(1, 5i64.into());
+        let signed_tx = sign_with_extension(get_var_long_eq_tree(1, 5), extension.clone()).unwrap();
+        assert_eq!(
+            signed_tx.inputs.first().unwrap().spending_proof.extension,
+            extension
+        );
+    }
+
+    #[test]
+    fn test_sign_with_wrong_context_extension() {
+        let mut extension = ContextExtension::empty();
+        extension.values.insert(1, 4i64.into());
+        assert_eq!(
+            sign_with_extension(get_var_long_eq_tree(1, 5), extension),
+            Err(TxSigningError::ProverError(ProverError::ReducedToFalse, 0))
+        );
+    }
+
+    #[test]
+    fn test_sign_without_context_extension() {
+        assert!(matches!(
+            sign_with_extension(get_var_long_eq_tree(1, 5), ContextExtension::empty()),
+            Err(TxSigningError::ProverError(
+                ProverError::EvalError(EvalError::NotFound(_)),
+                0
+            ))
+        ));
+    }
 }
@@ -167,8 +167,11 @@ impl<S: ErgoBoxAssets + ErgoBoxId + Clone> TxBuilder<S> {
                     change_address_ergo_tree.clone(),
                     self.current_height,
                 );
-                for token in &b.tokens() {
-                    candidate.add_token(token.clone());
+                // order change tokens by id to make the resulting box reproducible
+                let mut tokens = b.tokens();
+                tokens.sort_by(|a, b| a.token_id.cmp(&b.token_id));
+                for token in tokens {
+                    candidate.add_token(token);
                 }
                 candidate.build()
             })
@@ -288,6 +291,7 @@ mod tests {
         ergo_box::{checked_sum, ErgoBox, NonMandatoryRegisters},
         token::{tests::ArbTokenIdParam, Token, TokenAmount, TokenId},
         transaction::TxId,
+        Digest32,
     };
     use crate::ergo_tree::ErgoTree;
     use crate::test_util::{force_any_val, force_any_val_with};
@@ -392,6 +396,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_change_tokens_ordered_by_id() {
+        // distinct token ids, in descending order
+        let tokens: Vec<Token> = (1..=5u8)
+            .rev()
+            .map(|i| Token {
+                token_id: TokenId(Digest32(Box::new([i; Digest32::SIZE]))),
+                amount: 100.try_into().unwrap(),
+            })
+            .collect();
+        let input_box = ErgoBox::new(
+            10000000i64.try_into().unwrap(),
+            force_any_val::<ErgoTree>(),
+            tokens.clone(),
+            NonMandatoryRegisters::empty(),
+            1,
+            force_any_val::<TxId>(),
+            0,
+        );
+        let tx_fee = BoxValue::SAFE_USER_MIN;
+        let out_box_value = BoxValue::SAFE_USER_MIN;
+        let target_balance = out_box_value.checked_add(&tx_fee).unwrap();
+        let box_selection = SimpleBoxSelector::new()
+            .select(vec![input_box], target_balance, &[])
+            .unwrap();
+        let out_box = ErgoBoxCandidateBuilder::new(out_box_value, force_any_val::<ErgoTree>(), 0)
+            .build()
+            .unwrap();
+        let tx = TxBuilder::new(
+            box_selection,
+            vec![out_box],
+            0,
+            tx_fee,
+            force_any_val::<Address>(),
+            BoxValue::SAFE_USER_MIN,
+        )
+        .build()
+        .unwrap();
+        let change_token_ids: Vec<TokenId> = tx
+            .output_candidates
+            .get(1)
+            .unwrap()
+            .tokens()
+            .into_iter()
+            .map(|t| t.token_id)
+            .collect();
+        let mut expected: Vec<TokenId> = tokens.into_iter().map(|t| t.token_id).collect();
+        expected.sort();
+        assert_eq!(change_token_ids, expected);
+    }
+
     #[test]
     fn test_mint_token() {
         let input_box = ErgoBox::new(
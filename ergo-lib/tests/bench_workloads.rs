@@ -0,0 +1,40 @@
+//! Checks that the benchmarked workloads (see `benches/`) produce correct results
+
+#[path = "../benches/common/mod.rs"]
+mod common;
+
+use common::*;
+use ergo_lib::serialization::SigmaSerializable;
+use ergo_lib::sigma_protocol::prover::ProofBytes;
+use ergo_lib::sigma_protocol::sigma_boolean::SigmaBoolean;
+
+#[test]
+fn parse_large_tree() {
+    let bytes = large_tree_bytes(LARGE_TREE_KEYS_COUNT);
+    let tree = parse_tree(&bytes);
+    assert!(tree.proposition().is_ok());
+    assert_eq!(tree.sigma_serialize_bytes(), bytes);
+}
+
+#[test]
+fn reduce_p2pk() {
+    let secret = secret(1);
+    let res = reduce(&wallet(&secret), &p2pk_tx_context(&secret));
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].sigma_prop, SigmaBoolean::from(secret.public_image()));
+}
+
+#[test]
+fn sign_p2pk() {
+    let secret = secret(1);
+    let tx_context = p2pk_tx_context(&secret);
+    let input_id = tx_context.boxes_to_spend[0].box_id();
+    let tx = sign(&wallet(&secret), tx_context);
+    assert_eq!(tx.inputs.len(), 1);
+    assert_eq!(tx.inputs[0].box_id, input_id);
+    // challenge (24 bytes) and response (32 bytes) of the Schnorr signature
+    assert!(matches!(
+        &tx.inputs[0].spending_proof.proof,
+        ProofBytes::Some(bytes) if bytes.len() == 56
+    ));
+}
@@ -96,6 +96,17 @@ pub trait WriteSigmaVlqExt: io::Write {
 
         Self::write_all(self, &buffer[..position + 1])
     }
+
+    /// Pack bits (8 per byte, first bit in the lowest bit of the first byte) and write them
+    fn put_bits(&mut self, bools: &[bool]) -> io::Result<()> {
+        let mut bytes = vec![0u8; bools.len().div_ceil(8)];
+        bools
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b)
+            .for_each(|(i, _)| bytes[i / 8] |= 1 << (i % 8));
+        Self::write_all(self, &bytes)
+    }
 }
 
 /// Mark all types implementing `Write` as implementing the extension.
@@ -164,6 +175,15 @@ pub trait ReadSigmaVlqExt: peekable_reader::Peekable {
         }
         Err(VlqEncodingError::VlqDecodingFailed)
     }
+
+    /// Read `size` bits written with [`WriteSigmaVlqExt::put_bits`]
+    fn get_bits(&mut self, size: usize) -> Result<Vec<bool>, io::Error> {
+        let mut bytes = vec![0u8; size.div_ceil(8)];
+        self.read_exact(&mut bytes)?;
+        Ok((0..size)
+            .map(|i| bytes[i / 8] & (1 << (i % 8)) != 0)
+            .collect())
+    }
 }
 
 /// Mark all types implementing `Read` as implementing the extension.
@@ -289,4 +309,21 @@ mod tests {
             prop_assert_eq![dec, arr];
         }
     }
+
+    #[test]
+    fn test_write_bits() {
+        let mut w = Cursor::new(vec![]);
+        w.put_bits(&[true, false, true]).unwrap();
+        w.put_bits(&[true; 9]).unwrap();
+        w.put_bits(&[]).unwrap();
+        assert_eq!(w.into_inner(), vec![0x05, 0xff, 0x01])
+    }
+
+    #[test]
+    fn test_read_bits() {
+        let mut r = PeekableReader::new(Cursor::new(vec![0x05, 0xff, 0x01]));
+        assert_eq!(r.get_bits(3).unwrap(), vec![true, false, true]);
+        assert_eq!(r.get_bits(9).unwrap(), vec![true; 9]);
+        assert_eq!(r.get_bits(0).unwrap(), vec![]);
+    }
 }